@@ -0,0 +1,2 @@
+alter table chores add column cooldown_days int null default null;
+alter table chore_activities add column is_cooldown_violation boolean not null default false;
@@ -0,0 +1,5 @@
+alter table chores add column bounty_points_per_day int null default null;
+alter table chores add column bounty_max_points int null default null;
+
+alter table chore_activities add column points int not null default 0;
+update chore_activities set points = (select chores.points from chores where chores.id = chore_activities.chore_id);
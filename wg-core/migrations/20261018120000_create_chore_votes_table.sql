@@ -0,0 +1,14 @@
+alter table chores add column points_voting_method text null default null;
+
+create table chore_votes
+(
+    id text not null primary key,
+    chore_id text not null references chores(id),
+    user_id text not null references users(id),
+    points int not null,
+    date_created timestamp not null default current_timestamp,
+    date_updated timestamp not null default current_timestamp
+);
+
+create unique index chore_votes_unique_chore_id_user_id_idx on chore_votes(chore_id, user_id);
+create index chore_votes_user_id_idx on chore_votes(user_id);
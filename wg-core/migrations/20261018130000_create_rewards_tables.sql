@@ -0,0 +1,25 @@
+create table rewards
+(
+    id text not null primary key,
+    chore_list_id text not null references chore_lists(id),
+    name text not null,
+    description text null default null,
+    points int not null,
+    date_created timestamp not null default current_timestamp,
+    date_deleted timestamp default null
+);
+
+create index rewards_chore_list_id_idx on rewards(chore_list_id);
+
+create table reward_redemptions
+(
+    id text not null primary key,
+    reward_id text not null references rewards(id),
+    user_id text not null references users(id),
+    points int not null,
+    date_created timestamp not null default current_timestamp,
+    date_deleted timestamp default null
+);
+
+create index reward_redemptions_reward_id_idx on reward_redemptions(reward_id);
+create index reward_redemptions_user_id_idx on reward_redemptions(user_id);
@@ -0,0 +1,21 @@
+-- handover offers are swap requests without a target user, which is set once
+-- someone accepts the offer
+create table chore_swap_requests
+(
+    id text not null primary key,
+    chore_id text not null references chores(id),
+    requesting_user_id text not null references users(id),
+    target_user_id text null default null references users(id),
+    absence_id text null default null references absences(id),
+    offered_points int not null default 0,
+    comment text null default null,
+    status text not null,
+    chore_activity_id text null default null references chore_activities(id),
+    date_created timestamp not null default current_timestamp,
+    date_updated timestamp not null default current_timestamp
+);
+
+create index chore_swap_requests_chore_id_idx on chore_swap_requests(chore_id);
+create index chore_swap_requests_target_user_id_idx on chore_swap_requests(target_user_id);
+create index chore_swap_requests_absence_id_idx on chore_swap_requests(absence_id);
+create index chore_swap_requests_chore_activity_id_idx on chore_swap_requests(chore_activity_id);
@@ -0,0 +1,2 @@
+alter table chores add column is_one_off boolean not null default false;
+alter table chores add column date_completed timestamp null default null;
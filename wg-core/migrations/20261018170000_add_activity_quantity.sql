@@ -0,0 +1,2 @@
+alter table chores add column max_quantity integer null default null;
+alter table chore_activities add column quantity integer not null default 1;
@@ -0,0 +1,23 @@
+alter table chores add column award_partial_checklist_points boolean not null default false;
+
+create table chore_checklist_items
+(
+    id text not null primary key,
+    chore_id text not null references chores(id),
+    position int not null,
+    name text not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index chore_checklist_items_chore_id_idx on chore_checklist_items(chore_id);
+
+create table chore_activity_checklist_items
+(
+    id text not null primary key,
+    chore_activity_id text not null references chore_activities(id),
+    position int not null,
+    name text not null,
+    is_done boolean not null
+);
+
+create index chore_activity_checklist_items_chore_activity_id_idx on chore_activity_checklist_items(chore_activity_id);
@@ -0,0 +1,4 @@
+alter table chores add column follow_up_of_chore_id text null default null references chores(id);
+alter table chores add column follow_up_delay_days integer null default null;
+
+create index chores_follow_up_of_chore_id_idx on chores(follow_up_of_chore_id);
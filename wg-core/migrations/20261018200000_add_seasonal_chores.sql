@@ -0,0 +1,2 @@
+alter table chores add column active_from_month integer null default null;
+alter table chores add column active_until_month integer null default null;
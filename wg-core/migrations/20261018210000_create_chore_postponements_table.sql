@@ -0,0 +1,13 @@
+create table chore_postponements
+(
+    id text not null primary key,
+    chore_id text not null references chores(id),
+    user_id text not null references users(id),
+    kind text not null,
+    previous_due_date timestamp not null,
+    new_due_date timestamp not null,
+    reason text null default null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index chore_postponements_chore_id_idx on chore_postponements(chore_id);
@@ -0,0 +1,4 @@
+alter table chore_activities add column time text null default null;
+
+alter table chores add column interval_hours integer null default null;
+alter table chores add column next_due_time text null default null;
@@ -0,0 +1,10 @@
+create table chore_exclusions
+(
+    id text not null primary key,
+    chore_id text not null references chores(id),
+    user_id text not null references users(id),
+    date_created timestamp not null default current_timestamp
+);
+
+create unique index chore_exclusions_chore_id_user_id_idx on chore_exclusions(chore_id, user_id);
+create index chore_exclusions_user_id_idx on chore_exclusions(user_id);
@@ -0,0 +1,28 @@
+-- Email and password become optional, which requires rebuilding the table.
+-- The migrations run with foreign keys disabled, so the references of other
+-- tables to "users" stay intact while the table is replaced.
+create table users_new
+(
+    id text not null primary key,
+    name text not null,
+    email text null default null,
+    password_hash text null default null,
+    kind text not null default 'Member',
+    last_used_language text default null,
+    date_created timestamp not null default current_timestamp,
+    date_deleted timestamp default null
+);
+
+insert into users_new (id, name, email, password_hash, last_used_language, date_created, date_deleted)
+select id, name, email, password_hash, last_used_language, date_created, date_deleted from users;
+
+drop table users;
+
+alter table users_new rename to users;
+
+create unique index users_unique_email_idx on users(email);
+
+-- Activities of non-scoring participants are recorded by somebody else
+alter table chore_activities add column recorded_by_user_id text null default null references users(id);
+
+update chore_activities set recorded_by_user_id = user_id;
@@ -0,0 +1,10 @@
+create table password_reset_tokens
+(
+    id text not null primary key,
+    token_hash text not null,
+    user_id text not null references users(id),
+    date_expires timestamp not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index password_reset_tokens_token_hash_idx on password_reset_tokens(token_hash);
@@ -0,0 +1,12 @@
+create table user_invitations
+(
+    id text not null primary key,
+    token_hash text not null,
+    name text not null,
+    email text not null,
+    invited_by_user_id text not null references users(id),
+    date_expires timestamp not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index user_invitations_token_hash_idx on user_invitations(token_hash);
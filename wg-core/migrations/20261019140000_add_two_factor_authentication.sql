@@ -0,0 +1,15 @@
+alter table users add column totp_secret text null default null;
+-- Protects against replaying a one-time password within its time window
+alter table users add column totp_last_used_step integer null default null;
+
+alter table authentication_sessions add column is_second_factor_pending boolean not null default false;
+
+create table user_recovery_codes
+(
+    id text not null primary key,
+    user_id text not null references users(id),
+    code_hash text not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index user_recovery_codes_user_id_idx on user_recovery_codes(user_id);
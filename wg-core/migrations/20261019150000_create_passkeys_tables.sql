@@ -0,0 +1,27 @@
+create table passkeys
+(
+    id text not null primary key,
+    user_id text not null references users(id),
+    name text not null,
+    credential_id text not null,
+    public_key blob not null,
+    sign_count integer not null default 0,
+    date_last_used timestamp null default null,
+    date_created timestamp not null default current_timestamp
+);
+
+create unique index passkeys_unique_credential_id_idx on passkeys(credential_id);
+create index passkeys_user_id_idx on passkeys(user_id);
+
+create table passkey_challenges
+(
+    id text not null primary key,
+    challenge text not null,
+    user_id text null default null references users(id),
+    ip_address text null default null,
+    date_expires timestamp not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index passkey_challenges_challenge_idx on passkey_challenges(challenge);
+create index passkey_challenges_ip_address_idx on passkey_challenges(ip_address);
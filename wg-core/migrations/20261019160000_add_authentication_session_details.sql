@@ -0,0 +1,5 @@
+alter table authentication_sessions add column user_agent text null default null;
+alter table authentication_sessions add column ip_address text null default null;
+alter table authentication_sessions add column date_last_used timestamp null default null;
+
+update authentication_sessions set date_last_used = date_created;
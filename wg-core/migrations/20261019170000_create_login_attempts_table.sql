@@ -0,0 +1,11 @@
+create table login_attempts
+(
+    id text not null primary key,
+    user_id text not null references users(id),
+    is_successful boolean not null,
+    user_agent text null default null,
+    ip_address text not null,
+    date_created timestamp not null default current_timestamp
+);
+
+create index login_attempts_user_id_idx on login_attempts(user_id);
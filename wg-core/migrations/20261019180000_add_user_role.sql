@@ -0,0 +1,10 @@
+alter table users add column role text not null default 'Member';
+
+-- The first user set up the instance, so it becomes its admin
+update users set role = 'Admin'
+where id = (
+    select id from users
+    where kind = 'Member' and date_deleted is null
+    order by date_created asc
+    limit 1
+);
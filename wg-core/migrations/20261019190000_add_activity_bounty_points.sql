@@ -0,0 +1,3 @@
+-- the bounty of overdue chores is awarded once per activity, regardless of
+-- the quantity
+alter table chore_activities add column bounty_points integer not null default 0;
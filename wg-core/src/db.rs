@@ -1,5 +1,7 @@
+use std::str::FromStr;
+
 pub use sqlx;
-use sqlx::migrate::MigrateDatabase;
+use sqlx::{migrate::MigrateDatabase, Connection};
 
 pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!();
 
@@ -15,3 +17,15 @@ pub async fn create_pool(path: String) -> Pool {
 
     sqlx::sqlite::SqlitePool::connect(&db_url).await.unwrap()
 }
+
+/// Migrations run on a dedicated connection with foreign keys disabled, as
+/// SQLite requires that for rebuilding tables which are referenced by others
+pub async fn run_migrations(path: &str) {
+    let db_url = format!("sqlite:{}", path);
+    let options = sqlx::sqlite::SqliteConnectOptions::from_str(&db_url).unwrap()
+        .foreign_keys(false);
+
+    let mut connection = sqlx::sqlite::SqliteConnection::connect_with(&options).await.unwrap();
+    MIGRATOR.run(&mut connection).await.unwrap();
+    connection.close().await.unwrap();
+}
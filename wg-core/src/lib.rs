@@ -16,3 +16,6 @@ pub mod value;
 pub mod model;
 pub mod service;
 pub mod db;
+
+#[cfg(test)]
+mod test_util;
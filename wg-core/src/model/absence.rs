@@ -4,6 +4,10 @@ use crate::value::{DateTime, Uuid};
 
 pub type AbsenceId = Tagged<Uuid, Absence>;
 
+/// Days from the start of an absence without an end date in which due chores
+/// can be handed over
+const OPEN_ENDED_HANDOVER_DAYS: u64 = 14;
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct Absence {
     pub id: AbsenceId,
@@ -34,6 +38,11 @@ impl Absence {
         self.date_start.is_in_future()
     }
 
+    /// Last day on which chores that come due can be handed over
+    pub fn get_handover_end_date(&self) -> Date {
+        self.date_end.unwrap_or_else(|| Date::from(*self.date_start.as_ref() + chrono::Days::new(OPEN_ENDED_HANDOVER_DAYS)))
+    }
+
     pub fn num_days(&self) -> Option<u32> {
         self.date_end.map(|date_end| date_end.as_ref().signed_duration_since(*self.date_start.as_ref()).num_days() as u32)
     }
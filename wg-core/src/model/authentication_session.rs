@@ -10,6 +10,14 @@ pub struct AuthenticationSession {
     pub token: String,
     pub user_id: UserId,
     pub last_used_language: Option<Language>,
+    /// Set after the password has been checked for users with two-factor
+    /// authentication, until they have entered their code as well
+    pub is_second_factor_pending: bool,
+    /// User agent and IP address of the last request, so that users can
+    /// recognize their sessions
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub date_last_used: DateTime,
     pub date_expires: DateTime,
     pub date_created: DateTime,
 }
@@ -62,11 +70,15 @@ pub async fn create(
     pool: &sqlx::sqlite::SqlitePool,
     auth_session: &AuthenticationSession,
 ) -> Result<(), sqlx::Error> {
-    sqlx::query("INSERT INTO authentication_sessions (id, token, user_id, last_used_language, date_expires, date_created) VALUES (?, ?, ?, ?, ?, ?)")
+    sqlx::query("INSERT INTO authentication_sessions (id, token, user_id, last_used_language, is_second_factor_pending, user_agent, ip_address, date_last_used, date_expires, date_created) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
         .bind(auth_session.id)
         .bind(&auth_session.token)
         .bind(auth_session.user_id)
         .bind(auth_session.last_used_language)
+        .bind(auth_session.is_second_factor_pending)
+        .bind(&auth_session.user_agent)
+        .bind(&auth_session.ip_address)
+        .bind(auth_session.date_last_used)
         .bind(auth_session.date_expires)
         .bind(auth_session.date_created)
         .execute(pool)
@@ -78,10 +90,14 @@ pub async fn update(
     pool: &sqlx::sqlite::SqlitePool,
     auth_session: &AuthenticationSession,
 ) -> Result<(), sqlx::Error> {
-    sqlx::query("UPDATE authentication_sessions SET token = ?, user_id = ?, last_used_language = ?, date_expires = ? WHERE id = ?")
+    sqlx::query("UPDATE authentication_sessions SET token = ?, user_id = ?, last_used_language = ?, is_second_factor_pending = ?, user_agent = ?, ip_address = ?, date_last_used = ?, date_expires = ? WHERE id = ?")
         .bind(&auth_session.token)
         .bind(auth_session.user_id)
         .bind(auth_session.last_used_language)
+        .bind(auth_session.is_second_factor_pending)
+        .bind(&auth_session.user_agent)
+        .bind(&auth_session.ip_address)
+        .bind(auth_session.date_last_used)
         .bind(auth_session.date_expires)
         .bind(auth_session.id)
         .execute(pool)
@@ -100,6 +116,30 @@ pub async fn delete(
         .map(|_| ())
 }
 
+pub async fn delete_all_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM authentication_sessions WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_for_user_except(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+    excluded_id: &AuthenticationSessionId,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM authentication_sessions WHERE user_id = ? AND id != ?")
+        .bind(user_id)
+        .bind(excluded_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
 pub async fn delete_all_expired(pool: &sqlx::sqlite::SqlitePool) -> Result<(), sqlx::Error> {
     sqlx::query("DELETE FROM authentication_sessions WHERE date_expires < ?")
         .bind(DateTime::now())
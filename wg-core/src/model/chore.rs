@@ -1,25 +1,239 @@
 use crate::model::chore_list::ChoreListId;
+use crate::model::user::UserId;
 use crate::value::Tagged;
-use crate::value::{Date, DateTime, Uuid};
+use crate::value::{Date, DateTime, Time, Uuid};
+use chrono::Datelike;
 
 pub type ChoreId = Tagged<Uuid, Chore>;
 
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    strum::EnumString,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+pub enum PointsVotingMethod {
+    Median,
+    Mean,
+}
+
+impl PointsVotingMethod {
+    pub fn calculate_points(&self, votes: &[u32]) -> Option<u32> {
+        if votes.is_empty() {
+            return None;
+        }
+
+        let points = match *self {
+            PointsVotingMethod::Median => {
+                let mut votes = votes.to_vec();
+                votes.sort();
+
+                let middle = votes.len() / 2;
+                if votes.len().is_multiple_of(2) {
+                    (u64::from(votes[middle - 1]) + u64::from(votes[middle])) as f64 / 2.0
+                } else {
+                    votes[middle] as f64
+                }
+            },
+            PointsVotingMethod::Mean => {
+                votes.iter().map(|vote| *vote as f64).sum::<f64>() / votes.len() as f64
+            },
+        };
+
+        Some(points.round() as u32)
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct Chore {
     pub id: ChoreId,
     pub chore_list_id: ChoreListId,
     pub name: String,
     pub points: u32,
+    pub points_voting_method: Option<PointsVotingMethod>,
     pub interval_days: Option<u32>,
+    /// Interval for chores which are due several times a day, used instead
+    /// of `interval_days`
+    pub interval_hours: Option<u32>,
+    pub cooldown_days: Option<u32>,
+    pub bounty_points_per_day: Option<u32>,
+    pub bounty_max_points: Option<u32>,
+    /// If set, activities record a quantity of up to this many units and the
+    /// points are awarded per unit
+    pub max_quantity: Option<u32>,
+    /// Award only a share of the points if not all checklist items were done
+    pub award_partial_checklist_points: bool,
+    /// Parent chore which makes this chore due once an activity is recorded
+    /// for it
+    pub follow_up_of_chore_id: Option<ChoreId>,
+    /// Days after the activity of the parent chore until this chore is due
+    pub follow_up_delay_days: Option<u32>,
+    /// First month (1-12) of the active window of seasonal chores
+    pub active_from_month: Option<u32>,
+    /// Last month (1-12) of the active window of seasonal chores. The window
+    /// may wrap around the end of the year.
+    pub active_until_month: Option<u32>,
     pub next_due_date: Option<Date>,
+    /// Time of day on `next_due_date` from which the chore is due. The chore
+    /// is due for the whole day if not set.
+    pub next_due_time: Option<Time>,
+    /// One-off tasks are closed once they have been completed
+    pub is_one_off: bool,
+    /// Date of the activity which completed the one-off task
+    pub date_completed: Option<Date>,
     pub description: Option<String>,
     pub date_created: DateTime,
     pub date_deleted: Option<DateTime>,
 }
 
 impl Chore {
+    pub fn is_quantity_based(&self) -> bool {
+        self.max_quantity.is_some()
+    }
+
     pub fn is_due(&self) -> Option<bool> {
-        self.next_due_date.map(|date| date.is_in_past_or_today())
+        if self.is_completed() || self.is_dormant() {
+            return Some(false);
+        }
+
+        self.next_due_date.map(|date| match self.next_due_time {
+            Some(time) if date.is_today() => time <= Time::now(),
+            _ => date.is_in_past_or_today(),
+        })
+    }
+
+    pub fn has_interval(&self) -> bool {
+        self.interval_days.is_some() || self.interval_hours.is_some()
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.date_completed.is_some()
+    }
+
+    pub fn is_seasonal(&self) -> bool {
+        self.active_from_month.is_some() && self.active_until_month.is_some()
+    }
+
+    pub fn is_active_in_month(&self, month: u32) -> bool {
+        match (self.active_from_month, self.active_until_month) {
+            (Some(from_month), Some(until_month)) if from_month <= until_month => {
+                (from_month..=until_month).contains(&month)
+            }
+            (Some(from_month), Some(until_month)) => month >= from_month || month <= until_month,
+            _ => true,
+        }
+    }
+
+    /// Seasonal chores are dormant outside of their active window and don't
+    /// come due
+    pub fn is_dormant(&self) -> bool {
+        !self.is_active_in_month(Date::now().as_ref().month())
+    }
+
+    /// First day of the active window seasonal chores are currently in. Not
+    /// set for dormant and non-seasonal chores.
+    pub fn get_current_active_window_start_date(&self) -> Option<Date> {
+        let from_month = self.active_from_month.filter(|_| self.is_seasonal() && !self.is_dormant())?;
+
+        let today = *Date::now().as_ref();
+        // Windows which wrap around the end of the year may have started in
+        // the previous year
+        let year = if from_month <= today.month() { today.year() } else { today.year() - 1 };
+
+        chrono::NaiveDate::from_ymd_opt(year, from_month, 1).map(Date::from)
+    }
+
+    /// Extra points which are awarded because the chore is overdue
+    pub fn get_current_bounty(&self) -> u32 {
+        if self.is_completed() || self.is_dormant() {
+            return 0;
+        }
+
+        let (next_due_date, bounty_points_per_day) = match (self.next_due_date, self.bounty_points_per_day) {
+            (Some(next_due_date), Some(bounty_points_per_day)) => (next_due_date, bounty_points_per_day),
+            _ => return 0,
+        };
+
+        let overdue_days = Date::now().as_ref().signed_duration_since(*next_due_date.as_ref()).num_days();
+        if overdue_days <= 0 {
+            return 0;
+        }
+
+        let bounty = (overdue_days as u32).saturating_mul(bounty_points_per_day);
+
+        match self.bounty_max_points {
+            Some(bounty_max_points) => bounty.min(bounty_max_points),
+            None => bounty,
+        }
+    }
+
+    pub fn get_current_points(&self) -> u32 {
+        self.points.saturating_add(self.get_current_bounty())
+    }
+
+    /// Points the chore yields per day on average if it is done whenever it
+    /// comes due. Chores without an interval are not taken into account.
+    pub fn get_expected_points_per_day(&self) -> f64 {
+        if self.is_deleted() || self.is_one_off || self.is_dormant() {
+            return 0.0;
+        }
+
+        match (self.interval_hours, self.interval_days) {
+            (Some(interval_hours), _) if interval_hours > 0 => self.points as f64 * 24.0 / interval_hours as f64,
+            (None, Some(interval_days)) if interval_days > 0 => self.points as f64 / interval_days as f64,
+            _ => 0.0,
+        }
+    }
+
+    /// Points per unit for an activity that completed `done_items` of
+    /// `total_items` checklist items
+    pub fn get_points_for_checklist(&self, done_items: usize, total_items: usize) -> u32 {
+        self.scale_for_checklist(self.points, done_items, total_items)
+    }
+
+    /// Current bounty for an activity that completed `done_items` of
+    /// `total_items` checklist items
+    pub fn get_current_bounty_for_checklist(&self, done_items: usize, total_items: usize) -> u32 {
+        self.scale_for_checklist(self.get_current_bounty(), done_items, total_items)
+    }
+
+    /// Adjusts points that were awarded for `previous_done_items` of
+    /// `previous_total_items` checklist items to `done_items` of
+    /// `total_items`, e.g. after the checklist of an activity was edited.
+    ///
+    /// Returns None if the full points can't be derived because no item was
+    /// done before
+    pub fn rescale_for_checklist(
+        &self,
+        points: u32,
+        (previous_done_items, previous_total_items): (usize, usize),
+        (done_items, total_items): (usize, usize),
+    ) -> Option<u32> {
+        let full_points = if !self.award_partial_checklist_points || previous_total_items == 0 {
+            points
+        } else if previous_done_items == 0 {
+            return None;
+        } else {
+            (points as f64 * previous_total_items as f64 / previous_done_items as f64).round() as u32
+        };
+
+        Some(self.scale_for_checklist(full_points, done_items, total_items))
+    }
+
+    fn scale_for_checklist(&self, points: u32, done_items: usize, total_items: usize) -> u32 {
+        if !self.award_partial_checklist_points || total_items == 0 {
+            return points;
+        }
+
+        (points as f64 * done_items as f64 / total_items as f64).round() as u32
     }
 
     pub fn is_deleted(&self) -> bool {
@@ -50,9 +264,77 @@ pub async fn get_all_for_chore_list(
         .await
 }
 
+/// Only returns chores that have not been deleted
+pub async fn get_all_follow_ups(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<Chore>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chores WHERE follow_up_of_chore_id = ? AND date_deleted IS NULL ORDER BY name")
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Seasonal chores which are currently dormant are excluded
 pub async fn get_all_due(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<Chore>, sqlx::Error> {
-    sqlx::query_as("SELECT * FROM chores WHERE next_due_date IS NOT NULL AND next_due_date <= ? ORDER BY points")
+    let current_month = Date::now().as_ref().month();
+
+    sqlx::query_as("
+        SELECT * FROM chores
+        WHERE next_due_date IS NOT NULL
+            AND (next_due_date < ? OR (next_due_date = ? AND (next_due_time IS NULL OR next_due_time <= ?)))
+            AND date_completed IS NULL
+            AND (
+                active_from_month IS NULL
+                OR active_until_month IS NULL
+                OR (active_from_month <= active_until_month AND ? BETWEEN active_from_month AND active_until_month)
+                OR (active_from_month > active_until_month AND (? >= active_from_month OR ? <= active_until_month))
+            )
+        ORDER BY points
+    ")
+        .bind(Date::now())
         .bind(Date::now())
+        .bind(Time::now())
+        .bind(current_month)
+        .bind(current_month)
+        .bind(current_month)
+        .fetch_all(pool)
+        .await
+}
+
+/// Returns all chores of the user that come due within the given period.
+/// Chores the user is excluded from, deleted chores, chores of deleted chore
+/// lists and currently dormant seasonal chores are left out.
+pub async fn get_all_due_in_period_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+    start_date: Date,
+    end_date: Date,
+) -> Result<Vec<Chore>, sqlx::Error> {
+    let current_month = Date::now().as_ref().month();
+
+    sqlx::query_as("
+        SELECT chores.* FROM chores
+        INNER JOIN chore_lists ON chores.chore_list_id = chore_lists.id AND chore_lists.date_deleted IS NULL
+        LEFT JOIN chore_exclusions ON chore_exclusions.chore_id = chores.id AND chore_exclusions.user_id = ?
+        WHERE chores.date_deleted IS NULL
+            AND chore_exclusions.id IS NULL
+            AND chores.date_completed IS NULL
+            AND chores.next_due_date BETWEEN ? AND ?
+            AND (
+                chores.active_from_month IS NULL
+                OR chores.active_until_month IS NULL
+                OR (chores.active_from_month <= chores.active_until_month AND ? BETWEEN chores.active_from_month AND chores.active_until_month)
+                OR (chores.active_from_month > chores.active_until_month AND (? >= chores.active_from_month OR ? <= chores.active_until_month))
+            )
+        ORDER BY chores.next_due_date ASC, chores.next_due_time ASC, chores.name ASC
+    ")
+        .bind(user_id)
+        .bind(start_date)
+        .bind(end_date)
+        .bind(current_month)
+        .bind(current_month)
+        .bind(current_month)
         .fetch_all(pool)
         .await
 }
@@ -60,13 +342,27 @@ pub async fn get_all_due(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<Chore>,
 pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore: &Chore) -> Result<(), sqlx::Error> {
     tracing::info!(chore = ?chore, "Creating chore");
 
-    sqlx::query("INSERT INTO chores (id, chore_list_id, name, points, interval_days, next_due_date, description, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)")
+    sqlx::query("INSERT INTO chores (id, chore_list_id, name, points, points_voting_method, interval_days, interval_hours, cooldown_days, bounty_points_per_day, bounty_max_points, max_quantity, award_partial_checklist_points, follow_up_of_chore_id, follow_up_delay_days, active_from_month, active_until_month, next_due_date, next_due_time, is_one_off, date_completed, description, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
         .bind(chore.id)
         .bind(chore.chore_list_id)
         .bind(&chore.name)
         .bind(chore.points)
+        .bind(chore.points_voting_method)
         .bind(chore.interval_days)
+        .bind(chore.interval_hours)
+        .bind(chore.cooldown_days)
+        .bind(chore.bounty_points_per_day)
+        .bind(chore.bounty_max_points)
+        .bind(chore.max_quantity)
+        .bind(chore.award_partial_checklist_points)
+        .bind(chore.follow_up_of_chore_id)
+        .bind(chore.follow_up_delay_days)
+        .bind(chore.active_from_month)
+        .bind(chore.active_until_month)
         .bind(chore.next_due_date)
+        .bind(chore.next_due_time)
+        .bind(chore.is_one_off)
+        .bind(chore.date_completed)
         .bind(&chore.description)
         .bind(chore.date_created)
         .bind(chore.date_deleted)
@@ -78,12 +374,26 @@ pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore: &Chore) -> Result<()
 pub async fn update(pool: &sqlx::sqlite::SqlitePool, chore: &Chore) -> Result<(), sqlx::Error> {
     tracing::info!(chore = ?chore, "Updating chore");
 
-    sqlx::query("UPDATE chores SET chore_list_id = ?, name = ?, points = ?, interval_days = ?, next_due_date = ?, description = ?, date_deleted = ? WHERE id = ?")
+    sqlx::query("UPDATE chores SET chore_list_id = ?, name = ?, points = ?, points_voting_method = ?, interval_days = ?, interval_hours = ?, cooldown_days = ?, bounty_points_per_day = ?, bounty_max_points = ?, max_quantity = ?, award_partial_checklist_points = ?, follow_up_of_chore_id = ?, follow_up_delay_days = ?, active_from_month = ?, active_until_month = ?, next_due_date = ?, next_due_time = ?, is_one_off = ?, date_completed = ?, description = ?, date_deleted = ? WHERE id = ?")
         .bind(chore.chore_list_id)
         .bind(&chore.name)
         .bind(chore.points)
+        .bind(chore.points_voting_method)
         .bind(chore.interval_days)
+        .bind(chore.interval_hours)
+        .bind(chore.cooldown_days)
+        .bind(chore.bounty_points_per_day)
+        .bind(chore.bounty_max_points)
+        .bind(chore.max_quantity)
+        .bind(chore.award_partial_checklist_points)
+        .bind(chore.follow_up_of_chore_id)
+        .bind(chore.follow_up_delay_days)
+        .bind(chore.active_from_month)
+        .bind(chore.active_until_month)
         .bind(chore.next_due_date)
+        .bind(chore.next_due_time)
+        .bind(chore.is_one_off)
+        .bind(chore.date_completed)
         .bind(&chore.description)
         .bind(chore.date_deleted)
         .bind(chore.id)
@@ -101,3 +411,31 @@ pub async fn delete(pool: &sqlx::sqlite::SqlitePool, chore: &Chore) -> Result<()
         .await
         .map(|_| ())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util;
+
+    #[test]
+    fn active_window_can_wrap_around_end_of_year() {
+        let chore_list = test_util::make_chore_list();
+        let summer_chore = super::Chore {
+            active_from_month: Some(4),
+            active_until_month: Some(9),
+            ..test_util::make_chore(&chore_list)
+        };
+        let winter_chore = super::Chore {
+            active_from_month: Some(11),
+            active_until_month: Some(2),
+            ..test_util::make_chore(&chore_list)
+        };
+
+        assert!(summer_chore.is_active_in_month(4));
+        assert!(summer_chore.is_active_in_month(9));
+        assert!(!summer_chore.is_active_in_month(10));
+        assert!(winter_chore.is_active_in_month(12));
+        assert!(winter_chore.is_active_in_month(1));
+        assert!(!winter_chore.is_active_in_month(3));
+        assert!(!winter_chore.is_active_in_month(10));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{model::{chore::ChoreId, chore_list::ChoreListId}, value::{Date, DateTime, Uuid, Tagged}};
+use crate::{model::{chore::ChoreId, chore_list::ChoreListId}, value::{Date, DateTime, Time, Uuid, Tagged}};
 
 use super::user::UserId;
 
@@ -9,8 +9,21 @@ pub struct ChoreActivity {
     pub id: ChoreActivityId,
     pub chore_id: ChoreId,
     pub user_id: UserId,
+    /// User who recorded the activity, which differs from `user_id` for
+    /// activities of non-scoring participants
+    pub recorded_by_user_id: Option<UserId>,
     pub date: Date,
+    /// Time of day the activity was done at, if it was recorded
+    pub time: Option<Time>,
+    /// Points per unit, see `quantity`
+    pub points: u32,
+    /// Number of units for quantity-based chores, 1 otherwise
+    pub quantity: u32,
+    /// Bounty for doing the chore while it was overdue, which is awarded once
+    /// on top of the points for all units
+    pub bounty_points: u32,
     pub comment: Option<String>,
+    pub is_cooldown_violation: bool,
     pub date_created: DateTime,
     pub date_deleted: Option<DateTime>,
 }
@@ -19,6 +32,12 @@ impl ChoreActivity {
     pub fn is_deleted(&self) -> bool {
         self.date_deleted.is_some()
     }
+
+    pub fn total_points(&self) -> u32 {
+        self.points
+            .saturating_mul(self.quantity)
+            .saturating_add(self.bounty_points)
+    }
 }
 
 pub async fn get_by_id(
@@ -32,7 +51,7 @@ pub async fn get_by_id(
 }
 
 pub async fn get_all(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<ChoreActivity>, sqlx::Error> {
-    sqlx::query_as("SELECT * FROM chore_activitie ORDER BY date DESC, date_created DESC")
+    sqlx::query_as("SELECT * FROM chore_activitie ORDER BY date DESC, time DESC, date_created DESC")
         .fetch_all(pool)
         .await
 }
@@ -41,7 +60,7 @@ pub async fn get_all_for_chore(
     pool: &sqlx::sqlite::SqlitePool,
     chore_id: &ChoreId,
 ) -> Result<Vec<ChoreActivity>, sqlx::Error> {
-    sqlx::query_as("SELECT * FROM chore_activities WHERE chore_id = ? ORDER BY date DESC, date_created DESC")
+    sqlx::query_as("SELECT * FROM chore_activities WHERE chore_id = ? ORDER BY date DESC, time DESC, date_created DESC")
         .bind(chore_id)
         .fetch_all(pool)
         .await
@@ -56,7 +75,7 @@ pub async fn get_oldest_not_deleted_for_chore_list(
         FROM chore_activities
         INNER JOIN chores ON chore_activities.chore_id = chores.id
         WHERE chores.chore_list_id = ? AND chore_activities.date_deleted IS NULL
-        ORDER BY date ASC, date_created ASC
+        ORDER BY date ASC, time ASC, date_created ASC
         LIMIT 1
     ")
         .bind(chore_list_id)
@@ -68,7 +87,7 @@ pub async fn get_latest_not_deleted_for_chore(
     pool: &sqlx::sqlite::SqlitePool,
     chore_id: &ChoreId,
 ) -> Result<ChoreActivity, sqlx::Error> {
-    sqlx::query_as("SELECT * FROM chore_activities WHERE chore_id = ? AND date_deleted IS NULL ORDER BY date DESC, date_created DESC LIMIT 1")
+    sqlx::query_as("SELECT * FROM chore_activities WHERE chore_id = ? AND date_deleted IS NULL ORDER BY date DESC, time DESC, date_created DESC LIMIT 1")
         .bind(chore_id)
         .fetch_one(pool)
         .await
@@ -82,13 +101,46 @@ pub async fn get_all_for_chore_list(
         SELECT chore_activities.* FROM chore_activities
         INNER JOIN chores ON chore_activities.chore_id = chores.id
         WHERE chores.chore_list_id = ?
-        ORDER BY date DESC, date_created DESC
+        ORDER BY date DESC, time DESC, date_created DESC
+    ")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_cooldown_violations_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<ChoreActivity>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_activities.* FROM chore_activities
+        INNER JOIN chores ON chore_activities.chore_id = chores.id
+        WHERE chores.chore_list_id = ? AND chore_activities.is_cooldown_violation = TRUE
+        ORDER BY date DESC, time DESC, date_created DESC
     ")
         .bind(chore_list_id)
         .fetch_all(pool)
         .await
 }
 
+pub async fn get_all_creditable_for_chore_in_period(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+    start_date: Date,
+    end_date: Date,
+) -> Result<Vec<ChoreActivity>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT * FROM chore_activities
+        WHERE chore_id = ? AND date_deleted IS NULL AND is_cooldown_violation = FALSE AND date >= ? AND date <= ?
+        ORDER BY date DESC, time DESC, date_created DESC
+    ")
+        .bind(chore_id)
+        .bind(start_date)
+        .bind(end_date)
+        .fetch_all(pool)
+        .await
+}
+
 pub async fn get_all_for_chore_list_and_user(
     pool: &sqlx::sqlite::SqlitePool,
     chore_list_id: &ChoreListId,
@@ -99,7 +151,7 @@ pub async fn get_all_for_chore_list_and_user(
         INNER JOIN chores ON chore_activities.chore_id = chores.id
         INNER JOIN users ON chore_activities.user_id = users.id
         WHERE chores.chore_list_id = ? AND users.id = ?
-        ORDER BY date DESC, date_created DESC
+        ORDER BY date DESC, time DESC, date_created DESC
     ")
         .bind(chore_list_id)
         .bind(user_id)
@@ -113,12 +165,18 @@ pub async fn create(
 ) -> Result<(), sqlx::Error> {
     tracing::info!(chore_activity = ?chore_activity, "Creating chore activity");
 
-    sqlx::query("INSERT INTO chore_activities (id, chore_id, user_id, date, comment, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?)")
+    sqlx::query("INSERT INTO chore_activities (id, chore_id, user_id, recorded_by_user_id, date, time, points, quantity, bounty_points, comment, is_cooldown_violation, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
         .bind(chore_activity.id)
         .bind(chore_activity.chore_id)
         .bind(chore_activity.user_id)
+        .bind(chore_activity.recorded_by_user_id)
         .bind(chore_activity.date)
+        .bind(chore_activity.time)
+        .bind(chore_activity.points)
+        .bind(chore_activity.quantity)
+        .bind(chore_activity.bounty_points)
         .bind(&chore_activity.comment)
+        .bind(chore_activity.is_cooldown_violation)
         .bind(chore_activity.date_created)
         .bind(chore_activity.date_deleted)
         .execute(pool)
@@ -132,11 +190,16 @@ pub async fn update(
 ) -> Result<(), sqlx::Error> {
     tracing::info!(chore_activity = ?chore_activity, "Updating chore activity");
 
-    sqlx::query("UPDATE chore_activities SET chore_id = ?, user_id = ?, date = ?, comment = ?, date_deleted = ? WHERE id = ?")
+    sqlx::query("UPDATE chore_activities SET chore_id = ?, user_id = ?, date = ?, time = ?, points = ?, quantity = ?, bounty_points = ?, comment = ?, is_cooldown_violation = ?, date_deleted = ? WHERE id = ?")
         .bind(chore_activity.chore_id)
         .bind(chore_activity.user_id)
         .bind(chore_activity.date)
+        .bind(chore_activity.time)
+        .bind(chore_activity.points)
+        .bind(chore_activity.quantity)
+        .bind(chore_activity.bounty_points)
         .bind(&chore_activity.comment)
+        .bind(chore_activity.is_cooldown_violation)
         .bind(chore_activity.date_deleted)
         .bind(chore_activity.id)
         .execute(pool)
@@ -164,6 +227,7 @@ pub fn group_and_sort_by_date(
     activities.sort_by(|a, b| {
         a.date
             .cmp(&b.date)
+            .then_with(|| a.time.cmp(&b.time))
             .then_with(|| a.date_created.cmp(&b.date_created))
     });
     if sort_latest_first {
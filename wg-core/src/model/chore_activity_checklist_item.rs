@@ -0,0 +1,55 @@
+use crate::model::chore_activity::ChoreActivityId;
+use crate::value::{Tagged, Uuid};
+
+pub type ChoreActivityChecklistItemId = Tagged<Uuid, ChoreActivityChecklistItem>;
+
+/// Snapshot of a chore checklist item at the time an activity was logged, so
+/// later changes to the checklist don't alter the history
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChoreActivityChecklistItem {
+    pub id: ChoreActivityChecklistItemId,
+    pub chore_activity_id: ChoreActivityId,
+    pub position: u32,
+    pub name: String,
+    pub is_done: bool,
+}
+
+pub async fn get_all_for_chore_activity(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_activity_id: &ChoreActivityId,
+) -> Result<Vec<ChoreActivityChecklistItem>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_activity_checklist_items WHERE chore_activity_id = ? ORDER BY position ASC")
+        .bind(chore_activity_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_activity_checklist_item: &ChoreActivityChecklistItem,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_activity_checklist_item = ?chore_activity_checklist_item, "Creating chore activity checklist item");
+
+    sqlx::query("INSERT INTO chore_activity_checklist_items (id, chore_activity_id, position, name, is_done) VALUES (?, ?, ?, ?, ?)")
+        .bind(chore_activity_checklist_item.id)
+        .bind(chore_activity_checklist_item.chore_activity_id)
+        .bind(chore_activity_checklist_item.position)
+        .bind(&chore_activity_checklist_item.name)
+        .bind(chore_activity_checklist_item.is_done)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_for_chore_activity(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_activity_id: &ChoreActivityId,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_activity_id = ?chore_activity_id, "Deleting all checklist items of chore activity");
+
+    sqlx::query("DELETE FROM chore_activity_checklist_items WHERE chore_activity_id = ?")
+        .bind(chore_activity_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
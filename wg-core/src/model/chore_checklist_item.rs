@@ -0,0 +1,64 @@
+use crate::model::chore::ChoreId;
+use crate::model::chore_list::ChoreListId;
+use crate::value::{DateTime, Tagged, Uuid};
+
+pub type ChoreChecklistItemId = Tagged<Uuid, ChoreChecklistItem>;
+
+/// An ordered sub-step of a chore
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChoreChecklistItem {
+    pub id: ChoreChecklistItemId,
+    pub chore_id: ChoreId,
+    pub position: u32,
+    pub name: String,
+    pub date_created: DateTime,
+}
+
+pub async fn get_all_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<ChoreChecklistItem>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_checklist_items WHERE chore_id = ? ORDER BY position ASC")
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<ChoreChecklistItem>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_checklist_items.* FROM chore_checklist_items
+        INNER JOIN chores ON chore_checklist_items.chore_id = chores.id
+        WHERE chores.chore_list_id = ?
+        ORDER BY chore_checklist_items.position ASC
+    ")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore_checklist_item: &ChoreChecklistItem) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_checklist_item = ?chore_checklist_item, "Creating chore checklist item");
+
+    sqlx::query("INSERT INTO chore_checklist_items (id, chore_id, position, name, date_created) VALUES (?, ?, ?, ?, ?)")
+        .bind(chore_checklist_item.id)
+        .bind(chore_checklist_item.chore_id)
+        .bind(chore_checklist_item.position)
+        .bind(&chore_checklist_item.name)
+        .bind(chore_checklist_item.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_for_chore(pool: &sqlx::sqlite::SqlitePool, chore_id: &ChoreId) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_id = ?chore_id, "Deleting all chore checklist items of chore");
+
+    sqlx::query("DELETE FROM chore_checklist_items WHERE chore_id = ?")
+        .bind(chore_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,70 @@
+use crate::model::chore::ChoreId;
+use crate::model::chore_list::ChoreListId;
+use crate::model::user::UserId;
+use crate::value::{DateTime, Tagged, Uuid};
+
+pub type ChoreExclusionId = Tagged<Uuid, ChoreExclusion>;
+
+/// Marks a chore as something the user can't do. The chore is left out of
+/// their reminders and doesn't count towards the points available to them.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChoreExclusion {
+    pub id: ChoreExclusionId,
+    pub chore_id: ChoreId,
+    pub user_id: UserId,
+    pub date_created: DateTime,
+}
+
+pub async fn get_all(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<ChoreExclusion>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_exclusions ORDER BY date_created ASC")
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<ChoreExclusion>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_exclusions WHERE chore_id = ? ORDER BY date_created ASC")
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<ChoreExclusion>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_exclusions.* FROM chore_exclusions
+        INNER JOIN chores ON chore_exclusions.chore_id = chores.id
+        WHERE chores.chore_list_id = ?
+        ORDER BY chore_exclusions.date_created ASC
+    ")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore_exclusion: &ChoreExclusion) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_exclusion = ?chore_exclusion, "Creating chore exclusion");
+
+    sqlx::query("INSERT INTO chore_exclusions (id, chore_id, user_id, date_created) VALUES (?, ?, ?, ?)")
+        .bind(chore_exclusion.id)
+        .bind(chore_exclusion.chore_id)
+        .bind(chore_exclusion.user_id)
+        .bind(chore_exclusion.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(pool: &sqlx::sqlite::SqlitePool, chore_exclusion: &ChoreExclusion) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_exclusion = ?chore_exclusion, "Deleting chore exclusion");
+
+    sqlx::query("DELETE FROM chore_exclusions WHERE id = ?")
+        .bind(chore_exclusion.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -89,6 +89,7 @@ pub async fn get_all(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<ChoreList>,
         .await
 }
 
+/// Non-scoring participants are left out
 pub async fn get_score_per_user(
     pool: &sqlx::sqlite::SqlitePool,
     chore_list: &ChoreList,
@@ -100,21 +101,46 @@ pub async fn get_score_per_user(
     sqlx::query_as::<_, (UserId, i32)>("
         SELECT users.id as user_id, COALESCE(total_score, 0) as total_score
         FROM (
-            SELECT users.id as user_id, SUM(chores.points) as total_score
-            FROM chore_activities
-            INNER JOIN chores ON chore_activities.chore_id = chores.id AND chores.date_deleted IS NULL
-            INNER JOIN chore_lists ON chores.chore_list_id = chore_lists.id AND chore_lists.date_deleted IS NULL
-            INNER JOIN users ON chore_activities.user_id = users.id AND users.date_deleted IS NULL
-            WHERE chore_activities.date_deleted IS NULL
-                AND chore_lists.id = ?
-                AND (? IS NULL OR chore_activities.date >= ?)
-                AND (? IS NULL OR chore_activities.date <= ?)
-            GROUP BY users.id
+            SELECT user_id, SUM(points) as total_score
+            FROM (
+                SELECT chore_activities.user_id as user_id, chore_activities.points * chore_activities.quantity + chore_activities.bounty_points as points, chore_activities.date as date
+                FROM chore_activities
+                INNER JOIN chores ON chore_activities.chore_id = chores.id AND chores.date_deleted IS NULL
+                INNER JOIN chore_lists ON chores.chore_list_id = chore_lists.id AND chore_lists.date_deleted IS NULL
+                WHERE chore_activities.date_deleted IS NULL
+                    AND chore_activities.is_cooldown_violation = FALSE
+                    AND chore_lists.id = ?
+
+                UNION ALL
+
+                SELECT chore_swap_requests.target_user_id as user_id, chore_swap_requests.offered_points as points, chore_activities.date as date
+                FROM chore_swap_requests
+                INNER JOIN chore_activities ON chore_swap_requests.chore_activity_id = chore_activities.id AND chore_activities.chore_id = chore_swap_requests.chore_id AND chore_activities.date_deleted IS NULL AND chore_activities.is_cooldown_violation = FALSE
+                INNER JOIN chores ON chore_activities.chore_id = chores.id AND chores.date_deleted IS NULL
+                INNER JOIN chore_lists ON chores.chore_list_id = chore_lists.id AND chore_lists.date_deleted IS NULL
+                WHERE chore_swap_requests.status = 'Completed'
+                    AND chore_lists.id = ?
+
+                UNION ALL
+
+                SELECT chore_swap_requests.requesting_user_id as user_id, -chore_swap_requests.offered_points as points, chore_activities.date as date
+                FROM chore_swap_requests
+                INNER JOIN chore_activities ON chore_swap_requests.chore_activity_id = chore_activities.id AND chore_activities.chore_id = chore_swap_requests.chore_id AND chore_activities.date_deleted IS NULL AND chore_activities.is_cooldown_violation = FALSE
+                INNER JOIN chores ON chore_activities.chore_id = chores.id AND chores.date_deleted IS NULL
+                INNER JOIN chore_lists ON chores.chore_list_id = chore_lists.id AND chore_lists.date_deleted IS NULL
+                WHERE chore_swap_requests.status = 'Completed'
+                    AND chore_lists.id = ?
+            )
+            WHERE (? IS NULL OR date >= ?)
+                AND (? IS NULL OR date <= ?)
+            GROUP BY user_id
         )
         RIGHT JOIN users ON user_id = users.id
-        WHERE users.date_deleted IS NULL
+        WHERE users.date_deleted IS NULL AND users.kind = 'Member'
         ORDER BY total_score DESC
     ")
+        .bind(chore_list.id)
+        .bind(chore_list.id)
         .bind(chore_list.id)
         .bind(interval_start_date)
         .bind(interval_start_date)
@@ -125,6 +151,75 @@ pub async fn get_score_per_user(
         .map(|r| r.into_iter().collect())
 }
 
+/// Points that can be spent in the rewards shop. In contrast to the score,
+/// the balance is never reset and is reduced by every reward redemption.
+/// Points offered in open swap requests are reserved, so that they can't be
+/// spent before the request is completed.
+pub async fn get_balance_per_user(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    chore_list: &ChoreList,
+) -> Result<Vec<(UserId, i32)>, sqlx::Error> {
+    sqlx::query_as::<_, (UserId, i32)>("
+        SELECT users.id as user_id, COALESCE(earned_points, 0) - COALESCE(spent_points, 0) as balance
+        FROM users
+        LEFT JOIN (
+            SELECT user_id, SUM(points) as earned_points
+            FROM (
+                SELECT chore_activities.user_id as user_id, chore_activities.points * chore_activities.quantity + chore_activities.bounty_points as points
+                FROM chore_activities
+                INNER JOIN chores ON chore_activities.chore_id = chores.id
+                WHERE chore_activities.date_deleted IS NULL
+                    AND chore_activities.is_cooldown_violation = FALSE
+                    AND chores.chore_list_id = ?
+
+                UNION ALL
+
+                SELECT chore_swap_requests.target_user_id as user_id, chore_swap_requests.offered_points as points
+                FROM chore_swap_requests
+                INNER JOIN chore_activities ON chore_swap_requests.chore_activity_id = chore_activities.id AND chore_activities.chore_id = chore_swap_requests.chore_id AND chore_activities.date_deleted IS NULL AND chore_activities.is_cooldown_violation = FALSE
+                INNER JOIN chores ON chore_activities.chore_id = chores.id
+                WHERE chore_swap_requests.status = 'Completed'
+                    AND chores.chore_list_id = ?
+
+                UNION ALL
+
+                SELECT chore_swap_requests.requesting_user_id as user_id, -chore_swap_requests.offered_points as points
+                FROM chore_swap_requests
+                INNER JOIN chore_activities ON chore_swap_requests.chore_activity_id = chore_activities.id AND chore_activities.chore_id = chore_swap_requests.chore_id AND chore_activities.date_deleted IS NULL AND chore_activities.is_cooldown_violation = FALSE
+                INNER JOIN chores ON chore_activities.chore_id = chores.id
+                WHERE chore_swap_requests.status = 'Completed'
+                    AND chores.chore_list_id = ?
+
+                UNION ALL
+
+                SELECT chore_swap_requests.requesting_user_id as user_id, -chore_swap_requests.offered_points as points
+                FROM chore_swap_requests
+                INNER JOIN chores ON chore_swap_requests.chore_id = chores.id
+                WHERE chore_swap_requests.status IN ('Pending', 'Accepted')
+                    AND chores.chore_list_id = ?
+            )
+            GROUP BY user_id
+        ) earned ON earned.user_id = users.id
+        LEFT JOIN (
+            SELECT reward_redemptions.user_id as user_id, SUM(reward_redemptions.points) as spent_points
+            FROM reward_redemptions
+            INNER JOIN rewards ON reward_redemptions.reward_id = rewards.id
+            WHERE reward_redemptions.date_deleted IS NULL
+                AND rewards.chore_list_id = ?
+            GROUP BY reward_redemptions.user_id
+        ) spent ON spent.user_id = users.id
+        WHERE users.date_deleted IS NULL AND users.kind = 'Member'
+        ORDER BY balance DESC
+    ")
+        .bind(chore_list.id)
+        .bind(chore_list.id)
+        .bind(chore_list.id)
+        .bind(chore_list.id)
+        .bind(chore_list.id)
+        .fetch_all(executor)
+        .await
+}
+
 pub async fn create(
     pool: &sqlx::sqlite::SqlitePool,
     chore_list: &ChoreList,
@@ -172,3 +267,34 @@ pub async fn delete(
         .await
         .map(|_| ())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{model::chore_activity, test_util, value::DateTime};
+
+    #[tokio::test]
+    async fn score_counts_quantity_and_bounty_but_not_cooldown_violations() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let alice = test_util::create_user(&pool, "Alice").await;
+        let bob = test_util::create_user(&pool, "Bob").await;
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+
+        let mut activity = test_util::create_activity(&pool, &chore, &alice, test_util::date(2026, 3, 1)).await;
+        activity.quantity = 2;
+        activity.bounty_points = 3;
+        chore_activity::update(&pool, &activity).await.unwrap();
+
+        let mut violating_activity = test_util::create_activity(&pool, &chore, &alice, test_util::date(2026, 3, 2)).await;
+        violating_activity.is_cooldown_violation = true;
+        chore_activity::update(&pool, &violating_activity).await.unwrap();
+
+        let mut deleted_activity = test_util::create_activity(&pool, &chore, &bob, test_util::date(2026, 3, 3)).await;
+        deleted_activity.date_deleted = Some(DateTime::now());
+        chore_activity::update(&pool, &deleted_activity).await.unwrap();
+
+        let score_per_user = super::get_score_per_user(&pool, &chore_list).await.unwrap();
+
+        assert_eq!(score_per_user, vec![(alice.id, 23), (bob.id, 0)]);
+    }
+}
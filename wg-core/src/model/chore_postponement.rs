@@ -0,0 +1,80 @@
+use crate::model::chore::ChoreId;
+use crate::model::user::UserId;
+use crate::value::{Date, DateTime, Tagged, Uuid};
+
+pub type ChorePostponementId = Tagged<Uuid, ChorePostponement>;
+
+/// Chores can be snoozed for up to a year
+pub const MAX_SNOOZE_DAYS: u32 = 365;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    strum::EnumString,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+pub enum ChorePostponementKind {
+    /// The due occurrence is skipped and the chore is due one interval later
+    Skip,
+    /// The chore is due again after a number of days
+    Snooze,
+}
+
+/// Moves the next due date of a chore without awarding any points. It is
+/// taken into account until the next activity is recorded for the chore.
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChorePostponement {
+    pub id: ChorePostponementId,
+    pub chore_id: ChoreId,
+    pub user_id: UserId,
+    pub kind: ChorePostponementKind,
+    pub previous_due_date: Date,
+    pub new_due_date: Date,
+    pub reason: Option<String>,
+    pub date_created: DateTime,
+}
+
+pub async fn get_all_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<ChorePostponement>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_postponements WHERE chore_id = ? ORDER BY date_created DESC")
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_latest_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<ChorePostponement, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_postponements WHERE chore_id = ? ORDER BY date_created DESC LIMIT 1")
+        .bind(chore_id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore_postponement: &ChorePostponement) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_postponement = ?chore_postponement, "Creating chore postponement");
+
+    sqlx::query("INSERT INTO chore_postponements (id, chore_id, user_id, kind, previous_due_date, new_due_date, reason, date_created) VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(chore_postponement.id)
+        .bind(chore_postponement.chore_id)
+        .bind(chore_postponement.user_id)
+        .bind(chore_postponement.kind)
+        .bind(chore_postponement.previous_due_date)
+        .bind(chore_postponement.new_due_date)
+        .bind(&chore_postponement.reason)
+        .bind(chore_postponement.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,203 @@
+use crate::model::absence::AbsenceId;
+use crate::model::chore::ChoreId;
+use crate::model::chore_activity::ChoreActivityId;
+use crate::model::chore_list::ChoreListId;
+use crate::model::user::UserId;
+use crate::value::{DateTime, Tagged, Uuid};
+
+pub type ChoreSwapRequestId = Tagged<Uuid, ChoreSwapRequest>;
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    strum::EnumString,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+pub enum ChoreSwapRequestStatus {
+    /// Waiting for the target user to accept or decline
+    Pending,
+    /// Accepted by the target user, but the chore has not been logged by them yet
+    Accepted,
+    Declined,
+    Cancelled,
+    /// The target user logged the chore and the offered points have been transferred
+    Completed,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChoreSwapRequest {
+    pub id: ChoreSwapRequestId,
+    pub chore_id: ChoreId,
+    pub requesting_user_id: UserId,
+    /// Not set for handover offers, which can be accepted by anyone
+    pub target_user_id: Option<UserId>,
+    /// Set if the chore was offered because of an absence of the requesting user
+    pub absence_id: Option<AbsenceId>,
+    pub offered_points: u32,
+    pub comment: Option<String>,
+    pub status: ChoreSwapRequestStatus,
+    pub chore_activity_id: Option<ChoreActivityId>,
+    pub date_created: DateTime,
+    pub date_updated: DateTime,
+}
+
+impl ChoreSwapRequest {
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, ChoreSwapRequestStatus::Pending | ChoreSwapRequestStatus::Accepted)
+    }
+
+    pub fn is_handover_offer(&self) -> bool {
+        self.target_user_id.is_none()
+    }
+}
+
+pub async fn get_by_id(
+    pool: &sqlx::sqlite::SqlitePool,
+    id: &ChoreSwapRequestId,
+) -> Result<ChoreSwapRequest, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_swap_requests WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_all_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<ChoreSwapRequest>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_swap_requests.* FROM chore_swap_requests
+        INNER JOIN chores ON chore_swap_requests.chore_id = chores.id
+        WHERE chores.chore_list_id = ?
+        ORDER BY chore_swap_requests.date_created DESC
+    ")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_pending_handover_offers_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<ChoreSwapRequest>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_swap_requests.* FROM chore_swap_requests
+        INNER JOIN chores ON chore_swap_requests.chore_id = chores.id AND chores.date_deleted IS NULL
+        WHERE chores.chore_list_id = ? AND chore_swap_requests.target_user_id IS NULL AND chore_swap_requests.status = ?
+        ORDER BY chore_swap_requests.date_created ASC
+    ")
+        .bind(chore_list_id)
+        .bind(ChoreSwapRequestStatus::Pending)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_for_absence(
+    pool: &sqlx::sqlite::SqlitePool,
+    absence_id: &AbsenceId,
+) -> Result<Vec<ChoreSwapRequest>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_swap_requests WHERE absence_id = ? ORDER BY date_created ASC")
+        .bind(absence_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Pending and accepted requests
+pub async fn get_all_open_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<ChoreSwapRequest>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT * FROM chore_swap_requests
+        WHERE chore_id = ? AND status IN (?, ?)
+        ORDER BY date_created ASC
+    ")
+        .bind(chore_id)
+        .bind(ChoreSwapRequestStatus::Pending)
+        .bind(ChoreSwapRequestStatus::Accepted)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_accepted_for_chore_and_target_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+    target_user_id: &UserId,
+) -> Result<Vec<ChoreSwapRequest>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT * FROM chore_swap_requests
+        WHERE chore_id = ? AND target_user_id = ? AND status = ?
+        ORDER BY date_created ASC
+    ")
+        .bind(chore_id)
+        .bind(target_user_id)
+        .bind(ChoreSwapRequestStatus::Accepted)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_swap_request: &ChoreSwapRequest,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_swap_request = ?chore_swap_request, "Creating chore swap request");
+
+    sqlx::query("INSERT INTO chore_swap_requests (id, chore_id, requesting_user_id, target_user_id, absence_id, offered_points, comment, status, chore_activity_id, date_created, date_updated) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(chore_swap_request.id)
+        .bind(chore_swap_request.chore_id)
+        .bind(chore_swap_request.requesting_user_id)
+        .bind(chore_swap_request.target_user_id)
+        .bind(chore_swap_request.absence_id)
+        .bind(chore_swap_request.offered_points)
+        .bind(&chore_swap_request.comment)
+        .bind(chore_swap_request.status)
+        .bind(chore_swap_request.chore_activity_id)
+        .bind(chore_swap_request.date_created)
+        .bind(chore_swap_request.date_updated)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn update(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_swap_request: &ChoreSwapRequest,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_swap_request = ?chore_swap_request, "Updating chore swap request");
+
+    sqlx::query("UPDATE chore_swap_requests SET chore_id = ?, requesting_user_id = ?, target_user_id = ?, absence_id = ?, offered_points = ?, comment = ?, status = ?, chore_activity_id = ?, date_updated = ? WHERE id = ?")
+        .bind(chore_swap_request.chore_id)
+        .bind(chore_swap_request.requesting_user_id)
+        .bind(chore_swap_request.target_user_id)
+        .bind(chore_swap_request.absence_id)
+        .bind(chore_swap_request.offered_points)
+        .bind(&chore_swap_request.comment)
+        .bind(chore_swap_request.status)
+        .bind(chore_swap_request.chore_activity_id)
+        .bind(chore_swap_request.date_updated)
+        .bind(chore_swap_request.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_swap_request: &ChoreSwapRequest,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_swap_request = ?chore_swap_request, "Deleting chore swap request");
+
+    sqlx::query("DELETE FROM chore_swap_requests WHERE id = ?")
+        .bind(chore_swap_request.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
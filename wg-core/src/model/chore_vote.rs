@@ -0,0 +1,92 @@
+use crate::model::chore::ChoreId;
+use crate::model::user::UserId;
+use crate::value::{DateTime, Tagged, Uuid};
+
+pub type ChoreVoteId = Tagged<Uuid, ChoreVote>;
+
+/// Highest amount of points a user can vote for
+pub const MAX_POINTS: u32 = 1000;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct ChoreVote {
+    pub id: ChoreVoteId,
+    pub chore_id: ChoreId,
+    pub user_id: UserId,
+    pub points: u32,
+    pub date_created: DateTime,
+    pub date_updated: DateTime,
+}
+
+pub async fn get_by_id(pool: &sqlx::sqlite::SqlitePool, id: &ChoreVoteId) -> Result<ChoreVote, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_votes WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_by_chore_and_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+    user_id: &UserId,
+) -> Result<ChoreVote, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM chore_votes WHERE chore_id = ? AND user_id = ?")
+        .bind(chore_id)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+}
+
+/// Only returns votes of users that have not been deleted
+pub async fn get_all_for_chore(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_id: &ChoreId,
+) -> Result<Vec<ChoreVote>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT chore_votes.* FROM chore_votes
+        INNER JOIN users ON chore_votes.user_id = users.id AND users.date_deleted IS NULL
+        WHERE chore_votes.chore_id = ?
+        ORDER BY chore_votes.date_created ASC
+    ")
+        .bind(chore_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(pool: &sqlx::sqlite::SqlitePool, chore_vote: &ChoreVote) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_vote = ?chore_vote, "Creating chore vote");
+
+    sqlx::query("INSERT INTO chore_votes (id, chore_id, user_id, points, date_created, date_updated) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(chore_vote.id)
+        .bind(chore_vote.chore_id)
+        .bind(chore_vote.user_id)
+        .bind(chore_vote.points)
+        .bind(chore_vote.date_created)
+        .bind(chore_vote.date_updated)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn update(pool: &sqlx::sqlite::SqlitePool, chore_vote: &ChoreVote) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_vote = ?chore_vote, "Updating chore vote");
+
+    sqlx::query("UPDATE chore_votes SET chore_id = ?, user_id = ?, points = ?, date_updated = ? WHERE id = ?")
+        .bind(chore_vote.chore_id)
+        .bind(chore_vote.user_id)
+        .bind(chore_vote.points)
+        .bind(chore_vote.date_updated)
+        .bind(chore_vote.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(pool: &sqlx::sqlite::SqlitePool, chore_vote: &ChoreVote) -> Result<(), sqlx::Error> {
+    tracing::info!(chore_vote = ?chore_vote, "Deleting chore vote");
+
+    sqlx::query("DELETE FROM chore_votes WHERE id = ?")
+        .bind(chore_vote.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
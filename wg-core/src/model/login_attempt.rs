@@ -0,0 +1,65 @@
+use crate::value::{DateTime, Tagged, Uuid};
+
+use super::user::UserId;
+
+pub type LoginAttemptId = Tagged<Uuid, LoginAttempt>;
+
+/// Only attempts for existing accounts are recorded
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct LoginAttempt {
+    pub id: LoginAttemptId,
+    pub user_id: UserId,
+    pub is_successful: bool,
+    pub user_agent: Option<String>,
+    pub ip_address: String,
+    pub date_created: DateTime,
+}
+
+pub async fn get_latest_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+    limit: u32,
+) -> Result<Vec<LoginAttempt>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM login_attempts WHERE user_id = ? ORDER BY date_created DESC LIMIT ?")
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn get_all_successful_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<Vec<LoginAttempt>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM login_attempts WHERE user_id = ? AND is_successful = true")
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    login_attempt: &LoginAttempt,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO login_attempts (id, user_id, is_successful, user_agent, ip_address, date_created) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(login_attempt.id)
+        .bind(login_attempt.user_id)
+        .bind(login_attempt.is_successful)
+        .bind(&login_attempt.user_agent)
+        .bind(&login_attempt.ip_address)
+        .bind(login_attempt.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_older_than(
+    pool: &sqlx::sqlite::SqlitePool,
+    date: DateTime,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM login_attempts WHERE date_created < ?")
+        .bind(date)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,94 @@
+use crate::value::{DateTime, Tagged, TokenHash, Uuid};
+
+use super::user::UserId;
+
+pub type LoginTokenId = Tagged<Uuid, LoginToken>;
+
+/// Token of a magic login link, which is deleted once it has been used
+#[derive(Clone, sqlx::FromRow)]
+pub struct LoginToken {
+    pub id: LoginTokenId,
+    pub token_hash: TokenHash,
+    pub user_id: UserId,
+    pub date_expires: DateTime,
+    pub date_created: DateTime,
+}
+
+impl LoginToken {
+    pub fn is_expired(&self) -> bool {
+        self.date_expires.as_ref() < DateTime::now().as_ref()
+    }
+}
+
+/// Deletes the token in the same statement, so that concurrent requests
+/// can't both use it
+pub async fn consume_by_token(
+    pool: &sqlx::sqlite::SqlitePool,
+    token: &str,
+) -> Result<LoginToken, sqlx::Error> {
+    sqlx::query_as("DELETE FROM login_tokens WHERE token_hash = ? RETURNING *")
+        .bind(TokenHash::from_token(token))
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_latest_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<LoginToken, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM login_tokens WHERE user_id = ? ORDER BY date_created DESC LIMIT 1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    login_token: &LoginToken,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO login_tokens (id, token_hash, user_id, date_expires, date_created) VALUES (?, ?, ?, ?, ?)")
+        .bind(login_token.id)
+        .bind(&login_token.token_hash)
+        .bind(login_token.user_id)
+        .bind(login_token.date_expires)
+        .bind(login_token.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_expired(pool: &sqlx::sqlite::SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM login_tokens WHERE date_expires < ?")
+        .bind(DateTime::now())
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test_util, value::{DateTime, TokenHash}};
+    use super::{LoginToken, LoginTokenId};
+
+    #[tokio::test]
+    async fn token_can_only_be_consumed_once() {
+        let pool = test_util::create_pool().await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let login_token = LoginToken {
+            id: LoginTokenId::new(),
+            token_hash: TokenHash::from_token("secret"),
+            user_id: user.id,
+            date_expires: DateTime::now(),
+            date_created: DateTime::now(),
+        };
+        super::create(&pool, &login_token).await.unwrap();
+
+        let (first, second) = tokio::join!(
+            super::consume_by_token(&pool, "secret"),
+            super::consume_by_token(&pool, "secret"),
+        );
+
+        assert_eq!(first.unwrap().id, login_token.id);
+        assert!(matches!(second, Err(sqlx::Error::RowNotFound)));
+    }
+}
@@ -1,6 +1,21 @@
 pub mod authentication_session;
 pub mod chore;
 pub mod chore_activity;
+pub mod chore_activity_checklist_item;
+pub mod chore_checklist_item;
+pub mod chore_exclusion;
 pub mod chore_list;
+pub mod chore_postponement;
+pub mod chore_swap_request;
+pub mod chore_vote;
+pub mod login_attempt;
+pub mod login_token;
+pub mod passkey;
+pub mod passkey_challenge;
+pub mod password_reset_token;
+pub mod reward;
+pub mod reward_redemption;
 pub mod user;
+pub mod user_invitation;
+pub mod user_recovery_code;
 pub mod absence;
@@ -0,0 +1,99 @@
+use crate::value::{DateTime, Tagged, Uuid};
+
+use super::user::UserId;
+
+pub type PasskeyId = Tagged<Uuid, Passkey>;
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Passkey {
+    pub id: PasskeyId,
+    pub user_id: UserId,
+    /// Given by the user to tell their passkeys apart
+    pub name: String,
+    /// Base64url encoded id assigned by the authenticator
+    pub credential_id: String,
+    /// DER encoded SubjectPublicKeyInfo of the ES256 key
+    pub public_key: Vec<u8>,
+    pub sign_count: u32,
+    pub date_last_used: Option<DateTime>,
+    pub date_created: DateTime,
+}
+
+pub async fn get_by_id(
+    pool: &sqlx::sqlite::SqlitePool,
+    id: &PasskeyId,
+) -> Result<Passkey, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM passkeys WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_by_credential_id(
+    pool: &sqlx::sqlite::SqlitePool,
+    credential_id: &str,
+) -> Result<Passkey, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM passkeys WHERE credential_id = ?")
+        .bind(credential_id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_all_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<Vec<Passkey>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM passkeys WHERE user_id = ? ORDER BY date_created")
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    passkey: &Passkey,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(passkey = ?passkey, "Creating passkey");
+
+    sqlx::query("INSERT INTO passkeys (id, user_id, name, credential_id, public_key, sign_count, date_last_used, date_created) VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(passkey.id)
+        .bind(passkey.user_id)
+        .bind(&passkey.name)
+        .bind(&passkey.credential_id)
+        .bind(&passkey.public_key)
+        .bind(passkey.sign_count)
+        .bind(passkey.date_last_used)
+        .bind(passkey.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn update(
+    pool: &sqlx::sqlite::SqlitePool,
+    passkey: &Passkey,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(passkey = ?passkey, "Updating passkey");
+
+    sqlx::query("UPDATE passkeys SET name = ?, sign_count = ?, date_last_used = ? WHERE id = ?")
+        .bind(&passkey.name)
+        .bind(passkey.sign_count)
+        .bind(passkey.date_last_used)
+        .bind(passkey.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(
+    pool: &sqlx::sqlite::SqlitePool,
+    passkey: &Passkey,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(passkey = ?passkey, "Deleting passkey");
+
+    sqlx::query("DELETE FROM passkeys WHERE id = ?")
+        .bind(passkey.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,95 @@
+use crate::value::{DateTime, Tagged, Uuid};
+
+use super::user::UserId;
+
+pub type PasskeyChallengeId = Tagged<Uuid, PasskeyChallenge>;
+
+/// Random challenge which the authenticator has to sign, so that responses
+/// can't be replayed. Deleted once it has been used.
+#[derive(Clone, sqlx::FromRow)]
+pub struct PasskeyChallenge {
+    pub id: PasskeyChallengeId,
+    /// Base64url encoded
+    pub challenge: String,
+    /// Set when registering a passkey, not set when logging in
+    pub user_id: Option<UserId>,
+    /// Set when logging in, so that the challenges per client can be limited
+    pub ip_address: Option<String>,
+    pub date_expires: DateTime,
+    pub date_created: DateTime,
+}
+
+impl PasskeyChallenge {
+    pub fn is_expired(&self) -> bool {
+        self.date_expires.as_ref() < DateTime::now().as_ref()
+    }
+}
+
+/// Deletes the challenge while reading it, so that it can only be used once
+/// even if several responses arrive at the same time
+pub async fn consume_by_challenge(
+    pool: &sqlx::sqlite::SqlitePool,
+    challenge: &str,
+) -> Result<PasskeyChallenge, sqlx::Error> {
+    sqlx::query_as("DELETE FROM passkey_challenges WHERE challenge = ? RETURNING *")
+        .bind(challenge)
+        .fetch_one(pool)
+        .await
+}
+
+/// Only counts challenges for logging in, optionally from one IP address
+pub async fn count_unexpired_for_login(
+    pool: &sqlx::sqlite::SqlitePool,
+    ip_address: Option<&str>,
+) -> Result<u32, sqlx::Error> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM passkey_challenges WHERE user_id IS NULL AND (? IS NULL OR ip_address = ?) AND date_expires >= ?")
+        .bind(ip_address)
+        .bind(ip_address)
+        .bind(DateTime::now())
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    passkey_challenge: &PasskeyChallenge,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO passkey_challenges (id, challenge, user_id, ip_address, date_expires, date_created) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(passkey_challenge.id)
+        .bind(&passkey_challenge.challenge)
+        .bind(passkey_challenge.user_id)
+        .bind(&passkey_challenge.ip_address)
+        .bind(passkey_challenge.date_expires)
+        .bind(passkey_challenge.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_expired(pool: &sqlx::sqlite::SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM passkey_challenges WHERE date_expires < ?")
+        .bind(DateTime::now())
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{service, test_util};
+
+    #[tokio::test]
+    async fn challenge_can_only_be_consumed_once() {
+        let pool = test_util::create_pool().await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let passkey_challenge = service::passkey::create_challenge(&pool, user.id).await;
+
+        let (first, second) = tokio::join!(
+            super::consume_by_challenge(&pool, &passkey_challenge.challenge),
+            super::consume_by_challenge(&pool, &passkey_challenge.challenge),
+        );
+
+        assert_eq!(first.unwrap().id, passkey_challenge.id);
+        assert!(matches!(second, Err(sqlx::Error::RowNotFound)));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::value::{DateTime, Tagged, TokenHash, Uuid};
+
+use super::user::UserId;
+
+pub type PasswordResetTokenId = Tagged<Uuid, PasswordResetToken>;
+
+/// Tokens are single-use and get deleted once the password has been reset
+#[derive(Clone, sqlx::FromRow)]
+pub struct PasswordResetToken {
+    pub id: PasswordResetTokenId,
+    pub token_hash: TokenHash,
+    pub user_id: UserId,
+    pub date_expires: DateTime,
+    pub date_created: DateTime,
+}
+
+impl PasswordResetToken {
+    pub fn is_expired(&self) -> bool {
+        self.date_expires.as_ref() < DateTime::now().as_ref()
+    }
+}
+
+pub async fn get_by_token(
+    pool: &sqlx::sqlite::SqlitePool,
+    token: &str,
+) -> Result<PasswordResetToken, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM password_reset_tokens WHERE token_hash = ?")
+        .bind(TokenHash::from_token(token))
+        .fetch_one(pool)
+        .await
+}
+
+/// Deletes the token in the same statement, so that concurrent requests
+/// can't both use it
+pub async fn consume_by_token(
+    pool: &sqlx::sqlite::SqlitePool,
+    token: &str,
+) -> Result<PasswordResetToken, sqlx::Error> {
+    sqlx::query_as("DELETE FROM password_reset_tokens WHERE token_hash = ? RETURNING *")
+        .bind(TokenHash::from_token(token))
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_latest_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<PasswordResetToken, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM password_reset_tokens WHERE user_id = ? ORDER BY date_created DESC LIMIT 1")
+        .bind(user_id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    reset_token: &PasswordResetToken,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO password_reset_tokens (id, token_hash, user_id, date_expires, date_created) VALUES (?, ?, ?, ?, ?)")
+        .bind(reset_token.id)
+        .bind(&reset_token.token_hash)
+        .bind(reset_token.user_id)
+        .bind(reset_token.date_expires)
+        .bind(reset_token.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM password_reset_tokens WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_expired(pool: &sqlx::sqlite::SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM password_reset_tokens WHERE date_expires < ?")
+        .bind(DateTime::now())
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -0,0 +1,79 @@
+use crate::model::chore_list::ChoreListId;
+use crate::value::{DateTime, Tagged, Uuid};
+
+pub type RewardId = Tagged<Uuid, Reward>;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct Reward {
+    pub id: RewardId,
+    pub chore_list_id: ChoreListId,
+    pub name: String,
+    pub description: Option<String>,
+    pub points: u32,
+    pub date_created: DateTime,
+    pub date_deleted: Option<DateTime>,
+}
+
+impl Reward {
+    pub fn is_deleted(&self) -> bool {
+        self.date_deleted.is_some()
+    }
+}
+
+pub async fn get_by_id(pool: &sqlx::sqlite::SqlitePool, id: &RewardId) -> Result<Reward, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM rewards WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_all_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<Reward>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM rewards WHERE chore_list_id = ? ORDER BY points ASC, name ASC")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(pool: &sqlx::sqlite::SqlitePool, reward: &Reward) -> Result<(), sqlx::Error> {
+    tracing::info!(reward = ?reward, "Creating reward");
+
+    sqlx::query("INSERT INTO rewards (id, chore_list_id, name, description, points, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?)")
+        .bind(reward.id)
+        .bind(reward.chore_list_id)
+        .bind(&reward.name)
+        .bind(&reward.description)
+        .bind(reward.points)
+        .bind(reward.date_created)
+        .bind(reward.date_deleted)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn update(pool: &sqlx::sqlite::SqlitePool, reward: &Reward) -> Result<(), sqlx::Error> {
+    tracing::info!(reward = ?reward, "Updating reward");
+
+    sqlx::query("UPDATE rewards SET chore_list_id = ?, name = ?, description = ?, points = ?, date_deleted = ? WHERE id = ?")
+        .bind(reward.chore_list_id)
+        .bind(&reward.name)
+        .bind(&reward.description)
+        .bind(reward.points)
+        .bind(reward.date_deleted)
+        .bind(reward.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(pool: &sqlx::sqlite::SqlitePool, reward: &Reward) -> Result<(), sqlx::Error> {
+    tracing::info!(reward = ?reward, "Deleting reward");
+
+    sqlx::query("DELETE FROM rewards WHERE id = ?")
+        .bind(reward.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
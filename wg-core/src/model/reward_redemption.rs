@@ -0,0 +1,196 @@
+use crate::model::chore_list::{self, ChoreList, ChoreListId};
+use crate::model::reward::RewardId;
+use crate::model::user::UserId;
+use crate::value::{DateTime, Tagged, Uuid};
+use chrono::TimeDelta;
+
+pub type RewardRedemptionId = Tagged<Uuid, RewardRedemption>;
+
+#[derive(Debug, sqlx::FromRow)]
+pub struct RewardRedemption {
+    pub id: RewardRedemptionId,
+    pub reward_id: RewardId,
+    pub user_id: UserId,
+    /// Price of the reward at the time of the redemption
+    pub points: u32,
+    pub date_created: DateTime,
+    pub date_deleted: Option<DateTime>,
+}
+
+impl RewardRedemption {
+    /// Redemptions can only be undone shortly after they were made, e.g. to
+    /// fix a mistap, as the reward might have been consumed later on
+    const UNDO_WINDOW: TimeDelta = TimeDelta::minutes(15);
+
+    pub fn is_deleted(&self) -> bool {
+        self.date_deleted.is_some()
+    }
+
+    pub fn can_be_undone(&self) -> bool {
+        !self.is_deleted() && *self.date_created.as_ref() + Self::UNDO_WINDOW > *DateTime::now().as_ref()
+    }
+}
+
+pub async fn get_by_id(
+    pool: &sqlx::sqlite::SqlitePool,
+    id: &RewardRedemptionId,
+) -> Result<RewardRedemption, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM reward_redemptions WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_all_for_chore_list(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list_id: &ChoreListId,
+) -> Result<Vec<RewardRedemption>, sqlx::Error> {
+    sqlx::query_as("
+        SELECT reward_redemptions.* FROM reward_redemptions
+        INNER JOIN rewards ON reward_redemptions.reward_id = rewards.id
+        WHERE rewards.chore_list_id = ?
+        ORDER BY reward_redemptions.date_created DESC
+    ")
+        .bind(chore_list_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    executor: impl sqlx::SqliteExecutor<'_>,
+    reward_redemption: &RewardRedemption,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(reward_redemption = ?reward_redemption, "Creating reward redemption");
+
+    sqlx::query("INSERT INTO reward_redemptions (id, reward_id, user_id, points, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?)")
+        .bind(reward_redemption.id)
+        .bind(reward_redemption.reward_id)
+        .bind(reward_redemption.user_id)
+        .bind(reward_redemption.points)
+        .bind(reward_redemption.date_created)
+        .bind(reward_redemption.date_deleted)
+        .execute(executor)
+        .await
+        .map(|_| ())
+}
+
+/// Creates the redemption only if the balance of the user covers its points.
+/// The balance is checked in the same transaction, so concurrent redemptions
+/// can't overspend it.
+///
+/// Returns false if the balance is insufficient
+pub async fn create_if_affordable(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore_list: &ChoreList,
+    reward_redemption: &RewardRedemption,
+) -> Result<bool, sqlx::Error> {
+    let mut transaction = pool.begin_with("BEGIN IMMEDIATE").await?;
+
+    let balance = chore_list::get_balance_per_user(&mut *transaction, chore_list)
+        .await?
+        .into_iter()
+        .find(|(user_id, _balance)| *user_id == reward_redemption.user_id)
+        .map(|(_user_id, balance)| balance)
+        .unwrap_or(0);
+    if i64::from(balance) < i64::from(reward_redemption.points) {
+        return Ok(false);
+    }
+
+    create(&mut *transaction, reward_redemption).await?;
+
+    transaction.commit().await?;
+
+    Ok(true)
+}
+
+pub async fn update(
+    pool: &sqlx::sqlite::SqlitePool,
+    reward_redemption: &RewardRedemption,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(reward_redemption = ?reward_redemption, "Updating reward redemption");
+
+    sqlx::query("UPDATE reward_redemptions SET reward_id = ?, user_id = ?, points = ?, date_deleted = ? WHERE id = ?")
+        .bind(reward_redemption.reward_id)
+        .bind(reward_redemption.user_id)
+        .bind(reward_redemption.points)
+        .bind(reward_redemption.date_deleted)
+        .bind(reward_redemption.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(
+    pool: &sqlx::sqlite::SqlitePool,
+    reward_redemption: &RewardRedemption,
+) -> Result<(), sqlx::Error> {
+    tracing::info!(reward_redemption = ?reward_redemption, "Deleting reward redemption");
+
+    sqlx::query("DELETE FROM reward_redemptions WHERE id = ?")
+        .bind(reward_redemption.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeDelta;
+    use crate::{model::{chore_list::ChoreListId, reward::{self, Reward, RewardId}, user::UserId}, test_util, value::DateTime};
+    use super::{RewardRedemption, RewardRedemptionId};
+
+    fn make_redemption(reward: &Reward, user_id: UserId, date_created: DateTime) -> RewardRedemption {
+        RewardRedemption {
+            id: RewardRedemptionId::new(),
+            reward_id: reward.id,
+            user_id,
+            points: reward.points,
+            date_created,
+            date_deleted: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn redemption_needs_sufficient_balance() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+        let reward = Reward {
+            id: RewardId::new(),
+            chore_list_id: chore_list.id,
+            name: "Cinema".to_string(),
+            description: None,
+            points: 15,
+            date_created: DateTime::now(),
+            date_deleted: None,
+        };
+        reward::create(&pool, &reward).await.unwrap();
+
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 1)).await;
+        assert!(!super::create_if_affordable(&pool, &chore_list, &make_redemption(&reward, user.id, DateTime::now())).await.unwrap());
+
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 2)).await;
+        assert!(super::create_if_affordable(&pool, &chore_list, &make_redemption(&reward, user.id, DateTime::now())).await.unwrap());
+        assert!(!super::create_if_affordable(&pool, &chore_list, &make_redemption(&reward, user.id, DateTime::now())).await.unwrap());
+    }
+
+    #[test]
+    fn redemption_can_only_be_undone_shortly_after() {
+        let user_id = UserId::new();
+        let reward = Reward {
+            id: RewardId::new(),
+            chore_list_id: ChoreListId::new(),
+            name: "Cinema".to_string(),
+            description: None,
+            points: 15,
+            date_created: DateTime::now(),
+            date_deleted: None,
+        };
+
+        assert!(make_redemption(&reward, user_id, DateTime::now()).can_be_undone());
+
+        let date_created = DateTime::from(*DateTime::now().as_ref() - TimeDelta::hours(1));
+        assert!(!make_redemption(&reward, user_id, date_created).can_be_undone());
+    }
+}
@@ -1,13 +1,84 @@
-use crate::value::{DateTime, Language, PasswordHash, Tagged, Uuid};
+use crate::value::{DateTime, Language, PasswordHash, Tagged, TotpSecret, Uuid};
 
 pub type UserId = Tagged<Uuid, User>;
 
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    strum::EnumString,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+pub enum UserKind {
+    /// Flatmate who logs in and collects points
+    Member,
+    /// Participant whose activities only advance the due dates of chores,
+    /// e.g. a paid cleaner. They don't log in and don't have a score.
+    NonScoring,
+}
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    strum::EnumString,
+    strum::Display,
+    strum::AsRefStr,
+    strum::IntoStaticStr,
+    strum::EnumIter,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+pub enum UserRole {
+    /// Manages the users and the instance
+    Admin,
+    Member,
+}
+
+/// Actions which not every user is allowed to perform
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Permission {
+    /// Invite, delete and restore users and change their roles
+    ManageUsers,
+    DeleteChoreLists,
+    /// See which activities were logged within a chore's cooldown
+    ViewCooldownViolations,
+    /// Edit, delete and restore the activities of all users
+    ManageAllActivities,
+}
+
+impl UserRole {
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::ManageUsers => *self == UserRole::Admin,
+            Permission::DeleteChoreLists => *self == UserRole::Admin,
+            Permission::ViewCooldownViolations => *self == UserRole::Admin,
+            Permission::ManageAllActivities => *self == UserRole::Admin,
+        }
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 pub struct User {
     pub id: UserId,
     pub name: String,
-    pub email: String,
-    pub password_hash: PasswordHash,
+    /// Not set for non-scoring participants
+    pub email: Option<String>,
+    /// Not set for non-scoring participants
+    pub password_hash: Option<PasswordHash>,
+    pub kind: UserKind,
+    pub role: UserRole,
+    /// Set once the user has enabled two-factor authentication
+    pub totp_secret: Option<TotpSecret>,
     pub last_used_language: Option<Language>,
     pub date_created: DateTime,
     pub date_deleted: Option<DateTime>,
@@ -17,6 +88,19 @@ impl User {
     pub fn is_deleted(&self) -> bool {
         self.date_deleted.is_some()
     }
+
+    pub fn is_scoring(&self) -> bool {
+        self.kind == UserKind::Member
+    }
+
+    pub fn has_two_factor_authentication(&self) -> bool {
+        self.totp_secret.is_some()
+    }
+
+    /// Deleted users lose all their permissions
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        !self.is_deleted() && self.role.has_permission(permission)
+    }
 }
 
 pub async fn get_by_id(pool: &sqlx::sqlite::SqlitePool, id: &UserId) -> Result<User, sqlx::Error> {
@@ -40,18 +124,21 @@ pub async fn get_all(pool: &sqlx::sqlite::SqlitePool) -> Result<Vec<User>, sqlx:
     sqlx::query_as("SELECT * FROM users").fetch_all(pool).await
 }
 
-pub async fn create(pool: &sqlx::sqlite::SqlitePool, user: &User) -> Result<(), sqlx::Error> {
+pub async fn create(executor: impl sqlx::SqliteExecutor<'_>, user: &User) -> Result<(), sqlx::Error> {
     tracing::info!(user = ?user, "Creating user");
 
-    sqlx::query("INSERT INTO users (id, name, email, password_hash, last_used_language, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?)")
+    sqlx::query("INSERT INTO users (id, name, email, password_hash, kind, role, totp_secret, last_used_language, date_created, date_deleted) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
         .bind(user.id)
         .bind(&user.name)
         .bind(&user.email)
         .bind(&user.password_hash)
+        .bind(user.kind)
+        .bind(user.role)
+        .bind(&user.totp_secret)
         .bind(&user.last_used_language)
         .bind(user.date_created)
         .bind(user.date_deleted)
-        .execute(pool)
+        .execute(executor)
         .await
         .map(|_| ())
 }
@@ -59,10 +146,13 @@ pub async fn create(pool: &sqlx::sqlite::SqlitePool, user: &User) -> Result<(),
 pub async fn update(pool: &sqlx::sqlite::SqlitePool, user: &User) -> Result<(), sqlx::Error> {
     tracing::info!(user = ?user, "Updating user");
 
-    sqlx::query("UPDATE users SET name = ?, email = ?, password_hash = ?, last_used_language = ?, date_deleted = ? WHERE id = ?")
+    sqlx::query("UPDATE users SET name = ?, email = ?, password_hash = ?, kind = ?, role = ?, totp_secret = ?, last_used_language = ?, date_deleted = ? WHERE id = ?")
         .bind(&user.name)
         .bind(&user.email)
         .bind(&user.password_hash)
+        .bind(user.kind)
+        .bind(user.role)
+        .bind(&user.totp_secret)
         .bind(&user.last_used_language)
         .bind(user.date_deleted)
         .bind(user.id)
@@ -71,6 +161,22 @@ pub async fn update(pool: &sqlx::sqlite::SqlitePool, user: &User) -> Result<(),
         .map(|_| ())
 }
 
+/// Only moves the last used time step of the one-time password forward.
+/// Returns false if the step has already been used, i.e. the code is replayed.
+pub async fn update_totp_last_used_step(pool: &sqlx::sqlite::SqlitePool, user_id: &UserId, step: u64) -> Result<bool, sqlx::Error> {
+    let step = i64::try_from(step).unwrap_or(i64::MAX);
+
+    let rows_affected = sqlx::query("UPDATE users SET totp_last_used_step = ? WHERE id = ? AND (totp_last_used_step IS NULL OR totp_last_used_step < ?)")
+        .bind(step)
+        .bind(user_id)
+        .bind(step)
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+    Ok(rows_affected == 1)
+}
+
 pub async fn delete(pool: &sqlx::sqlite::SqlitePool, user: &User) -> Result<(), sqlx::Error> {
     tracing::info!(user = ?user, "Deleting user");
 
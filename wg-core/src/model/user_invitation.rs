@@ -0,0 +1,132 @@
+use crate::value::{DateTime, Tagged, TokenHash, Uuid};
+
+use super::user::{self, User, UserId};
+
+pub type UserInvitationId = Tagged<Uuid, UserInvitation>;
+
+/// Invitations are deleted once they have been accepted or revoked
+#[derive(Clone, sqlx::FromRow)]
+pub struct UserInvitation {
+    pub id: UserInvitationId,
+    pub token_hash: TokenHash,
+    pub name: String,
+    pub email: String,
+    pub invited_by_user_id: UserId,
+    pub date_expires: DateTime,
+    pub date_created: DateTime,
+}
+
+impl UserInvitation {
+    pub fn is_expired(&self) -> bool {
+        self.date_expires.as_ref() < DateTime::now().as_ref()
+    }
+}
+
+pub async fn get_by_id(
+    pool: &sqlx::sqlite::SqlitePool,
+    id: &UserInvitationId,
+) -> Result<UserInvitation, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM user_invitations WHERE id = ?")
+        .bind(id)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_by_token(
+    pool: &sqlx::sqlite::SqlitePool,
+    token: &str,
+) -> Result<UserInvitation, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM user_invitations WHERE token_hash = ?")
+        .bind(TokenHash::from_token(token))
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn get_all_pending(
+    pool: &sqlx::sqlite::SqlitePool,
+) -> Result<Vec<UserInvitation>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM user_invitations WHERE date_expires >= ? ORDER BY date_created")
+        .bind(DateTime::now())
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    invitation: &UserInvitation,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO user_invitations (id, token_hash, name, email, invited_by_user_id, date_expires, date_created) VALUES (?, ?, ?, ?, ?, ?, ?)")
+        .bind(invitation.id)
+        .bind(&invitation.token_hash)
+        .bind(&invitation.name)
+        .bind(&invitation.email)
+        .bind(invitation.invited_by_user_id)
+        .bind(invitation.date_expires)
+        .bind(invitation.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(
+    pool: &sqlx::sqlite::SqlitePool,
+    invitation: &UserInvitation,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM user_invitations WHERE id = ?")
+        .bind(invitation.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+/// Consumes the invitation and creates the invited user in one transaction,
+/// so that the invitation can't be accepted twice
+///
+/// Returns false if the invitation has already been used or the email
+/// address has been taken in the meantime
+pub async fn accept(
+    pool: &sqlx::sqlite::SqlitePool,
+    invitation: &UserInvitation,
+    user: &User,
+) -> Result<bool, sqlx::Error> {
+    let mut transaction = pool.begin().await?;
+
+    let deleted_rows = sqlx::query("DELETE FROM user_invitations WHERE id = ?")
+        .bind(invitation.id)
+        .execute(&mut *transaction)
+        .await?
+        .rows_affected();
+    if deleted_rows == 0 {
+        return Ok(false);
+    }
+
+    match user::create(&mut *transaction, user).await {
+        Ok(()) => {},
+        Err(sqlx::Error::Database(err)) if err.is_unique_violation() => return Ok(false),
+        Err(err) => return Err(err),
+    }
+
+    transaction.commit().await?;
+
+    Ok(true)
+}
+
+/// Replaces earlier invitations when somebody is invited again
+pub async fn delete_all_for_email(
+    pool: &sqlx::sqlite::SqlitePool,
+    email: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM user_invitations WHERE email = ?")
+        .bind(email)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_expired(pool: &sqlx::sqlite::SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM user_invitations WHERE date_expires < ?")
+        .bind(DateTime::now())
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
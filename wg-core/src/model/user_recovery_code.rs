@@ -0,0 +1,61 @@
+use crate::value::{DateTime, PasswordHash, Tagged, Uuid};
+
+use super::user::UserId;
+
+pub type UserRecoveryCodeId = Tagged<Uuid, UserRecoveryCode>;
+
+/// Single-use code which can be entered instead of a one-time password, e.g.
+/// when the authenticator app got lost
+#[derive(sqlx::FromRow)]
+pub struct UserRecoveryCode {
+    pub id: UserRecoveryCodeId,
+    pub user_id: UserId,
+    pub code_hash: PasswordHash,
+    pub date_created: DateTime,
+}
+
+pub async fn get_all_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<Vec<UserRecoveryCode>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM user_recovery_codes WHERE user_id = ?")
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn create(
+    pool: &sqlx::sqlite::SqlitePool,
+    recovery_code: &UserRecoveryCode,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO user_recovery_codes (id, user_id, code_hash, date_created) VALUES (?, ?, ?, ?)")
+        .bind(recovery_code.id)
+        .bind(recovery_code.user_id)
+        .bind(&recovery_code.code_hash)
+        .bind(recovery_code.date_created)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete(
+    pool: &sqlx::sqlite::SqlitePool,
+    recovery_code: &UserRecoveryCode,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM user_recovery_codes WHERE id = ?")
+        .bind(recovery_code.id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+pub async fn delete_all_for_user(
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: &UserId,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM user_recovery_codes WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
@@ -1,28 +1,38 @@
-use chrono::Days;
-use crate::{model::{self, chore::Chore, chore_activity}, value::Date};
+use chrono::{Days, DurationRound, TimeDelta};
+use crate::{model::{self, chore::Chore, chore_activity, chore_checklist_item::{self, ChoreChecklistItem, ChoreChecklistItemId}, chore_exclusion::{self, ChoreExclusion, ChoreExclusionId}, chore_postponement::{self, ChorePostponement, ChorePostponementId, ChorePostponementKind}, chore_vote, user::UserId}, value::{Date, DateTime, Time}};
 
+/// One-off tasks keep the due date they were given. Instead they are marked
+/// as completed as long as there is an activity for them.
+///
+/// Follow-up chores become due after the delay once an activity has been
+/// recorded for their parent chore, unless they have been done since. If the
+/// chore also has an interval, the earlier of both dates is used.
+///
+/// Chores with an interval in hours are due from the time of day the
+/// interval ends. Activities without a time count as done at midnight.
+///
+/// A skip or snooze which happened after the latest activity overrides the
+/// calculated date.
+///
+/// Seasonal chores which were due during their dormant months become due at
+/// the start of their active window, so that they aren't overdue right away.
+///
 /// Returns true if changes were made and false if nothing changed
 pub async fn update_next_due_date(
     chore: &mut Chore,
     pool: &sqlx::sqlite::SqlitePool,
     save_to_db: bool,
 ) -> Result<bool, sqlx::Error> {
-    if let Some(interval_days) = chore.interval_days {
-        let last_activity_date =
+    if chore.is_one_off {
+        let date_completed =
             match chore_activity::get_latest_not_deleted_for_chore(pool, &chore.id).await {
-                Ok(chore_activity) => chore_activity.date,
-                Err(sqlx::Error::RowNotFound) => {
-                    Date::from(chore.date_created.as_ref().date_naive())
-                }
+                Ok(chore_activity) => Some(chore_activity.date),
+                Err(sqlx::Error::RowNotFound) => None,
                 Err(err) => return Err(err),
             };
 
-        let next_due_date = Some(Date::from(
-            last_activity_date.as_ref().clone() + Days::new(interval_days.into()),
-        ));
-
-        if chore.next_due_date != next_due_date {
-            chore.next_due_date = next_due_date;
+        if chore.date_completed != date_completed {
+            chore.date_completed = date_completed;
 
             if save_to_db {
                 model::chore::update(pool, chore).await?;
@@ -33,8 +43,66 @@ pub async fn update_next_due_date(
 
         Ok(false)
     } else {
-        if chore.next_due_date.is_some() {
-            chore.next_due_date = None;
+        let interval_due_date = match (chore.interval_hours, chore.interval_days) {
+            (Some(interval_hours), _) => {
+                let last_done_at =
+                    match chore_activity::get_latest_not_deleted_for_chore(pool, &chore.id).await {
+                        Ok(chore_activity) => chore_activity.date.as_ref().and_time(
+                            chore_activity.time.map(Into::into).unwrap_or_default(),
+                        ),
+                        Err(sqlx::Error::RowNotFound) => {
+                            chore.date_created.as_ref().naive_utc().duration_trunc(TimeDelta::minutes(1)).unwrap()
+                        }
+                        Err(err) => return Err(err),
+                    };
+
+                let due_at = last_done_at + TimeDelta::hours(interval_hours.into());
+                Some((Date::from(due_at), Some(Time::from(due_at))))
+            }
+            (None, Some(interval_days)) => {
+                let last_activity_date =
+                    match chore_activity::get_latest_not_deleted_for_chore(pool, &chore.id).await {
+                        Ok(chore_activity) => chore_activity.date,
+                        Err(sqlx::Error::RowNotFound) => {
+                            Date::from(chore.date_created.as_ref().date_naive())
+                        }
+                        Err(err) => return Err(err),
+                    };
+
+                Some((
+                    Date::from(*last_activity_date.as_ref() + Days::new(interval_days.into())),
+                    None,
+                ))
+            }
+            (None, None) => None,
+        };
+
+        let follow_up_due_date = get_follow_up_due_date(chore, pool)
+            .await?
+            .map(|follow_up_due_date| (follow_up_due_date, None));
+
+        let next_due = match (interval_due_date, follow_up_due_date) {
+            (Some(interval_due_date), Some(follow_up_due_date)) => {
+                Some(interval_due_date.min(follow_up_due_date))
+            }
+            (interval_due_date, follow_up_due_date) => interval_due_date.or(follow_up_due_date),
+        };
+
+        let (next_due_date, next_due_time) = match get_postponed_due_date(chore, pool).await? {
+            Some(postponed_due_date) => (Some(postponed_due_date), None),
+            None => (next_due.map(|(date, _)| date), next_due.and_then(|(_, time)| time)),
+        };
+
+        let (next_due_date, next_due_time) = match (next_due_date, chore.get_current_active_window_start_date()) {
+            (Some(next_due_date), Some(window_start_date)) if next_due_date < window_start_date => {
+                (Some(window_start_date), None)
+            }
+            _ => (next_due_date, next_due_time),
+        };
+
+        if chore.next_due_date != next_due_date || chore.next_due_time != next_due_time {
+            chore.next_due_date = next_due_date;
+            chore.next_due_time = next_due_time;
 
             if save_to_db {
                 model::chore::update(pool, chore).await?;
@@ -46,3 +114,458 @@ pub async fn update_next_due_date(
         Ok(false)
     }
 }
+
+/// Returns the due date of the latest skip or snooze, unless an activity has
+/// been recorded since
+async fn get_postponed_due_date(
+    chore: &Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+) -> Result<Option<Date>, sqlx::Error> {
+    let postponement = match chore_postponement::get_latest_for_chore(pool, &chore.id).await {
+        Ok(postponement) => postponement,
+        Err(sqlx::Error::RowNotFound) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let is_done_since_postponement = match chore_activity::get_latest_not_deleted_for_chore(pool, &chore.id).await {
+        Ok(chore_activity) => chore_activity.date_created >= postponement.date_created,
+        Err(sqlx::Error::RowNotFound) => false,
+        Err(err) => return Err(err),
+    };
+    if is_done_since_postponement {
+        return Ok(None);
+    }
+
+    Ok(Some(postponement.new_due_date))
+}
+
+/// Returns the date on which a follow-up chore becomes due because of the
+/// latest activity of its parent chore, if it has not been done since
+async fn get_follow_up_due_date(
+    chore: &Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+) -> Result<Option<Date>, sqlx::Error> {
+    let parent_chore_id = match chore.follow_up_of_chore_id {
+        Some(parent_chore_id) => parent_chore_id,
+        None => return Ok(None),
+    };
+
+    let parent_chore = model::chore::get_by_id(pool, &parent_chore_id).await?;
+    if parent_chore.is_deleted() {
+        return Ok(None);
+    }
+
+    let parent_activity = match chore_activity::get_latest_not_deleted_for_chore(pool, &parent_chore.id).await {
+        Ok(chore_activity) => chore_activity,
+        Err(sqlx::Error::RowNotFound) => return Ok(None),
+        Err(err) => return Err(err),
+    };
+
+    let is_done_since_parent_activity = match chore_activity::get_latest_not_deleted_for_chore(pool, &chore.id).await {
+        Ok(chore_activity) => {
+            (chore_activity.date, chore_activity.date_created) >= (parent_activity.date, parent_activity.date_created)
+        }
+        Err(sqlx::Error::RowNotFound) => false,
+        Err(err) => return Err(err),
+    };
+    if is_done_since_parent_activity {
+        return Ok(None);
+    }
+
+    let delay_days = chore.follow_up_delay_days.unwrap_or_default();
+
+    Ok(Some(Date::from(
+        *parent_activity.date.as_ref() + Days::new(delay_days.into()),
+    )))
+}
+
+/// Updates the next due date of all follow-ups of a chore. Needs to be called
+/// whenever the activities of the chore change.
+pub async fn update_follow_up_due_dates(
+    chore: &Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+) -> Result<(), sqlx::Error> {
+    for mut follow_up in model::chore::get_all_follow_ups(pool, &chore.id).await? {
+        update_next_due_date(&mut follow_up, pool, true).await?;
+    }
+
+    Ok(())
+}
+
+/// Recalculates the points of a chore based on the votes of the users. Chores
+/// without a voting method or without any votes keep their current points.
+///
+/// Returns true if changes were made and false if nothing changed
+pub async fn update_points_from_votes(
+    chore: &mut Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+    save_to_db: bool,
+) -> Result<bool, sqlx::Error> {
+    let voting_method = match chore.points_voting_method {
+        Some(voting_method) => voting_method,
+        None => return Ok(false),
+    };
+
+    let votes = chore_vote::get_all_for_chore(pool, &chore.id)
+        .await?
+        .into_iter()
+        .map(|vote| vote.points)
+        .collect::<Vec<u32>>();
+
+    let points = match voting_method.calculate_points(&votes) {
+        Some(points) => points.max(1),
+        None => return Ok(false),
+    };
+
+    if chore.points != points {
+        chore.points = points;
+
+        if save_to_db {
+            model::chore::update(pool, chore).await?;
+        }
+
+        return Ok(true);
+    }
+
+    Ok(false)
+}
+
+/// Recalculates the points of all chores the user has voted on, e.g. after
+/// the user has been deleted or restored and their votes no longer or again
+/// count
+pub async fn update_points_from_votes_of_user(
+    user_id: &UserId,
+    pool: &sqlx::sqlite::SqlitePool,
+) -> Result<(), sqlx::Error> {
+    for mut chore in model::chore::get_all(pool).await? {
+        if chore.is_deleted() {
+            continue;
+        }
+
+        match chore_vote::get_by_chore_and_user(pool, &chore.id, user_id).await {
+            Ok(_) => {},
+            Err(sqlx::Error::RowNotFound) => continue,
+            Err(err) => return Err(err),
+        }
+
+        update_points_from_votes(&mut chore, pool, true).await?;
+    }
+
+    Ok(())
+}
+
+/// Replaces the checklist of a chore with the given steps. The existing items
+/// are kept if the steps did not change.
+///
+/// Returns true if changes were made and false if nothing changed
+pub async fn update_checklist(
+    chore: &Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+    item_names: &[String],
+) -> Result<bool, sqlx::Error> {
+    let current_item_names = chore_checklist_item::get_all_for_chore(pool, &chore.id)
+        .await?
+        .into_iter()
+        .map(|item| item.name)
+        .collect::<Vec<String>>();
+
+    if current_item_names == item_names {
+        return Ok(false);
+    }
+
+    chore_checklist_item::delete_all_for_chore(pool, &chore.id).await?;
+
+    for (position, name) in item_names.iter().enumerate() {
+        chore_checklist_item::create(pool, &ChoreChecklistItem {
+            id: ChoreChecklistItemId::new(),
+            chore_id: chore.id,
+            position: position as u32,
+            name: name.clone(),
+            date_created: DateTime::now(),
+        }).await?;
+    }
+
+    Ok(true)
+}
+
+/// Replaces the users the chore is excluded for
+///
+/// Returns true if changes were made and false if nothing changed
+pub async fn update_exclusions(
+    chore: &Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+    user_ids: &[UserId],
+) -> Result<bool, sqlx::Error> {
+    let current_exclusions = chore_exclusion::get_all_for_chore(pool, &chore.id).await?;
+
+    let mut has_changes = false;
+
+    for exclusion in current_exclusions.iter() {
+        if !user_ids.contains(&exclusion.user_id) {
+            chore_exclusion::delete(pool, exclusion).await?;
+            has_changes = true;
+        }
+    }
+
+    for user_id in user_ids {
+        if !current_exclusions.iter().any(|exclusion| &exclusion.user_id == user_id) {
+            chore_exclusion::create(pool, &ChoreExclusion {
+                id: ChoreExclusionId::new(),
+                chore_id: chore.id,
+                user_id: *user_id,
+                date_created: DateTime::now(),
+            }).await?;
+            has_changes = true;
+        }
+    }
+
+    Ok(has_changes)
+}
+
+/// Moves the next due date of a due chore without awarding points. Skipping
+/// moves it by whole intervals until it lies in the future, snoozing moves it
+/// to the given number of days from today.
+///
+/// Returns None if the chore can't be postponed this way
+pub async fn postpone(
+    chore: &mut Chore,
+    pool: &sqlx::sqlite::SqlitePool,
+    user_id: UserId,
+    kind: ChorePostponementKind,
+    snooze_days: Option<u32>,
+    reason: Option<String>,
+) -> Result<Option<ChorePostponement>, sqlx::Error> {
+    let previous_due_date = match chore.next_due_date {
+        Some(next_due_date) if !chore.is_completed() => next_due_date,
+        _ => return Ok(None),
+    };
+
+    let (new_due_date, new_due_time) = match (kind, chore.interval_hours, chore.interval_days, snooze_days) {
+        (ChorePostponementKind::Skip, Some(interval_hours), _, _) if !chore.is_one_off && interval_hours > 0 => {
+            let mut new_due_at = previous_due_date.as_ref().and_time(chore.next_due_time.map(Into::into).unwrap_or_default());
+            loop {
+                new_due_at = match new_due_at.checked_add_signed(TimeDelta::hours(interval_hours.into())) {
+                    Some(new_due_at) => new_due_at,
+                    None => return Ok(None),
+                };
+                if new_due_at > chrono::Utc::now().naive_utc() {
+                    break;
+                }
+            }
+
+            (Date::from(new_due_at), Some(Time::from(new_due_at)))
+        }
+        (ChorePostponementKind::Skip, None, Some(interval_days), _) if !chore.is_one_off && interval_days > 0 => {
+            let mut new_due_date = *previous_due_date.as_ref();
+            loop {
+                new_due_date = match new_due_date.checked_add_days(Days::new(interval_days.into())) {
+                    Some(new_due_date) => new_due_date,
+                    None => return Ok(None),
+                };
+                if new_due_date > *Date::now().as_ref() {
+                    break;
+                }
+            }
+
+            (Date::from(new_due_date), None)
+        }
+        (ChorePostponementKind::Snooze, _, _, Some(snooze_days)) if (1..=chore_postponement::MAX_SNOOZE_DAYS).contains(&snooze_days) => {
+            (Date::from(*Date::now().as_ref() + Days::new(snooze_days.into())), None)
+        }
+        _ => return Ok(None),
+    };
+
+    let postponement = ChorePostponement {
+        id: ChorePostponementId::new(),
+        chore_id: chore.id,
+        user_id,
+        kind,
+        previous_due_date,
+        new_due_date,
+        reason,
+        date_created: DateTime::now(),
+    };
+
+    chore_postponement::create(pool, &postponement).await?;
+
+    chore.next_due_date = Some(new_due_date);
+    chore.next_due_time = new_due_time;
+    model::chore::update(pool, chore).await?;
+
+    Ok(Some(postponement))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, Days, TimeDelta};
+    use crate::{model::{chore::Chore, chore_activity, chore_postponement::ChorePostponementKind}, test_util, value::{Date, Time}};
+
+    #[tokio::test]
+    async fn skip_moves_daily_chore_by_whole_intervals() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let previous_due_date = *Date::now().as_ref() - Days::new(10);
+        let mut chore = test_util::create_chore(&pool, Chore {
+            interval_days: Some(7),
+            next_due_date: Some(Date::from(previous_due_date)),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        let postponement = super::postpone(&mut chore, &pool, user.id, ChorePostponementKind::Skip, None, None).await.unwrap();
+
+        assert!(postponement.is_some());
+        assert_eq!(chore.next_due_date, Some(Date::from(previous_due_date + Days::new(14))));
+        assert_eq!(chore.next_due_time, None);
+    }
+
+    #[tokio::test]
+    async fn skip_moves_hourly_chore_by_whole_intervals() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let now = chrono::Utc::now().naive_utc();
+        let previous_due_at = now - TimeDelta::hours(25);
+        let mut chore = test_util::create_chore(&pool, Chore {
+            interval_days: None,
+            interval_hours: Some(4),
+            next_due_date: Some(Date::from(previous_due_at)),
+            next_due_time: Some(Time::from(previous_due_at)),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        let postponement = super::postpone(&mut chore, &pool, user.id, ChorePostponementKind::Skip, None, None).await.unwrap();
+
+        assert!(postponement.is_some());
+        let new_due_at = chore.next_due_date.unwrap().as_ref()
+            .and_time(chore.next_due_time.unwrap().into());
+        assert_eq!(new_due_at, previous_due_at + TimeDelta::hours(28));
+    }
+
+    #[tokio::test]
+    async fn one_off_task_can_not_be_skipped() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let mut chore = test_util::create_chore(&pool, Chore {
+            interval_days: None,
+            is_one_off: true,
+            next_due_date: Some(Date::now()),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        let postponement = super::postpone(&mut chore, &pool, user.id, ChorePostponementKind::Skip, None, None).await.unwrap();
+
+        assert!(postponement.is_none());
+    }
+
+    #[tokio::test]
+    async fn follow_up_is_due_after_delay_until_it_is_done() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let parent_chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+        let mut follow_up = test_util::create_chore(&pool, Chore {
+            interval_days: None,
+            follow_up_of_chore_id: Some(parent_chore.id),
+            follow_up_delay_days: Some(2),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        super::update_next_due_date(&mut follow_up, &pool, false).await.unwrap();
+        assert_eq!(follow_up.next_due_date, None);
+
+        test_util::create_activity(&pool, &parent_chore, &user, test_util::date(2026, 3, 30)).await;
+        super::update_next_due_date(&mut follow_up, &pool, false).await.unwrap();
+        assert_eq!(follow_up.next_due_date, Some(test_util::date(2026, 4, 1)));
+
+        test_util::create_activity(&pool, &follow_up, &user, test_util::date(2026, 4, 1)).await;
+        super::update_next_due_date(&mut follow_up, &pool, false).await.unwrap();
+        assert_eq!(follow_up.next_due_date, None);
+    }
+
+    #[tokio::test]
+    async fn follow_up_with_interval_uses_earlier_date() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let parent_chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+        let mut follow_up = test_util::create_chore(&pool, Chore {
+            interval_days: Some(30),
+            follow_up_of_chore_id: Some(parent_chore.id),
+            follow_up_delay_days: Some(2),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        test_util::create_activity(&pool, &follow_up, &user, test_util::date(2026, 3, 1)).await;
+        super::update_next_due_date(&mut follow_up, &pool, false).await.unwrap();
+        assert_eq!(follow_up.next_due_date, Some(test_util::date(2026, 3, 31)));
+
+        test_util::create_activity(&pool, &parent_chore, &user, test_util::date(2026, 3, 10)).await;
+        super::update_next_due_date(&mut follow_up, &pool, false).await.unwrap();
+        assert_eq!(follow_up.next_due_date, Some(test_util::date(2026, 3, 12)));
+    }
+
+    #[tokio::test]
+    async fn seasonal_chore_overdue_before_its_window_is_due_at_window_start() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let today = *Date::now().as_ref();
+        let mut chore = test_util::create_chore(&pool, Chore {
+            active_from_month: Some(today.month()),
+            active_until_month: Some(today.month()),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        test_util::create_activity(&pool, &chore, &user, Date::from(today - Days::new(60))).await;
+        super::update_next_due_date(&mut chore, &pool, false).await.unwrap();
+
+        assert_eq!(chore.next_due_date, Some(Date::from(today.with_day(1).unwrap())));
+    }
+
+    #[tokio::test]
+    async fn dormant_seasonal_chore_keeps_calculated_due_date() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let today = *Date::now().as_ref();
+        let next_month = today.month() % 12 + 1;
+        let mut chore = test_util::create_chore(&pool, Chore {
+            active_from_month: Some(next_month),
+            active_until_month: Some(next_month),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        test_util::create_activity(&pool, &chore, &user, Date::from(today - Days::new(60))).await;
+        super::update_next_due_date(&mut chore, &pool, false).await.unwrap();
+
+        assert_eq!(chore.next_due_date, Some(Date::from(today - Days::new(53))));
+    }
+
+    #[tokio::test]
+    async fn hourly_chore_is_due_at_time_of_day_after_interval() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let mut chore = test_util::create_chore(&pool, Chore {
+            interval_days: None,
+            interval_hours: Some(8),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        let mut activity = test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 3, 1)).await;
+        activity.time = Some(Time::from(chrono::NaiveTime::from_hms_opt(20, 30, 0).unwrap()));
+        chore_activity::update(&pool, &activity).await.unwrap();
+
+        super::update_next_due_date(&mut chore, &pool, false).await.unwrap();
+        assert_eq!(chore.next_due_date, Some(test_util::date(2026, 3, 2)));
+        assert_eq!(chore.next_due_time, Some(Time::from(chrono::NaiveTime::from_hms_opt(4, 30, 0).unwrap())));
+
+        // Activities without a time count as done at midnight
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 3, 5)).await;
+        super::update_next_due_date(&mut chore, &pool, false).await.unwrap();
+        assert_eq!(chore.next_due_date, Some(test_util::date(2026, 3, 5)));
+        assert_eq!(chore.next_due_time, Some(Time::from(chrono::NaiveTime::from_hms_opt(8, 0, 0).unwrap())));
+    }
+}
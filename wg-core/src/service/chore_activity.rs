@@ -0,0 +1,191 @@
+use chrono::Days;
+use crate::{model::{chore::Chore, chore_activity::{self, ChoreActivity}, chore_activity_checklist_item::{self, ChoreActivityChecklistItem, ChoreActivityChecklistItemId}, chore_checklist_item::{ChoreChecklistItem, ChoreChecklistItemId}, user::{Permission, UserId}}, service, value::Date};
+
+/// Returns the creditable activity of the same chore which lies within the cooldown of the given activity, if there is any
+pub async fn find_cooldown_conflict(
+    pool: &crate::db::Pool,
+    chore: &Chore,
+    activity: &ChoreActivity,
+) -> Result<Option<ChoreActivity>, sqlx::Error> {
+    let cooldown_days = match chore.cooldown_days {
+        Some(cooldown_days) if cooldown_days > 0 => cooldown_days,
+        _ => return Ok(None),
+    };
+
+    let window_days = Days::new((cooldown_days - 1).into());
+    let start_date = Date::from(*activity.date.as_ref() - window_days);
+    let end_date = Date::from(*activity.date.as_ref() + window_days);
+
+    let conflicting_activity = chore_activity::get_all_creditable_for_chore_in_period(pool, &chore.id, start_date, end_date)
+        .await?
+        .into_iter()
+        .find(|other_activity| other_activity.id != activity.id);
+
+    Ok(conflicting_activity)
+}
+
+/// Re-evaluates which activities of the chore violate its cooldown, e.g.
+/// after an earlier activity has been deleted or moved to another date.
+/// Activities are credited in the order they were done.
+pub async fn update_cooldown_violations(
+    pool: &crate::db::Pool,
+    chore: &Chore,
+) -> Result<(), sqlx::Error> {
+    let cooldown_days = chore.cooldown_days.filter(|cooldown_days| *cooldown_days > 0);
+
+    let mut activities = chore_activity::get_all_for_chore(pool, &chore.id).await?;
+    activities.reverse();
+
+    let mut last_creditable_date: Option<Date> = None;
+    for mut activity in activities.into_iter().filter(|activity| !activity.is_deleted()) {
+        let is_cooldown_violation = match (cooldown_days, last_creditable_date) {
+            (Some(cooldown_days), Some(last_creditable_date)) => {
+                (*activity.date.as_ref() - *last_creditable_date.as_ref()).num_days() < i64::from(cooldown_days)
+            },
+            _ => false,
+        };
+
+        if !is_cooldown_violation {
+            last_creditable_date = Some(activity.date);
+        }
+
+        if activity.is_cooldown_violation != is_cooldown_violation {
+            activity.is_cooldown_violation = is_cooldown_violation;
+            chore_activity::update(pool, &activity).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Activities can be changed by the user they are credited to, by whoever
+/// recorded them, e.g. for a non-scoring participant, and by admins
+pub async fn can_be_managed_by(
+    pool: &crate::db::Pool,
+    activity: &ChoreActivity,
+    user_id: &UserId,
+) -> bool {
+    activity.user_id == *user_id
+        || activity.recorded_by_user_id == Some(*user_id)
+        || service::user::has_permission(pool, user_id, Permission::ManageAllActivities).await
+}
+
+/// Stores which items of the chore checklist were done for the activity,
+/// replacing what has been stored before
+pub async fn save_checklist(
+    pool: &crate::db::Pool,
+    activity: &ChoreActivity,
+    checklist_items: &[ChoreChecklistItem],
+    done_item_ids: &[ChoreChecklistItemId],
+) -> Result<(), sqlx::Error> {
+    chore_activity_checklist_item::delete_all_for_chore_activity(pool, &activity.id).await?;
+
+    for item in checklist_items {
+        chore_activity_checklist_item::create(pool, &ChoreActivityChecklistItem {
+            id: ChoreActivityChecklistItemId::new(),
+            chore_activity_id: activity.id,
+            position: item.position,
+            name: item.name.clone(),
+            is_done: done_item_ids.contains(&item.id),
+        }).await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{model::{chore::Chore, chore_activity::{self, ChoreActivityId}, user::{self, UserKind, UserRole}}, test_util};
+
+    async fn get_violating_activity_ids(pool: &crate::db::Pool, chore: &Chore) -> Vec<ChoreActivityId> {
+        chore_activity::get_all_for_chore(pool, &chore.id).await.unwrap()
+            .into_iter()
+            .filter(|activity| activity.is_cooldown_violation)
+            .map(|activity| activity.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn backdated_activity_takes_the_credit_from_a_later_one() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let chore = test_util::create_chore(&pool, Chore {
+            cooldown_days: Some(7),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        let later_activity = test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 10)).await;
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+        assert!(get_violating_activity_ids(&pool, &chore).await.is_empty());
+
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 8)).await;
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+        assert_eq!(get_violating_activity_ids(&pool, &chore).await, vec![later_activity.id]);
+    }
+
+    #[tokio::test]
+    async fn activity_on_the_last_day_of_the_cooldown_is_a_violation() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let chore = test_util::create_chore(&pool, Chore {
+            cooldown_days: Some(3),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 1)).await;
+        let violating_activity = test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 3)).await;
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 4)).await;
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+
+        assert_eq!(get_violating_activity_ids(&pool, &chore).await, vec![violating_activity.id]);
+    }
+
+    #[tokio::test]
+    async fn changed_cooldown_updates_violations() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let user = test_util::create_user(&pool, "Alice").await;
+        let mut chore = test_util::create_chore(&pool, Chore {
+            cooldown_days: Some(5),
+            ..test_util::make_chore(&chore_list)
+        }).await;
+
+        test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 1)).await;
+        let second_activity = test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 4)).await;
+        let third_activity = test_util::create_activity(&pool, &chore, &user, test_util::date(2026, 1, 10)).await;
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+        assert_eq!(get_violating_activity_ids(&pool, &chore).await, vec![second_activity.id]);
+
+        chore.cooldown_days = Some(10);
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+        assert_eq!(get_violating_activity_ids(&pool, &chore).await, vec![third_activity.id, second_activity.id]);
+
+        chore.cooldown_days = None;
+        super::update_cooldown_violations(&pool, &chore).await.unwrap();
+        assert!(get_violating_activity_ids(&pool, &chore).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn activity_of_non_scoring_participant_can_be_managed_by_recording_user_and_admins() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let alice = test_util::create_user(&pool, "Alice").await;
+        let mut bob = test_util::create_user(&pool, "Bob").await;
+        let mut cleaner = test_util::create_user(&pool, "Cleaner").await;
+        cleaner.kind = UserKind::NonScoring;
+        user::update(&pool, &cleaner).await.unwrap();
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+
+        let mut activity = test_util::create_activity(&pool, &chore, &cleaner, test_util::date(2026, 1, 1)).await;
+        activity.recorded_by_user_id = Some(alice.id);
+
+        assert!(super::can_be_managed_by(&pool, &activity, &alice.id).await);
+        assert!(!super::can_be_managed_by(&pool, &activity, &bob.id).await);
+
+        bob.role = UserRole::Admin;
+        user::update(&pool, &bob).await.unwrap();
+        assert!(super::can_be_managed_by(&pool, &activity, &bob.id).await);
+    }
+}
@@ -4,8 +4,16 @@ pub struct UserScores {
     pub user_id: UserId,
     pub score: i32,
     pub adjusted_score: i32,
+    /// Points the user could have earned since the start of the interval,
+    /// leaving out chores they are excluded from
+    pub available_points: u32,
+    /// Points anybody could have earned since the start of the interval
+    pub total_points: u32,
 }
 
+/// The score is scaled up for days on which the user was absent and for
+/// chores they are excluded from, so that it is comparable to the scores of
+/// the other users
 pub async fn get_adjusted_score_per_user(
     pool: &crate::db::Pool,
     chore_list: &ChoreList,
@@ -22,11 +30,17 @@ pub async fn get_adjusted_score_per_user(
 
     let interval_passed_days = Date::now().as_ref().signed_duration_since(*interval_start_date.as_ref()).num_days() + 1;
 
-    let (score_per_user, absences) = tokio::try_join!(
+    let (score_per_user, absences, chores, exclusions) = tokio::try_join!(
         model::chore_list::get_score_per_user(pool, chore_list),
-        model::absence::get_active_in_period(pool, interval_start_date, Date::now())
+        model::absence::get_active_in_period(pool, interval_start_date, Date::now()),
+        model::chore::get_all_for_chore_list(pool, &chore_list.id),
+        model::chore_exclusion::get_all_for_chore_list(pool, &chore_list.id),
     ).unwrap();
 
+    let total_points_per_day = chores.iter()
+        .map(|chore| chore.get_expected_points_per_day())
+        .sum::<f64>();
+
     let user_scores = score_per_user.iter()
         .map(|&(user_id, score)| {
             let user_absences = absences.iter()
@@ -40,13 +54,24 @@ pub async fn get_adjusted_score_per_user(
             ) as i64;
             let present_num_days = interval_passed_days - absent_num_days;
 
-            let adjusted_score = if score == 0 || absent_num_days == 0 || present_num_days == 0 || interval_passed_days == 0 {
-                score
-            } else {
-                (score as f64 / present_num_days as f64 * interval_passed_days as f64).round() as i32
-            };
+            let available_points_per_day = chores.iter()
+                .filter(|chore| !exclusions.iter().any(|exclusion| exclusion.chore_id == chore.id && exclusion.user_id == user_id))
+                .map(|chore| chore.get_expected_points_per_day())
+                .sum::<f64>();
+
+            let mut adjusted_score = score as f64;
+            if absent_num_days > 0 && present_num_days > 0 && interval_passed_days > 0 {
+                adjusted_score = adjusted_score / present_num_days as f64 * interval_passed_days as f64;
+            }
+            if available_points_per_day > 0.0 && available_points_per_day < total_points_per_day {
+                adjusted_score = adjusted_score / available_points_per_day * total_points_per_day;
+            }
+            let adjusted_score = adjusted_score.round() as i32;
+
+            let available_points = (available_points_per_day * interval_passed_days as f64).round() as u32;
+            let total_points = (total_points_per_day * interval_passed_days as f64).round() as u32;
 
-            UserScores { user_id, score, adjusted_score }
+            UserScores { user_id, score, adjusted_score, available_points, total_points }
         })
         .collect();
 
@@ -0,0 +1,140 @@
+use crate::model::chore::Chore;
+use crate::model::chore_activity::ChoreActivity;
+use crate::model::chore_swap_request::{self, ChoreSwapRequest, ChoreSwapRequestStatus};
+use crate::value::DateTime;
+
+/// Chores can only be handed over while they are scheduled and nobody has
+/// been asked to take them over yet
+pub async fn can_be_offered(
+    pool: &sqlx::sqlite::SqlitePool,
+    chore: &Chore,
+) -> Result<bool, sqlx::Error> {
+    if chore.is_deleted() || chore.is_completed() || chore.is_dormant() || chore.next_due_date.is_none() {
+        return Ok(false);
+    }
+
+    let open_requests = chore_swap_request::get_all_open_for_chore(pool, &chore.id).await?;
+
+    Ok(open_requests.is_empty())
+}
+
+/// Completes the oldest accepted swap request for the chore of the given
+/// activity, if the activity was logged by the user the chore was handed
+/// over to. The offered points, which were reserved when the request was
+/// created, are transferred from then on, as long as the activity stays with
+/// the chore of the request.
+///
+/// Activities which violate the cooldown of the chore don't complete
+/// anything, as they don't earn points either.
+pub async fn complete_for_activity(
+    pool: &sqlx::sqlite::SqlitePool,
+    activity: &ChoreActivity,
+) -> Result<Option<ChoreSwapRequest>, sqlx::Error> {
+    if activity.is_deleted() || activity.is_cooldown_violation {
+        return Ok(None);
+    }
+
+    let accepted_requests = chore_swap_request::get_all_accepted_for_chore_and_target_user(
+        pool,
+        &activity.chore_id,
+        &activity.user_id,
+    ).await?;
+
+    let mut swap_request = match accepted_requests.into_iter().next() {
+        Some(swap_request) => swap_request,
+        None => return Ok(None),
+    };
+
+    swap_request.status = ChoreSwapRequestStatus::Completed;
+    swap_request.chore_activity_id = Some(activity.id);
+    swap_request.date_updated = DateTime::now();
+
+    chore_swap_request::update(pool, &swap_request).await?;
+
+    Ok(Some(swap_request))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{model::{chore::Chore, chore_activity, chore_list::{self, ChoreList}, chore_swap_request::{self, ChoreSwapRequest, ChoreSwapRequestId, ChoreSwapRequestStatus}, user::{User, UserId}}, test_util, value::DateTime};
+
+    async fn get_balance(pool: &crate::db::Pool, chore_list: &ChoreList, user: &User) -> i32 {
+        chore_list::get_balance_per_user(pool, chore_list).await.unwrap()
+            .into_iter()
+            .find(|(user_id, _balance)| *user_id == user.id)
+            .map(|(_user_id, balance)| balance)
+            .unwrap()
+    }
+
+    fn make_swap_request(chore: &Chore, requesting_user_id: UserId, target_user_id: UserId, offered_points: u32) -> ChoreSwapRequest {
+        ChoreSwapRequest {
+            id: ChoreSwapRequestId::new(),
+            chore_id: chore.id,
+            requesting_user_id,
+            target_user_id: Some(target_user_id),
+            absence_id: None,
+            offered_points,
+            comment: None,
+            status: ChoreSwapRequestStatus::Accepted,
+            chore_activity_id: None,
+            date_created: DateTime::now(),
+            date_updated: DateTime::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn offered_points_are_reserved_until_completion() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let alice = test_util::create_user(&pool, "Alice").await;
+        let bob = test_util::create_user(&pool, "Bob").await;
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+
+        test_util::create_activity(&pool, &chore, &alice, test_util::date(2026, 1, 1)).await;
+        chore_swap_request::create(&pool, &make_swap_request(&chore, alice.id, bob.id, 4)).await.unwrap();
+        assert_eq!(get_balance(&pool, &chore_list, &alice).await, 6);
+        assert_eq!(get_balance(&pool, &chore_list, &bob).await, 0);
+
+        let activity = test_util::create_activity(&pool, &chore, &bob, test_util::date(2026, 1, 2)).await;
+        let swap_request = super::complete_for_activity(&pool, &activity).await.unwrap();
+        assert!(swap_request.is_some());
+        assert_eq!(get_balance(&pool, &chore_list, &alice).await, 6);
+        assert_eq!(get_balance(&pool, &chore_list, &bob).await, 14);
+    }
+
+    #[tokio::test]
+    async fn points_are_not_transferred_for_activity_moved_to_another_chore() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let alice = test_util::create_user(&pool, "Alice").await;
+        let bob = test_util::create_user(&pool, "Bob").await;
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+        let other_chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+
+        test_util::create_activity(&pool, &chore, &alice, test_util::date(2026, 1, 1)).await;
+        chore_swap_request::create(&pool, &make_swap_request(&chore, alice.id, bob.id, 4)).await.unwrap();
+        let mut activity = test_util::create_activity(&pool, &chore, &bob, test_util::date(2026, 1, 2)).await;
+        super::complete_for_activity(&pool, &activity).await.unwrap();
+
+        activity.chore_id = other_chore.id;
+        chore_activity::update(&pool, &activity).await.unwrap();
+
+        assert_eq!(get_balance(&pool, &chore_list, &alice).await, 10);
+        assert_eq!(get_balance(&pool, &chore_list, &bob).await, 10);
+    }
+
+    #[tokio::test]
+    async fn cooldown_violation_does_not_complete_swap_request() {
+        let pool = test_util::create_pool().await;
+        let chore_list = test_util::create_chore_list(&pool).await;
+        let alice = test_util::create_user(&pool, "Alice").await;
+        let bob = test_util::create_user(&pool, "Bob").await;
+        let chore = test_util::create_chore(&pool, test_util::make_chore(&chore_list)).await;
+
+        chore_swap_request::create(&pool, &make_swap_request(&chore, alice.id, bob.id, 0)).await.unwrap();
+        let mut activity = test_util::create_activity(&pool, &chore, &bob, test_util::date(2026, 1, 2)).await;
+        activity.is_cooldown_violation = true;
+
+        assert!(super::complete_for_activity(&pool, &activity).await.unwrap().is_none());
+    }
+}
@@ -1,4 +1,7 @@
 pub mod user;
 pub mod chore_list;
 pub mod chore;
+pub mod chore_activity;
+pub mod chore_swap_request;
 pub mod absence;
+pub mod passkey;
@@ -0,0 +1,344 @@
+//! Minimal WebAuthn relying party, supporting ES256 passkeys which are
+//! registered without attestation
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::TimeDelta;
+use p256::ecdsa::{Signature, VerifyingKey, signature::Verifier};
+use p256::pkcs8::DecodePublicKey;
+use sha2::{Digest, Sha256};
+use crate::{model::{passkey::{self, Passkey, PasskeyId}, passkey_challenge::{self, PasskeyChallenge, PasskeyChallengeId}, user::{User, UserId}}, value::DateTime};
+
+/// COSE identifier of ECDSA with SHA-256 on the P-256 curve
+pub const ES256_ALGORITHM: i64 = -7;
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+
+pub struct RelyingParty {
+    /// Domain the passkeys are bound to
+    pub id: String,
+    pub origin: String,
+}
+
+impl RelyingParty {
+    /// The base URL is the public URL of the instance without trailing slash
+    pub fn from_base_url(base_url: &str) -> Self {
+        let host = base_url
+            .split_once("://")
+            .map_or(base_url, |(_scheme, rest)| rest)
+            .split(['/', ':'])
+            .next()
+            .unwrap_or_default();
+
+        Self {
+            id: host.to_string(),
+            origin: base_url.to_string(),
+        }
+    }
+}
+
+/// Fields are base64url encoded, as handed over by the browser
+pub struct RegistrationResponse {
+    pub credential_id: String,
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub public_key: String,
+    pub public_key_algorithm: i64,
+}
+
+/// Fields are base64url encoded, as handed over by the browser
+pub struct AuthenticationResponse {
+    pub credential_id: String,
+    pub client_data_json: String,
+    pub authenticator_data: String,
+    pub signature: String,
+}
+
+#[derive(serde::Deserialize)]
+struct ClientData {
+    #[serde(rename = "type")]
+    kind: String,
+    challenge: String,
+    origin: String,
+}
+
+/// Challenges for logging in can be requested without being logged in, so
+/// their number is limited to keep the table from being filled up
+const MAX_LOGIN_CHALLENGES_PER_IP_ADDRESS: u32 = 10;
+const MAX_LOGIN_CHALLENGES: u32 = 1000;
+
+/// Challenge for registering a passkey of the user
+pub async fn create_challenge(pool: &crate::db::Pool, user_id: UserId) -> PasskeyChallenge {
+    passkey_challenge::delete_all_expired(pool).await.unwrap();
+
+    let passkey_challenge = make_challenge(Some(user_id), None);
+    passkey_challenge::create(pool, &passkey_challenge).await.unwrap();
+
+    passkey_challenge
+}
+
+/// Returns `None` if too many challenges are pending, either from this IP
+/// address or in total
+pub async fn create_login_challenge(pool: &crate::db::Pool, ip_address: &str) -> Option<PasskeyChallenge> {
+    passkey_challenge::delete_all_expired(pool).await.unwrap();
+
+    let pending_for_ip_address = passkey_challenge::count_unexpired_for_login(pool, Some(ip_address)).await.unwrap();
+    let pending = passkey_challenge::count_unexpired_for_login(pool, None).await.unwrap();
+    if pending_for_ip_address >= MAX_LOGIN_CHALLENGES_PER_IP_ADDRESS || pending >= MAX_LOGIN_CHALLENGES {
+        tracing::warn!(ip_address, pending_for_ip_address, pending, "Too many pending passkey login challenges");
+        return None;
+    }
+
+    let passkey_challenge = make_challenge(None, Some(ip_address.to_string()));
+    passkey_challenge::create(pool, &passkey_challenge).await.unwrap();
+
+    Some(passkey_challenge)
+}
+
+fn make_challenge(user_id: Option<UserId>, ip_address: Option<String>) -> PasskeyChallenge {
+    let mut challenge_buf = [0u8; 32];
+    getrandom::getrandom(&mut challenge_buf).unwrap();
+
+    PasskeyChallenge {
+        id: PasskeyChallengeId::new(),
+        challenge: URL_SAFE_NO_PAD.encode(challenge_buf),
+        user_id,
+        ip_address,
+        date_expires: DateTime::from(*DateTime::now().as_ref() + TimeDelta::minutes(5)),
+        date_created: DateTime::now(),
+    }
+}
+
+/// Returns the new passkey, or `None` if the response isn't valid
+pub async fn register(
+    pool: &crate::db::Pool,
+    relying_party: &RelyingParty,
+    user: &User,
+    name: String,
+    response: &RegistrationResponse,
+) -> Option<Passkey> {
+    if response.public_key_algorithm != ES256_ALGORITHM {
+        return None;
+    }
+
+    let client_data_json = URL_SAFE_NO_PAD.decode(&response.client_data_json).ok()?;
+    let authenticator_data = URL_SAFE_NO_PAD.decode(&response.authenticator_data).ok()?;
+    let public_key = URL_SAFE_NO_PAD.decode(&response.public_key).ok()?;
+
+    verify_client_data(pool, relying_party, &client_data_json, "webauthn.create", Some(user.id)).await?;
+    let sign_count = verify_authenticator_data(relying_party, &authenticator_data)?;
+    VerifyingKey::from_public_key_der(&public_key).ok()?;
+
+    match passkey::get_by_credential_id(pool, &response.credential_id).await {
+        Ok(_) => return None,
+        Err(sqlx::Error::RowNotFound) => {},
+        Err(err) => panic!("{}", err),
+    }
+
+    let passkey = Passkey {
+        id: PasskeyId::new(),
+        user_id: user.id,
+        name,
+        credential_id: response.credential_id.clone(),
+        public_key,
+        sign_count,
+        date_last_used: None,
+        date_created: DateTime::now(),
+    };
+    passkey::create(pool, &passkey).await.unwrap();
+
+    Some(passkey)
+}
+
+/// Returns the used passkey, or `None` if the response isn't valid
+pub async fn authenticate(
+    pool: &crate::db::Pool,
+    relying_party: &RelyingParty,
+    response: &AuthenticationResponse,
+) -> Option<Passkey> {
+    let client_data_json = URL_SAFE_NO_PAD.decode(&response.client_data_json).ok()?;
+    let authenticator_data = URL_SAFE_NO_PAD.decode(&response.authenticator_data).ok()?;
+    let signature = URL_SAFE_NO_PAD.decode(&response.signature).ok()?;
+
+    let mut passkey = match passkey::get_by_credential_id(pool, &response.credential_id).await {
+        Ok(passkey) => passkey,
+        Err(sqlx::Error::RowNotFound) => return None,
+        Err(err) => panic!("{}", err),
+    };
+
+    verify_client_data(pool, relying_party, &client_data_json, "webauthn.get", None).await?;
+    let sign_count = verify_authenticator_data(relying_party, &authenticator_data)?;
+
+    let verifying_key = VerifyingKey::from_public_key_der(&passkey.public_key).ok()?;
+    let signature = Signature::from_der(&signature).ok()?;
+    let signed_data = [authenticator_data.as_slice(), &Sha256::digest(&client_data_json)[..]].concat();
+    verifying_key.verify(&signed_data, &signature).ok()?;
+
+    // A counter that doesn't increase hints at a cloned authenticator. Many
+    // passkeys always report zero though.
+    if (sign_count != 0 || passkey.sign_count != 0) && sign_count <= passkey.sign_count {
+        return None;
+    }
+
+    passkey.sign_count = sign_count;
+    passkey.date_last_used = Some(DateTime::now());
+    passkey::update(pool, &passkey).await.unwrap();
+
+    Some(passkey)
+}
+
+/// Also uses up the challenge
+async fn verify_client_data(
+    pool: &crate::db::Pool,
+    relying_party: &RelyingParty,
+    client_data_json: &[u8],
+    expected_kind: &str,
+    expected_user_id: Option<UserId>,
+) -> Option<()> {
+    let client_data = serde_json::from_slice::<ClientData>(client_data_json).ok()?;
+    if client_data.kind != expected_kind || client_data.origin != relying_party.origin {
+        return None;
+    }
+
+    let passkey_challenge = match passkey_challenge::consume_by_challenge(pool, &client_data.challenge).await {
+        Ok(passkey_challenge) => passkey_challenge,
+        Err(sqlx::Error::RowNotFound) => return None,
+        Err(err) => panic!("{}", err),
+    };
+
+    if passkey_challenge.is_expired() || passkey_challenge.user_id != expected_user_id {
+        return None;
+    }
+
+    Some(())
+}
+
+/// Returns the signature counter
+fn verify_authenticator_data(relying_party: &RelyingParty, authenticator_data: &[u8]) -> Option<u32> {
+    if authenticator_data.len() < 37 {
+        return None;
+    }
+
+    let rp_id_hash = &authenticator_data[0..32];
+    if rp_id_hash != &Sha256::digest(relying_party.id.as_bytes())[..] {
+        return None;
+    }
+
+    let flags = authenticator_data[32];
+    if flags & FLAG_USER_PRESENT == 0 || flags & FLAG_USER_VERIFIED == 0 {
+        return None;
+    }
+
+    Some(u32::from_be_bytes(authenticator_data[33..37].try_into().unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+    use p256::ecdsa::{Signature, SigningKey, signature::Signer};
+    use p256::pkcs8::EncodePublicKey;
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use crate::{model::passkey::{self, Passkey, PasskeyId}, test_util, value::DateTime};
+    use super::{AuthenticationResponse, RelyingParty, FLAG_USER_PRESENT, FLAG_USER_VERIFIED};
+
+    fn relying_party() -> RelyingParty {
+        RelyingParty::from_base_url("https://wg.example.com")
+    }
+
+    fn make_authenticator_data(relying_party_id: &str, flags: u8, sign_count: u32) -> Vec<u8> {
+        [&Sha256::digest(relying_party_id.as_bytes())[..], &[flags], &sign_count.to_be_bytes()].concat()
+    }
+
+    fn make_client_data_json(kind: &str, challenge: &str) -> Vec<u8> {
+        serde_json::json!({
+            "type": kind,
+            "challenge": challenge,
+            "origin": "https://wg.example.com",
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    fn sign(signing_key: &SigningKey, authenticator_data: &[u8], client_data_json: &[u8]) -> String {
+        let signed_data = [authenticator_data, &Sha256::digest(client_data_json)[..]].concat();
+        let signature: Signature = signing_key.sign(&signed_data);
+
+        URL_SAFE_NO_PAD.encode(signature.to_der().as_bytes())
+    }
+
+    /// Passkey of a new user, together with the key its responses are
+    /// signed with
+    async fn create_passkey(pool: &crate::db::Pool) -> (Passkey, SigningKey) {
+        let user = test_util::create_user(pool, "Alice").await;
+        let signing_key = SigningKey::random(&mut OsRng);
+        let passkey = Passkey {
+            id: PasskeyId::new(),
+            user_id: user.id,
+            name: "Phone".to_string(),
+            credential_id: "credential".to_string(),
+            public_key: signing_key.verifying_key().to_public_key_der().unwrap().as_bytes().to_vec(),
+            sign_count: 0,
+            date_last_used: None,
+            date_created: DateTime::now(),
+        };
+        passkey::create(pool, &passkey).await.unwrap();
+
+        (passkey, signing_key)
+    }
+
+    #[test]
+    fn authenticator_data_requires_user_presence_and_verification() {
+        let relying_party = relying_party();
+        let both_flags = FLAG_USER_PRESENT | FLAG_USER_VERIFIED;
+
+        assert_eq!(super::verify_authenticator_data(&relying_party, &make_authenticator_data("wg.example.com", both_flags, 3)), Some(3));
+        assert_eq!(super::verify_authenticator_data(&relying_party, &make_authenticator_data("wg.example.com", FLAG_USER_PRESENT, 3)), None);
+        assert_eq!(super::verify_authenticator_data(&relying_party, &make_authenticator_data("wg.example.com", FLAG_USER_VERIFIED, 3)), None);
+        assert_eq!(super::verify_authenticator_data(&relying_party, &make_authenticator_data("evil.example.com", both_flags, 3)), None);
+        assert_eq!(super::verify_authenticator_data(&relying_party, &[0; 36]), None);
+    }
+
+    #[tokio::test]
+    async fn authenticate_verifies_signature_and_uses_up_challenge() {
+        let pool = test_util::create_pool().await;
+        let relying_party = relying_party();
+        let (passkey, signing_key) = create_passkey(&pool).await;
+        let challenge = super::create_login_challenge(&pool, "127.0.0.1").await.unwrap();
+
+        let authenticator_data = make_authenticator_data("wg.example.com", FLAG_USER_PRESENT | FLAG_USER_VERIFIED, 1);
+        let client_data_json = make_client_data_json("webauthn.get", &challenge.challenge);
+        let response = AuthenticationResponse {
+            credential_id: passkey.credential_id.clone(),
+            client_data_json: URL_SAFE_NO_PAD.encode(&client_data_json),
+            authenticator_data: URL_SAFE_NO_PAD.encode(&authenticator_data),
+            signature: sign(&signing_key, &authenticator_data, &client_data_json),
+        };
+
+        let authenticated_passkey = super::authenticate(&pool, &relying_party, &response).await.unwrap();
+        assert_eq!(authenticated_passkey.id, passkey.id);
+        assert_eq!(authenticated_passkey.sign_count, 1);
+
+        // Replaying the same response fails, as the challenge is gone
+        assert!(super::authenticate(&pool, &relying_party, &response).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn authenticate_rejects_signature_of_other_key() {
+        let pool = test_util::create_pool().await;
+        let relying_party = relying_party();
+        let (passkey, _signing_key) = create_passkey(&pool).await;
+        let challenge = super::create_login_challenge(&pool, "127.0.0.1").await.unwrap();
+
+        let authenticator_data = make_authenticator_data("wg.example.com", FLAG_USER_PRESENT | FLAG_USER_VERIFIED, 1);
+        let client_data_json = make_client_data_json("webauthn.get", &challenge.challenge);
+        let response = AuthenticationResponse {
+            credential_id: passkey.credential_id.clone(),
+            client_data_json: URL_SAFE_NO_PAD.encode(&client_data_json),
+            authenticator_data: URL_SAFE_NO_PAD.encode(&authenticator_data),
+            signature: sign(&SigningKey::random(&mut OsRng), &authenticator_data, &client_data_json),
+        };
+
+        assert!(super::authenticate(&pool, &relying_party, &response).await.is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use secrecy::SecretString;
-use crate::{model::{chore_list::{self, ChoreListId}, user::{self, User, UserId}}, service, value::{DateTime, PasswordHash}};
+use crate::{model::{chore_list::{self, ChoreListId}, user::{self, Permission, User, UserId, UserKind, UserRole}, user_recovery_code::{self, UserRecoveryCode, UserRecoveryCodeId}}, service, value::{DateTime, PasswordHash}};
 
 pub async fn exists_any_user(pool: &crate::db::Pool) -> bool {
     let users = user::get_all(pool).await.unwrap();
@@ -17,8 +17,11 @@ pub async fn create_default_admin_user(pool: &crate::db::Pool) -> (User, SecretS
     let user = User {
         id: UserId::new(),
         name: "Admin".to_string(),
-        email: "admin@localhost".to_string(),
-        password_hash: PasswordHash::from_plain_password(plain_password.clone()),
+        email: Some("admin@localhost".to_string()),
+        password_hash: Some(PasswordHash::from_plain_password(plain_password.clone())),
+        kind: UserKind::Member,
+        role: UserRole::Admin,
+        totp_secret: None,
         last_used_language: None,
         date_created: DateTime::now(),
         date_deleted: None,
@@ -28,6 +31,85 @@ pub async fn create_default_admin_user(pool: &crate::db::Pool) -> (User, SecretS
     (user, plain_password)
 }
 
+/// Recovery codes are shown hex encoded
+const RECOVERY_CODE_BYTES: usize = 5;
+
+/// Unknown users don't have any permissions
+pub async fn has_permission(pool: &crate::db::Pool, user_id: &UserId, permission: Permission) -> bool {
+    match user::get_by_id(pool, user_id).await {
+        Ok(user) => user.has_permission(permission),
+        Err(sqlx::Error::RowNotFound) => false,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Accepts either a one-time password, which can only be used once, or one
+/// of the recovery codes, which is used up in that case
+pub async fn verify_second_factor(pool: &crate::db::Pool, user: &User, code: &str) -> bool {
+    let Some(totp_secret) = &user.totp_secret else {
+        return false;
+    };
+
+    if let Some(step) = totp_secret.verify(code) {
+        return user::update_totp_last_used_step(pool, &user.id, step).await.unwrap();
+    }
+
+    // Every recovery code has to be checked against its slow hash, so this is
+    // only done for input that could actually be one
+    let plain_recovery_code = normalize_recovery_code(code);
+    if !is_recovery_code_format(&plain_recovery_code) {
+        return false;
+    }
+
+    let recovery_codes = user_recovery_code::get_all_for_user(pool, &user.id).await.unwrap();
+    for recovery_code in recovery_codes.iter() {
+        if recovery_code.code_hash.verify(plain_recovery_code.clone().into()) {
+            user_recovery_code::delete(pool, recovery_code).await.unwrap();
+
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Replaces the previous recovery codes of the user. Returns the plain codes,
+/// as only their hashes are stored.
+pub async fn regenerate_recovery_codes(pool: &crate::db::Pool, user: &User) -> Vec<SecretString> {
+    user_recovery_code::delete_all_for_user(pool, &user.id).await.unwrap();
+
+    let mut plain_recovery_codes = Vec::new();
+    for _ in 0..10 {
+        let mut code_buf = [0u8; RECOVERY_CODE_BYTES];
+        getrandom::getrandom(&mut code_buf).unwrap();
+        let code = const_hex::encode(code_buf);
+
+        let recovery_code = UserRecoveryCode {
+            id: UserRecoveryCodeId::new(),
+            user_id: user.id,
+            code_hash: PasswordHash::from_plain_password(code.clone().into()),
+            date_created: DateTime::now(),
+        };
+        user_recovery_code::create(pool, &recovery_code).await.unwrap();
+
+        plain_recovery_codes.push(format!("{}-{}", &code[..5], &code[5..]).into());
+    }
+
+    plain_recovery_codes
+}
+
+fn is_recovery_code_format(plain_recovery_code: &str) -> bool {
+    plain_recovery_code.len() == RECOVERY_CODE_BYTES * 2
+        && plain_recovery_code.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>()
+        .to_lowercase()
+}
+
 pub async fn get_low_score_users(pool: &crate::db::Pool) -> HashMap<UserId, Vec<ChoreListId>> {
     let mut low_score_users = HashMap::new();
 
@@ -81,3 +163,48 @@ pub async fn get_low_score_users(pool: &crate::db::Pool) -> HashMap<UserId, Vec<
 
     low_score_users
 }
+
+#[cfg(test)]
+mod tests {
+    use secrecy::ExposeSecret;
+    use crate::{model::user, test_util, value::TotpSecret};
+
+    fn generate_current_code(totp_secret: &TotpSecret) -> String {
+        let secret = totp_rs::Secret::Encoded(totp_secret.expose_encoded().to_string())
+            .to_bytes()
+            .unwrap();
+
+        totp_rs::TOTP::new(totp_rs::Algorithm::SHA1, 6, 0, 30, secret, None, String::new())
+            .unwrap()
+            .generate_current()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn totp_code_can_only_be_used_once() {
+        let pool = test_util::create_pool().await;
+        let mut user = test_util::create_user(&pool, "Alice").await;
+        user.totp_secret = Some(TotpSecret::generate());
+        user::update(&pool, &user).await.unwrap();
+
+        let code = generate_current_code(user.totp_secret.as_ref().unwrap());
+
+        assert!(super::verify_second_factor(&pool, &user, &code).await);
+        assert!(!super::verify_second_factor(&pool, &user, &code).await);
+    }
+
+    #[tokio::test]
+    async fn recovery_code_can_only_be_used_once() {
+        let pool = test_util::create_pool().await;
+        let mut user = test_util::create_user(&pool, "Alice").await;
+        user.totp_secret = Some(TotpSecret::generate());
+        user::update(&pool, &user).await.unwrap();
+
+        let recovery_codes = super::regenerate_recovery_codes(&pool, &user).await;
+        let recovery_code = recovery_codes[0].expose_secret();
+
+        assert!(!super::verify_second_factor(&pool, &user, "not a code").await);
+        assert!(super::verify_second_factor(&pool, &user, recovery_code).await);
+        assert!(!super::verify_second_factor(&pool, &user, recovery_code).await);
+    }
+}
@@ -0,0 +1,121 @@
+//! Fixtures for tests which need a database
+
+use std::str::FromStr;
+use chrono::NaiveDate;
+use crate::{db::Pool, model::{chore::{self, Chore, ChoreId}, chore_activity::{self, ChoreActivity, ChoreActivityId}, chore_list::{self, ChoreList, ChoreListId, ScoreResetInterval}, user::{self, User, UserId, UserKind, UserRole}}, value::{Date, DateTime}};
+
+/// In-memory database with all migrations applied. A single connection is
+/// used, as every connection would get its own in-memory database.
+pub async fn create_pool() -> Pool {
+    let options = sqlx::sqlite::SqliteConnectOptions::from_str("sqlite::memory:").unwrap()
+        .foreign_keys(false);
+    let pool = sqlx::sqlite::SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(options)
+        .await
+        .unwrap();
+
+    crate::db::MIGRATOR.run(&pool).await.unwrap();
+    sqlx::query("PRAGMA foreign_keys = ON").execute(&pool).await.unwrap();
+
+    pool
+}
+
+pub fn date(year: i32, month: u32, day: u32) -> Date {
+    Date::from(NaiveDate::from_ymd_opt(year, month, day).unwrap())
+}
+
+pub fn make_chore_list() -> ChoreList {
+    ChoreList {
+        id: ChoreListId::new(),
+        name: "Chores".to_string(),
+        description: None,
+        score_reset_interval: ScoreResetInterval::Never,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    }
+}
+
+pub async fn create_chore_list(pool: &Pool) -> ChoreList {
+    let chore_list = make_chore_list();
+    chore_list::create(pool, &chore_list).await.unwrap();
+
+    chore_list
+}
+
+pub async fn create_user(pool: &Pool, name: &str) -> User {
+    let user = User {
+        id: UserId::new(),
+        name: name.to_string(),
+        email: Some(format!("{}@localhost", name.to_lowercase())),
+        password_hash: None,
+        kind: UserKind::Member,
+        role: UserRole::Member,
+        totp_secret: None,
+        last_used_language: None,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+    user::create(pool, &user).await.unwrap();
+
+    user
+}
+
+/// Recurring chore worth 10 points, which is changed by the caller before
+/// calling `create_chore`
+pub fn make_chore(chore_list: &ChoreList) -> Chore {
+    Chore {
+        id: ChoreId::new(),
+        chore_list_id: chore_list.id,
+        name: "Vacuum".to_string(),
+        points: 10,
+        points_voting_method: None,
+        interval_days: Some(7),
+        interval_hours: None,
+        cooldown_days: None,
+        bounty_points_per_day: None,
+        bounty_max_points: None,
+        max_quantity: None,
+        award_partial_checklist_points: false,
+        follow_up_of_chore_id: None,
+        follow_up_delay_days: None,
+        active_from_month: None,
+        active_until_month: None,
+        next_due_date: None,
+        next_due_time: None,
+        is_one_off: false,
+        date_completed: None,
+        description: None,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    }
+}
+
+pub async fn create_chore(pool: &Pool, chore: Chore) -> Chore {
+    chore::create(pool, &chore).await.unwrap();
+
+    chore
+}
+
+pub async fn create_activity(pool: &Pool, chore: &Chore, user: &User, date: Date) -> ChoreActivity {
+    let activity = ChoreActivity {
+        id: ChoreActivityId::new(),
+        chore_id: chore.id,
+        user_id: user.id,
+        recorded_by_user_id: Some(user.id),
+        date,
+        time: None,
+        points: chore.points,
+        quantity: 1,
+        bounty_points: 0,
+        comment: None,
+        is_cooldown_violation: false,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+    chore_activity::create(pool, &activity).await.unwrap();
+
+    activity
+}
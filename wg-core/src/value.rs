@@ -6,6 +6,7 @@ use argon2::{
 };
 use chrono::ParseError;
 use secrecy::{ExposeSecret, SecretString};
+use sha2::{Digest, Sha256};
 use sqlx::{
     Decode, Encode, Sqlite, Type,
     encode::IsNull,
@@ -78,6 +79,14 @@ impl Display for Uuid {
     }
 }
 
+impl FromStr for Uuid {
+    type Err = uuid::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        uuid::Uuid::parse_str(string).map(Uuid)
+    }
+}
+
 impl From<Uuid> for uuid::Uuid {
     fn from(value: Uuid) -> Self {
         value.0
@@ -148,6 +157,14 @@ impl<D, T> From<D> for Tagged<D, T> {
     }
 }
 
+impl<D: FromStr, T> FromStr for Tagged<D, T> {
+    type Err = D::Err;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        D::from_str(string).map(Self::from)
+    }
+}
+
 impl<D, T> AsRef<D> for Tagged<D, T> {
     fn as_ref(&self) -> &D {
         &self.inner
@@ -356,11 +373,79 @@ impl AsRef<chrono::NaiveDate> for Date {
     }
 }
 
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    sqlx::Type,
+)]
+#[sqlx(transparent)]
+#[repr(transparent)]
+pub struct Time(chrono::NaiveTime);
+
+impl Time {
+    pub fn now() -> Self {
+        Self(chrono::Utc::now().time())
+    }
+
+    pub fn format(&self, fmt: &str) -> String {
+        self.0.format(fmt).to_string()
+    }
+}
+
+impl Display for Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format("%H:%M"))
+    }
+}
+
+impl From<Time> for chrono::NaiveTime {
+    fn from(value: Time) -> Self {
+        value.0
+    }
+}
+
+impl From<chrono::NaiveTime> for Time {
+    fn from(value: chrono::NaiveTime) -> Self {
+        Self(value)
+    }
+}
+
+impl From<chrono::NaiveDateTime> for Time {
+    fn from(value: chrono::NaiveDateTime) -> Self {
+        Self(value.time())
+    }
+}
+
+impl FromStr for Time {
+    type Err = ParseError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        chrono::NaiveTime::from_str(string).map(Time)
+    }
+}
+
+impl AsRef<chrono::NaiveTime> for Time {
+    fn as_ref(&self) -> &chrono::NaiveTime {
+        &self.0
+    }
+}
+
 #[derive(Debug, serde::Deserialize)]
 #[repr(transparent)]
 pub struct PasswordHash(SecretString);
 
 impl PasswordHash {
+    /// Minimum number of characters of a plain password
+    pub const MIN_LENGTH: usize = 5;
+
     pub fn from_plain_password(plain_password: SecretString) -> Self {
         let hash = Argon2::default()
             .hash_password(
@@ -408,3 +493,107 @@ impl Decode<'_, Sqlite> for PasswordHash {
             .map(|password_hash| PasswordHash::from_hash(password_hash.into()))
     }
 }
+
+/// SHA-256 hash of a random token that is sent out by mail. Only the hash is
+/// stored, so that a leaked database can't be used to redeem the tokens.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::Type)]
+#[sqlx(transparent)]
+#[repr(transparent)]
+pub struct TokenHash(String);
+
+impl TokenHash {
+    pub fn from_token(token: &str) -> Self {
+        Self(const_hex::encode(Sha256::digest(token.as_bytes())))
+    }
+}
+
+/// Base32 encoded shared secret for RFC 6238 time-based one-time passwords
+#[derive(Debug, Clone, serde::Deserialize)]
+#[repr(transparent)]
+pub struct TotpSecret(SecretString);
+
+impl TotpSecret {
+    const ISSUER: &str = "WG";
+
+    pub fn generate() -> Self {
+        let mut secret_buf = [0u8; 20];
+        getrandom::getrandom(&mut secret_buf).unwrap();
+
+        match totp_rs::Secret::Raw(secret_buf.to_vec()).to_encoded() {
+            totp_rs::Secret::Encoded(encoded) => Self(encoded.into()),
+            totp_rs::Secret::Raw(_) => unreachable!(),
+        }
+    }
+
+    pub fn from_encoded(encoded: SecretString) -> Self {
+        Self(encoded)
+    }
+
+    /// Needed for handing the secret over to the authenticator app
+    pub fn expose_encoded(&self) -> &str {
+        self.0.expose_secret()
+    }
+
+    /// Also accepts the codes of the previous and next time step to allow for
+    /// clock drift. Returns the time step of the accepted code, so that it
+    /// can't be used again.
+    pub fn verify(&self, code: &str) -> Option<u64> {
+        let totp = self.to_totp("")?;
+
+        let code = code.chars().filter(|c| !c.is_whitespace()).collect::<String>();
+        let current_step = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()?
+            .as_secs() / totp.step;
+
+        [current_step.checked_sub(1), Some(current_step), current_step.checked_add(1)]
+            .into_iter()
+            .flatten()
+            .find(|step| totp.check(&code, step * totp.step))
+    }
+
+    /// otpauth:// URL to be encoded in the QR code for enrollment
+    pub fn get_url(&self, account_name: &str) -> String {
+        self.to_totp(&account_name.replace(':', ""))
+            .unwrap()
+            .get_url()
+    }
+
+    fn to_totp(&self, account_name: &str) -> Option<totp_rs::TOTP> {
+        let secret = totp_rs::Secret::Encoded(self.0.expose_secret().to_string())
+            .to_bytes()
+            .ok()?;
+
+        totp_rs::TOTP::new(
+            totp_rs::Algorithm::SHA1,
+            6,
+            0,
+            30,
+            secret,
+            Some(Self::ISSUER.to_string()),
+            account_name.to_string(),
+        ).ok()
+    }
+}
+
+impl Type<Sqlite> for TotpSecret {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for TotpSecret {
+    fn encode_by_ref(
+        &self,
+        args: &mut Vec<SqliteArgumentValue<'q>>,
+    ) -> Result<IsNull, BoxDynError> {
+        <String as Encode<Sqlite>>::encode_by_ref(&self.0.expose_secret().to_string(), args)
+    }
+}
+
+impl Decode<'_, Sqlite> for TotpSecret {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        <String as Decode<Sqlite>>::decode(value)
+            .map(|encoded| TotpSecret::from_encoded(encoded.into()))
+    }
+}
@@ -1,7 +1,7 @@
 use fluent_static::MessageBundle;
 use lettre::{message::{header::ContentType, Mailbox}, Message};
 use maud::html;
-use wg_core::model::{chore::Chore, chore_list::ChoreList, user::User};
+use wg_core::model::{absence::Absence, chore::Chore, chore_list::ChoreList, chore_swap_request::ChoreSwapRequest, login_attempt::LoginAttempt, reward::Reward, user::User, user_invitation::UserInvitation};
 use crate::{layout, message_builder, Translations, DEFAULT_LANGAGE};
 
 pub fn low_score_reminder(
@@ -39,9 +39,254 @@ pub fn low_score_reminder(
     ).into_string();
 
     message_builder()
-        .to(Mailbox::new(Some(user.name.clone()), user.email.parse().unwrap()))
+        .to(mailbox(user))
         .subject(t.message_low_score_reminder_title().to_string())
         .header(ContentType::TEXT_HTML)
         .body(html)
         .unwrap()
 }
+
+pub fn reward_redeemed(
+    user: &User,
+    redeeming_user: &User,
+    reward: &Reward,
+    chore_list: &ChoreList,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_reward_redeemed_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p { (t.message_reward_redeemed_content(&redeeming_user.name, &reward.name, reward.points, &chore_list.name)) }
+
+            @if let Some(description) = &reward.description {
+                p { (description) }
+            }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_reward_redeemed_subject(&redeeming_user.name, &reward.name).to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+pub fn chore_swap_request(
+    user: &User,
+    requesting_user: &User,
+    chore: &Chore,
+    swap_request: &ChoreSwapRequest,
+    url: &str,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_chore_swap_request_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p { (t.message_chore_swap_request_content(&requesting_user.name, &chore.name, swap_request.offered_points)) }
+
+            @if let Some(comment) = &swap_request.comment {
+                blockquote { (comment) }
+            }
+
+            p {
+                a href=(url) { (t.message_chore_swap_request_link()) }
+            }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_chore_swap_request_subject(&requesting_user.name, &chore.name).to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+pub fn chore_handover_offer(
+    user: &User,
+    absent_user: &User,
+    chore: &Chore,
+    absence: &Absence,
+    url: &str,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let date_start = absence.date_start.format("%Y-%m-%d");
+
+    let html = layout::default(
+        &language,
+        &t.message_chore_handover_offer_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p {
+                @match absence.date_end {
+                    Some(date_end) => (t.message_chore_handover_offer_content(&absent_user.name, &date_start, date_end.format("%Y-%m-%d"), &chore.name)),
+                    None => (t.message_chore_handover_offer_content_open_end(&absent_user.name, &date_start, &chore.name)),
+                }
+            }
+
+            p {
+                a href=(url) { (t.message_chore_handover_offer_link()) }
+            }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_chore_handover_offer_subject(&chore.name, &absent_user.name).to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+pub fn password_reset(
+    user: &User,
+    url: &str,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_password_reset_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p { (t.message_password_reset_content()) }
+
+            p {
+                a href=(url) { (t.message_password_reset_link()) }
+            }
+
+            p { (t.message_password_reset_ignore()) }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_password_reset_title().to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+pub fn login_link(
+    user: &User,
+    url: &str,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_login_link_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p { (t.message_login_link_content()) }
+
+            p {
+                a href=(url) { (t.message_login_link_link()) }
+            }
+
+            p { (t.message_login_link_ignore()) }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_login_link_title().to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+/// Written in the language of the inviting user, as nothing is known about
+/// the invitee yet
+pub fn user_invitation(
+    invitation: &UserInvitation,
+    inviting_user: &User,
+    url: &str,
+) -> Message {
+    let language = inviting_user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_user_invitation_title(),
+        html! {
+            p { (t.greeting(&invitation.name)) }
+
+            p { (t.message_user_invitation_content(&inviting_user.name)) }
+
+            p {
+                a href=(url) { (t.message_user_invitation_link()) }
+            }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(Mailbox::new(Some(invitation.name.clone()), invitation.email.parse().unwrap()))
+        .subject(t.message_user_invitation_subject(&inviting_user.name).to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+/// Warns about a login from an unknown device or IP address
+pub fn new_login(
+    user: &User,
+    login_attempt: &LoginAttempt,
+    url: &str,
+) -> Message {
+    let language = user.last_used_language.unwrap_or(DEFAULT_LANGAGE);
+    let t = Translations::get(language.as_ref()).unwrap();
+
+    let html = layout::default(
+        &language,
+        &t.message_new_login_title(),
+        html! {
+            p { (t.greeting(&user.name)) }
+
+            p { (t.message_new_login_content()) }
+
+            ul {
+                li { (t.message_new_login_time(login_attempt.date_created.format("%Y-%m-%d %H:%M UTC"))) }
+                li { (t.message_new_login_device(login_attempt.user_agent.as_deref().unwrap_or("-"))) }
+                li { (t.message_new_login_ip_address(&login_attempt.ip_address)) }
+            }
+
+            p { (t.message_new_login_revoke()) }
+
+            p {
+                a href=(url) { (t.message_new_login_link()) }
+            }
+        },
+    ).into_string();
+
+    message_builder()
+        .to(mailbox(user))
+        .subject(t.message_new_login_title().to_string())
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .unwrap()
+}
+
+/// Non-scoring participants don't have an email address and must not be
+/// passed here
+fn mailbox(user: &User) -> Mailbox {
+    Mailbox::new(Some(user.name.clone()), user.email.as_ref().unwrap().parse().unwrap())
+}
@@ -12,3 +12,37 @@ message_low_score_reminder_content_due_chores =
         [one] ist gerade folgende Aufgabe
         *[other] sind gerade folgende Aufgaben
     } fällig:
+message_reward_redeemed_title = Belohnung eingelöst
+message_reward_redeemed_subject = {$user_name} hat „{$reward_name}“ eingelöst
+message_reward_redeemed_content = {$user_name} hat gerade die Belohnung „{$reward_name}“ für {$points} Punkte in der Liste „{$chore_list_name}“ eingelöst.
+message_chore_swap_request_title = Aufgabentausch-Anfrage
+message_chore_swap_request_subject = {$user_name} bittet dich, „{$chore_name}“ zu übernehmen
+message_chore_swap_request_content = {$user_name} möchte, dass du die Aufgabe „{$chore_name}“ übernimmst{$offered_points ->
+        [0] .
+        *[other] und bietet dir dafür {$offered_points} Punkte an.
+    }
+message_chore_swap_request_link = Anfrage annehmen oder ablehnen
+message_chore_handover_offer_title = Aufgabenübergabe
+message_chore_handover_offer_subject = Kannst du „{$chore_name}“ für {$user_name} übernehmen?
+message_chore_handover_offer_content = {$user_name} ist von {$date_start} bis {$date_end} abwesend und sucht jemanden, der die Aufgabe „{$chore_name}“ übernimmt, die in diesem Zeitraum fällig wird.
+message_chore_handover_offer_content_open_end = {$user_name} ist ab {$date_start} abwesend und sucht jemanden, der die Aufgabe „{$chore_name}“ übernimmt, die in diesem Zeitraum fällig wird.
+message_chore_handover_offer_link = Aufgabe übernehmen
+message_password_reset_title = Passwort zurücksetzen
+message_password_reset_content = jemand möchte das Passwort deines Kontos zurücksetzen. Der folgende Link ist eine Stunde lang gültig und kann nur einmal verwendet werden.
+message_password_reset_link = Neues Passwort festlegen
+message_password_reset_ignore = Falls du das nicht angefordert hast, kannst du diese E-Mail ignorieren und dein Passwort bleibt unverändert.
+message_user_invitation_title = Einladung
+message_user_invitation_subject = {$user_name} hat dich zu WG eingeladen
+message_user_invitation_content = {$user_name} hat dich eingeladen, eure gemeinsamen Aufgaben zusammen im Blick zu behalten. Lege ein Passwort fest, um dein Konto zu aktivieren. Der Link ist sieben Tage lang gültig.
+message_user_invitation_link = Einladung annehmen
+message_login_link_title = Dein Login-Link
+message_login_link_content = mit dem folgenden Link kannst du dich anmelden. Er ist 15 Minuten lang gültig und kann nur einmal verwendet werden.
+message_login_link_link = Anmelden
+message_login_link_ignore = Falls du das nicht angefordert hast, kannst du diese E-Mail ignorieren.
+message_new_login_title = Neue Anmeldung bei deinem Konto
+message_new_login_content = bei deinem Konto hat sich gerade jemand von einem Gerät oder Netzwerk angemeldet, das bisher nicht verwendet wurde:
+message_new_login_time = Zeitpunkt: {$time}
+message_new_login_device = Gerät: {$device}
+message_new_login_ip_address = IP-Adresse: {$ip_address}
+message_new_login_revoke = Falls du das warst, musst du nichts tun. Andernfalls melde die Sitzung unten ab und ändere dein Passwort.
+message_new_login_link = Sitzungen verwalten
@@ -12,3 +12,37 @@ message_low_score_reminder_content_due_chores =
         [one] chore is
         *[other] chores are
     } currently due by the way:
+message_reward_redeemed_title = Reward redeemed
+message_reward_redeemed_subject = {$user_name} redeemed "{$reward_name}"
+message_reward_redeemed_content = {$user_name} has just redeemed the reward "{$reward_name}" for {$points} points in the list "{$chore_list_name}".
+message_chore_swap_request_title = Chore swap request
+message_chore_swap_request_subject = {$user_name} asks you to take over "{$chore_name}"
+message_chore_swap_request_content = {$user_name} would like you to take over the chore "{$chore_name}"{$offered_points ->
+        [0] .
+        *[other] and offers you {$offered_points} of their points in return.
+    }
+message_chore_swap_request_link = Accept or decline the request
+message_chore_handover_offer_title = Chore handover
+message_chore_handover_offer_subject = Can you take over "{$chore_name}" for {$user_name}?
+message_chore_handover_offer_content = {$user_name} is absent from {$date_start} until {$date_end} and is looking for someone to take over the chore "{$chore_name}", which is due in that period.
+message_chore_handover_offer_content_open_end = {$user_name} is absent from {$date_start} and is looking for someone to take over the chore "{$chore_name}", which is due in that period.
+message_chore_handover_offer_link = Take over the chore
+message_password_reset_title = Reset your password
+message_password_reset_content = somebody asked to reset the password of your account. The link below is valid for one hour and can only be used once.
+message_password_reset_link = Set a new password
+message_password_reset_ignore = If you didn't ask for this, you can ignore this mail and your password stays unchanged.
+message_user_invitation_title = Invitation
+message_user_invitation_subject = {$user_name} invited you to WG
+message_user_invitation_content = {$user_name} invited you to keep track of your shared chores together. Set a password to activate your account. The link is valid for seven days.
+message_user_invitation_link = Accept the invitation
+message_login_link_title = Your login link
+message_login_link_content = use the link below to log in. It is valid for 15 minutes and can only be used once.
+message_login_link_link = Log in
+message_login_link_ignore = If you didn't ask for this, you can ignore this mail.
+message_new_login_title = New login to your account
+message_new_login_content = your account has just been logged into from a device or network that hasn't been used before:
+message_new_login_time = Time: {$time}
+message_new_login_device = Device: {$device}
+message_new_login_ip_address = IP address: {$ip_address}
+message_new_login_revoke = If this was you, there is nothing to do. Otherwise log out the session below and change your password.
+message_new_login_link = Manage sessions
@@ -5,11 +5,12 @@ use wg_core::model::{self, chore::Chore, chore_list::ChoreList};
 use crate::AppState;
 
 pub async fn low_score_reminder(state: Arc<AppState>) {
-    let (all_users, active_absences, all_chore_lists, all_due_chores, low_score_users) = tokio::try_join!(
+    let (all_users, active_absences, all_chore_lists, all_due_chores, all_exclusions, low_score_users) = tokio::try_join!(
         model::user::get_all(&state.pool),
         model::absence::get_active(&state.pool),
         model::chore_list::get_all(&state.pool),
         model::chore::get_all_due(&state.pool),
+        model::chore_exclusion::get_all(&state.pool),
         wg_core::service::user::get_low_score_users(&state.pool).map(|r| Ok(r)),
     ).unwrap();
 
@@ -30,9 +31,24 @@ pub async fn low_score_reminder(state: Arc<AppState>) {
 
         let due_chores = all_due_chores.iter()
             .filter(|c| chore_list_ids.contains(&c.chore_list_id))
+            .filter(|c| !all_exclusions.iter().any(|e| e.chore_id == c.id && e.user_id == user.id))
             .collect::<Vec<&Chore>>();
 
         let mail_message = wg_mail::message::low_score_reminder(user, &chore_lists, &due_chores);
         state.mail_transport.send(mail_message).await.unwrap();
     }
 }
+
+/// Moves the due date of seasonal chores which became active again to the
+/// start of their active window
+pub async fn seasonal_chore_reactivation(state: Arc<AppState>) {
+    let chores = model::chore::get_all(&state.pool).await.unwrap();
+
+    for mut chore in chores {
+        if chore.is_deleted() || !chore.is_seasonal() {
+            continue;
+        }
+
+        wg_core::service::chore::update_next_due_date(&mut chore, &state.pool, true).await.unwrap();
+    }
+}
@@ -32,6 +32,14 @@ pub async fn start(state: AppState, cancel_token: CancellationToken) {
 
     let tracker = TaskTracker::new();
 
+    tracker.spawn(start_cron(
+        "seasonal_chore_reactivation",
+        Schedule::from_str("0 0 0 * * *").unwrap(),
+        job::seasonal_chore_reactivation,
+        state.clone(),
+        cancel_token.clone(),
+    ));
+
     if let Ok(cron) = std::env::var("LOW_SCORE_REMINDER_CRON") {
         tracker.spawn(start_cron(
             "low_score_reminder",
@@ -0,0 +1,92 @@
+use axum::{
+    body::{self, Body},
+    extract::Request,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use axum_extra::extract::{CookieJar, cookie::{Cookie, SameSite}};
+use crate::{handler::authentication::generate_token, CSRF_TOKEN};
+
+pub const COOKIE_NAME: &str = "csrf_token";
+pub const FIELD_NAME: &str = "csrf_token";
+/// Used by requests which are sent by JavaScript instead of a form
+pub const HEADER_NAME: &str = "x-csrf-token";
+
+const MAX_FORM_SIZE: usize = 2 * 1024 * 1024;
+
+/// Double-submit protection: every browser gets a random token in a cookie,
+/// which forms have to send along. Another site can make the browser send the
+/// cookie, but can't read it to put it into the form.
+pub async fn protect(request: Request, next: Next) -> Response {
+    let cookie_jar = CookieJar::from_headers(request.headers());
+    let existing_csrf_token = cookie_jar.get(COOKIE_NAME)
+        .map(|cookie| cookie.value().to_string());
+
+    let request = if request.method().is_safe() {
+        request
+    } else {
+        match verify(request, existing_csrf_token.as_deref()).await {
+            Ok(request) => request,
+            Err(status_code) => return status_code.into_response(),
+        }
+    };
+
+    let csrf_token = existing_csrf_token.clone().unwrap_or_else(generate_token);
+    let response = CSRF_TOKEN.scope(csrf_token.clone(), next.run(request)).await;
+
+    if existing_csrf_token.is_some() {
+        return response;
+    }
+
+    (cookie_jar.add(make_cookie(csrf_token)), response).into_response()
+}
+
+/// Replaces the token when a session starts or ends, so a token that was
+/// known before the login can't be used for the logged in session
+pub fn rotate(cookie_jar: CookieJar) -> CookieJar {
+    cookie_jar.add(make_cookie(generate_token()))
+}
+
+fn make_cookie(csrf_token: String) -> Cookie<'static> {
+    Cookie::build((COOKIE_NAME, csrf_token))
+        .secure(true)
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .path("/")
+        .build()
+}
+
+/// The form body has to be buffered to look for the token, so it is put back
+/// into the request afterwards for the handler
+async fn verify(request: Request, expected_csrf_token: Option<&str>) -> Result<Request, StatusCode> {
+    let Some(expected_csrf_token) = expected_csrf_token else {
+        tracing::warn!("Rejecting request without CSRF cookie");
+        return Err(StatusCode::FORBIDDEN);
+    };
+
+    if let Some(csrf_token) = request.headers().get(HEADER_NAME) {
+        if csrf_token.as_bytes() != expected_csrf_token.as_bytes() {
+            tracing::warn!("Rejecting request with invalid CSRF header");
+            return Err(StatusCode::FORBIDDEN);
+        }
+
+        return Ok(request);
+    }
+
+    let (parts, body) = request.into_parts();
+    let bytes = body::to_bytes(body, MAX_FORM_SIZE)
+        .await
+        .map_err(|_| StatusCode::PAYLOAD_TOO_LARGE)?;
+
+    let csrf_token = form_urlencoded::parse(&bytes)
+        .find(|(name, _value)| name == FIELD_NAME)
+        .map(|(_name, value)| value);
+
+    if csrf_token.as_deref() != Some(expected_csrf_token) {
+        tracing::warn!("Rejecting form with missing or invalid CSRF token");
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(Request::from_parts(parts, Body::from(bytes)))
+}
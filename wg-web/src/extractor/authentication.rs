@@ -40,7 +40,7 @@ impl FromRequestParts<Arc<AppState>> for AuthSession {
             Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::UNAUTHORIZED),
             Err(err) => panic!("{}", err),
         };
-        if auth_session.is_expired() {
+        if auth_session.is_expired() || auth_session.is_second_factor_pending {
             return Err(StatusCode::UNAUTHORIZED);
         }
 
@@ -0,0 +1,37 @@
+use std::{net::{IpAddr, SocketAddr}, sync::Arc};
+use crate::AppState;
+use axum::{
+    extract::{ConnectInfo, FromRequestParts},
+    http::{StatusCode, request::Parts},
+};
+
+/// IP address of the client. Taken from the trusted reverse proxy header if
+/// one is configured, otherwise from the connection.
+pub struct ClientIp(pub IpAddr);
+
+impl FromRequestParts<Arc<AppState>> for ClientIp {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        if let Some(header_name) = &state.trusted_proxy_header {
+            // The proxy appends the address it got the request from, so the
+            // last entry is the only one that can't be forged by the client
+            let ip = parts.headers.get(header_name)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.rsplit(',').next())
+                .and_then(|value| value.trim().parse::<IpAddr>().ok());
+
+            if let Some(ip) = ip {
+                return Ok(ClientIp(ip));
+            }
+        }
+
+        match parts.extensions.get::<ConnectInfo<SocketAddr>>() {
+            Some(ConnectInfo(address)) => Ok(ClientIp(address.ip())),
+            None => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
@@ -2,3 +2,5 @@ pub mod language;
 pub mod theme;
 pub mod authentication;
 pub mod model;
+pub mod client_ip;
+pub mod user_agent;
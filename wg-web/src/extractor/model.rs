@@ -7,6 +7,12 @@ use wg_core::model::chore_activity;
 use wg_core::model::chore_activity::ChoreActivityId;
 use wg_core::model::chore_list;
 use wg_core::model::chore_list::ChoreListId;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_swap_request::ChoreSwapRequestId;
+use wg_core::model::reward;
+use wg_core::model::reward::RewardId;
+use wg_core::model::reward_redemption;
+use wg_core::model::reward_redemption::RewardRedemptionId;
 use wg_core::model::user::UserId;
 use crate::AppState;
 use axum::extract::FromRequestParts;
@@ -162,3 +168,90 @@ impl FromRequestParts<Arc<AppState>> for User {
         Ok(User(user))
     }
 }
+
+pub struct Reward(pub reward::Reward);
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+struct RewardPathData {
+    reward_id: RewardId,
+}
+
+impl FromRequestParts<Arc<AppState>> for Reward {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let path_data = match parts.extract::<Path<RewardPathData>>().await {
+            Ok(path_data) => path_data,
+            Err(_) => return Err(StatusCode::BAD_REQUEST),
+        };
+
+        let reward = match reward::get_by_id(&state.pool, &path_data.reward_id).await {
+            Ok(reward) => reward,
+            Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+            Err(err) => panic!("{}", err),
+        };
+
+        Ok(Reward(reward))
+    }
+}
+
+pub struct RewardRedemption(pub reward_redemption::RewardRedemption);
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+struct RewardRedemptionPathData {
+    reward_redemption_id: RewardRedemptionId,
+}
+
+impl FromRequestParts<Arc<AppState>> for RewardRedemption {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let path_data = match parts.extract::<Path<RewardRedemptionPathData>>().await {
+            Ok(path_data) => path_data,
+            Err(_) => return Err(StatusCode::BAD_REQUEST),
+        };
+
+        let reward_redemption = match reward_redemption::get_by_id(&state.pool, &path_data.reward_redemption_id).await {
+            Ok(reward_redemption) => reward_redemption,
+            Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+            Err(err) => panic!("{}", err),
+        };
+
+        Ok(RewardRedemption(reward_redemption))
+    }
+}
+
+pub struct ChoreSwapRequest(pub chore_swap_request::ChoreSwapRequest);
+
+#[derive(Debug, Copy, Clone, serde::Deserialize)]
+struct ChoreSwapRequestPathData {
+    chore_swap_request_id: ChoreSwapRequestId,
+}
+
+impl FromRequestParts<Arc<AppState>> for ChoreSwapRequest {
+    type Rejection = StatusCode;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let path_data = match parts.extract::<Path<ChoreSwapRequestPathData>>().await {
+            Ok(path_data) => path_data,
+            Err(_) => return Err(StatusCode::BAD_REQUEST),
+        };
+
+        let swap_request = match chore_swap_request::get_by_id(&state.pool, &path_data.chore_swap_request_id).await {
+            Ok(swap_request) => swap_request,
+            Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+            Err(err) => panic!("{}", err),
+        };
+
+        Ok(ChoreSwapRequest(swap_request))
+    }
+}
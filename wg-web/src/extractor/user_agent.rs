@@ -0,0 +1,25 @@
+use axum::{
+    extract::FromRequestParts,
+    http::{header, request::Parts},
+};
+
+/// User agent header of the request, if it is present and valid
+pub struct UserAgent(pub Option<String>);
+
+impl <S> FromRequestParts<S> for UserAgent
+where
+    S: Send + Sync
+{
+    type Rejection = ();
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        _state: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let user_agent = parts.headers.get(header::USER_AGENT)
+            .and_then(|user_agent| user_agent.to_str().ok())
+            .map(|user_agent| user_agent.to_string());
+
+        Ok(UserAgent(user_agent))
+    }
+}
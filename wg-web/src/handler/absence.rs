@@ -1,10 +1,18 @@
 use serde_with::serde_as;
 use wg_core::model::absence;
 use wg_core::model::absence::AbsenceId;
+use wg_core::model::chore;
+use wg_core::model::chore_list;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_swap_request::{ChoreSwapRequestId, ChoreSwapRequestStatus};
+use wg_core::model::chore::ChoreId;
 use wg_core::model::user;
 use wg_core::service;
+use wg_mail::lettre::AsyncTransport;
 use crate::extractor::authentication::AuthSession;
 use crate::extractor::model::Absence;
+use crate::extractor::model::Chore;
+use crate::handler::chore::ChoreIndexPath;
 use crate::template;
 use crate::AppState;
 use wg_core::value::{Date, DateTime};
@@ -138,6 +146,15 @@ pub async fn create(
         .await
         .unwrap();
 
+    let due_chores = chore::get_all_due_in_period_for_user(&state.pool, &absence.user_id, absence.date_start, absence.get_handover_end_date())
+        .await
+        .unwrap();
+    if !due_chores.is_empty() {
+        return Ok(Redirect::to(AbsenceHandoverPath {
+            absence_id: absence.id,
+        }.to_string().as_str()));
+    }
+
     Ok(Redirect::to(&AbsenceIndexPath.to_string().as_str()))
 }
 
@@ -273,6 +290,17 @@ pub async fn delete(
         .await
         .unwrap();
 
+    // Nobody needs to take over the chores anymore
+    let swap_requests = chore_swap_request::get_all_for_absence(&state.pool, &absence.id)
+        .await
+        .unwrap();
+    for mut swap_request in swap_requests.into_iter().filter(|swap_request| swap_request.is_open()) {
+        swap_request.status = ChoreSwapRequestStatus::Cancelled;
+        swap_request.date_updated = DateTime::now();
+
+        chore_swap_request::update(&state.pool, &swap_request).await.unwrap();
+    }
+
     Ok(Redirect::to(&AbsenceDetailPath { absence_id: absence.id }.to_string().as_str()))
 }
 
@@ -312,3 +340,118 @@ pub async fn restore(
 
     Ok(Redirect::to(&AbsenceDetailPath { absence_id: absence.id }.to_string().as_str()))
 }
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/absences/{absence_id}/handover")]
+pub struct AbsenceHandoverPath {
+    pub absence_id: AbsenceId,
+}
+
+pub async fn view_handover(
+    _path: AbsenceHandoverPath,
+    Absence(absence): Absence,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if absence.is_deleted() || absence.is_in_past() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    if absence.user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let (due_chores, chore_lists, swap_requests) = tokio::try_join!(
+        chore::get_all_due_in_period_for_user(&state.pool, &absence.user_id, absence.date_start, absence.get_handover_end_date()),
+        chore_list::get_all(&state.pool),
+        chore_swap_request::get_all_for_absence(&state.pool, &absence.id),
+    ).unwrap();
+
+    Ok(template::page::absence::handover(
+        absence,
+        due_chores,
+        chore_lists,
+        swap_requests,
+    ))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/absences/{absence_id}/handover/{chore_id}")]
+pub struct AbsenceHandoverOfferPath {
+    pub absence_id: AbsenceId,
+    pub chore_id: ChoreId,
+}
+
+pub async fn offer_handover(
+    _path: AbsenceHandoverOfferPath,
+    Absence(absence): Absence,
+    Chore(chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if absence.is_deleted() || absence.is_in_past() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    if absence.user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let (users, active_absences, due_chores) = tokio::try_join!(
+        user::get_all(&state.pool),
+        absence::get_active(&state.pool),
+        chore::get_all_due_in_period_for_user(&state.pool, &absence.user_id, absence.date_start, absence.get_handover_end_date()),
+    ).unwrap();
+
+    if !due_chores.iter().any(|due_chore| due_chore.id == chore.id) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let can_be_offered = service::chore_swap_request::can_be_offered(&state.pool, &chore)
+        .await
+        .unwrap();
+    if !can_be_offered {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let swap_request = chore_swap_request::ChoreSwapRequest {
+        id: ChoreSwapRequestId::new(),
+        chore_id: chore.id,
+        requesting_user_id: auth_session.user_id,
+        target_user_id: None,
+        absence_id: Some(absence.id),
+        offered_points: 0,
+        comment: None,
+        status: ChoreSwapRequestStatus::Pending,
+        chore_activity_id: None,
+        date_created: DateTime::now(),
+        date_updated: DateTime::now(),
+    };
+
+    chore_swap_request::create(&state.pool, &swap_request).await.unwrap();
+
+    let absent_user = users.iter()
+        .find(|user| user.id == auth_session.user_id)
+        .unwrap();
+    let url = format!("{}{}", state.base_url, ChoreIndexPath { chore_list_id: chore.chore_list_id });
+
+    for user in users.iter() {
+        if user.is_deleted() || !user.is_scoring() || user.id == absent_user.id {
+            continue;
+        }
+
+        let is_user_absent = active_absences.iter().any(|absence| absence.user_id == user.id);
+        if is_user_absent {
+            continue;
+        }
+
+        let mail_message = wg_mail::message::chore_handover_offer(user, absent_user, &chore, &absence, &url);
+        if let Err(err) = state.mail_transport.send(mail_message).await {
+            tracing::error!(user_id = ?user.id, error = %err, "Failed to send chore handover notification");
+        }
+    }
+
+    Ok(Redirect::to(AbsenceHandoverPath {
+        absence_id: absence.id,
+    }.to_string().as_str()))
+}
@@ -1,22 +1,44 @@
-use std::sync::Arc;
+use std::{net::IpAddr, sync::Arc};
 
 use wg_core::model::authentication_session::AuthenticationSessionId;
+use crate::csrf;
 use crate::extractor::authentication::{AuthSession, COOKIE_NAME};
+use crate::extractor::client_ip::ClientIp;
+use crate::extractor::user_agent::UserAgent;
+use crate::handler::authentication_session::AuthenticationSessionIndexPath;
 use crate::template;
 use wg_core::model::user;
 use wg_core::value::DateTime;
 use crate::AppState;
 use wg_core::model::{authentication_session::{self, AuthenticationSession}};
+use wg_core::model::login_attempt::{self, LoginAttempt, LoginAttemptId};
+use wg_core::model::login_token::{self, LoginToken, LoginTokenId};
+use wg_core::model::passkey;
+use wg_core::model::password_reset_token::{self, PasswordResetToken, PasswordResetTokenId};
+use wg_core::service;
+use wg_core::service::passkey::{AuthenticationResponse, RelyingParty};
+use wg_core::value::{PasswordHash, TokenHash};
 use axum::{
     Form,
+    Json,
     extract::State,
     http::StatusCode,
     response::{IntoResponse, Redirect},
 };
 use axum_extra::extract::{CookieJar, cookie::Cookie};
 use axum_extra::routing::TypedPath;
-use chrono::Days;
-use secrecy::SecretString;
+use chrono::{Days, TimeDelta};
+use maud::Markup;
+use secrecy::{ExposeSecret, SecretString};
+use wg_mail::lettre::AsyncTransport;
+
+/// Sessions expire after not being used for this long
+const SESSION_LIFETIME: Days = Days::new(30);
+/// Login attempts are kept this long for the login history
+const LOGIN_HISTORY_RETENTION: Days = Days::new(90);
+/// Minimum time between two mails with a login or password reset link for
+/// the same account
+const TOKEN_MAIL_COOLDOWN: TimeDelta = TimeDelta::minutes(1);
 
 #[derive(TypedPath, serde::Deserialize)]
 #[typed_path("/login")]
@@ -24,13 +46,14 @@ pub struct LoginPath;
 
 pub async fn view_login_form(
     _path: LoginPath,
+    State(state): State<Arc<AppState>>,
     auth_session: Option<AuthSession>,
 ) -> impl IntoResponse {
     if auth_session.is_some() {
         return Redirect::to("/").into_response();
     }
 
-    template::page::authentication::login().into_response()
+    template::page::authentication::login(state.is_magic_link_login_enabled).into_response()
 }
 
 #[derive(serde::Deserialize, Debug)]
@@ -39,36 +62,356 @@ pub struct LoginPayload {
     password: SecretString,
 }
 
+/// Repeated failures lock out the IP address and the account for a while,
+/// which also spares the server from verifying every guessed password
 pub async fn login(
     _path: LoginPath,
     State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
+    UserAgent(user_agent): UserAgent,
     cookie_jar: CookieJar,
     Form(payload): Form<LoginPayload>,
 ) -> Result<(CookieJar, Redirect), StatusCode> {
+    if state.login_throttle.get_lockout(client_ip, &payload.email).is_some() {
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+
     let user = match user::get_by_email(&state.pool, &payload.email).await {
-        Ok(user) => user,
+        Ok(user) => Some(user),
+        Err(wg_core::db::sqlx::Error::RowNotFound) => None,
+        Err(err) => panic!("{}", err),
+    };
+    let user = match user.filter(|user| !user.is_deleted()) {
+        Some(user) => user,
+        None => {
+            state.login_throttle.record_failure(client_ip, &payload.email);
+            return Err(StatusCode::UNAUTHORIZED);
+        },
+    };
+
+    let is_matching_password = match &user.password_hash {
+        Some(password_hash) => password_hash.verify(payload.password),
+        None => false,
+    };
+    if !is_matching_password {
+        state.login_throttle.record_failure(client_ip, &payload.email);
+        record_login_attempt(&state, &user, false, client_ip, user_agent).await;
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    // With two-factor authentication the failed attempts are only reset once
+    // the second factor has been verified, so that codes can't be guessed
+    // indefinitely by logging in again
+    let is_second_factor_pending = user.has_two_factor_authentication();
+    if !is_second_factor_pending {
+        state.login_throttle.record_success(&payload.email);
+    }
+
+    Ok(start_session(&state, cookie_jar, user, is_second_factor_pending, client_ip, user_agent).await)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/login/link")]
+pub struct LoginLinkRequestPath;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct LoginLinkRequestPayload {
+    email: String,
+}
+
+/// Responds the same whether or not a user with the email address exists, so
+/// that the form can't be used to find out who has an account
+pub async fn request_login_link(
+    _path: LoginLinkRequestPath,
+    State(state): State<Arc<AppState>>,
+    Form(payload): Form<LoginLinkRequestPayload>,
+) -> Result<Markup, StatusCode> {
+    if !state.is_magic_link_login_enabled {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let user = match user::get_by_email(&state.pool, &payload.email).await {
+        Ok(user) => Some(user),
+        Err(wg_core::db::sqlx::Error::RowNotFound) => None,
+        Err(err) => panic!("{}", err),
+    };
+
+    if let Some(user) = user.filter(|user| !user.is_deleted()) {
+        // Sending the mail takes a while, which would give away that the
+        // account exists
+        tokio::spawn(async move {
+            match login_token::get_latest_for_user(&state.pool, &user.id).await {
+                Ok(latest_login_token) if !is_token_mail_cooldown_over(&latest_login_token.date_created) => {
+                    tracing::info!(user_id = ?user.id, "Not sending another login link yet");
+                    return;
+                },
+                Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => {},
+                Err(err) => panic!("{}", err),
+            }
+
+            let token = generate_token();
+            let login_token = LoginToken {
+                id: LoginTokenId::new(),
+                token_hash: TokenHash::from_token(&token),
+                user_id: user.id,
+                date_expires: DateTime::from(*DateTime::now().as_ref() + TimeDelta::minutes(15)),
+                date_created: DateTime::now(),
+            };
+
+            login_token::create(&state.pool, &login_token)
+                .await
+                .unwrap();
+
+            login_token::delete_all_expired(&state.pool)
+                .await
+                .unwrap();
+
+            let url = format!("{}{}", state.base_url, LoginLinkPath { token });
+            let mail_message = wg_mail::message::login_link(&user, &url);
+            if let Err(err) = state.mail_transport.send(mail_message).await {
+                tracing::error!(user_id = ?user.id, error = %err, "Failed to send login link");
+            }
+        });
+    }
+
+    Ok(template::page::authentication::login_link_requested())
+}
+
+/// Opening the link only shows a confirmation, so that mail clients which
+/// prefetch links don't use up the token
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/login/link/{token}")]
+pub struct LoginLinkPath {
+    pub token: String,
+}
+
+pub async fn view_login_link_form(
+    _path: LoginLinkPath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Markup, StatusCode> {
+    if !state.is_magic_link_login_enabled {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(template::page::authentication::login_link())
+}
+
+pub async fn login_with_link(
+    path: LoginLinkPath,
+    State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    cookie_jar: CookieJar,
+) -> Result<(CookieJar, Redirect), StatusCode> {
+    if !state.is_magic_link_login_enabled {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let login_token = match login_token::consume_by_token(&state.pool, &path.token).await {
+        Ok(login_token) => login_token,
         Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::UNAUTHORIZED),
         Err(err) => panic!("{}", err),
     };
+
+    let user = user::get_by_id(&state.pool, &login_token.user_id)
+        .await
+        .unwrap();
+
+    if login_token.is_expired() {
+        record_login_attempt(&state, &user, false, client_ip, user_agent).await;
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
     if user.is_deleted() {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let is_matching_password = user.password_hash.verify(payload.password);
-    if !is_matching_password {
+    let is_second_factor_pending = user.has_two_factor_authentication();
+
+    Ok(start_session(&state, cookie_jar, user, is_second_factor_pending, client_ip, user_agent).await)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/login/passkey/challenge")]
+pub struct LoginPasskeyChallengePath;
+
+#[derive(serde::Serialize)]
+pub struct PasskeyChallengeResponse {
+    challenge: String,
+    rp_id: String,
+}
+
+pub async fn create_passkey_challenge(
+    _path: LoginPasskeyChallengePath,
+    State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
+) -> Result<Json<PasskeyChallengeResponse>, StatusCode> {
+    let Some(passkey_challenge) = service::passkey::create_login_challenge(&state.pool, &client_ip.to_string()).await else {
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    };
+    let relying_party = RelyingParty::from_base_url(&state.base_url);
+
+    Ok(Json(PasskeyChallengeResponse {
+        challenge: passkey_challenge.challenge,
+        rp_id: relying_party.id,
+    }))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/login/passkey")]
+pub struct LoginPasskeyPath;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct LoginPasskeyPayload {
+    credential_id: String,
+    client_data_json: String,
+    authenticator_data: String,
+    signature: String,
+}
+
+/// Passkeys require user verification by the authenticator, so they count as
+/// both factors
+pub async fn login_with_passkey(
+    _path: LoginPasskeyPath,
+    State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    cookie_jar: CookieJar,
+    Form(payload): Form<LoginPasskeyPayload>,
+) -> Result<(CookieJar, Redirect), StatusCode> {
+    let response = AuthenticationResponse {
+        credential_id: payload.credential_id,
+        client_data_json: payload.client_data_json,
+        authenticator_data: payload.authenticator_data,
+        signature: payload.signature,
+    };
+    let relying_party = RelyingParty::from_base_url(&state.base_url);
+
+    let passkey = match service::passkey::authenticate(&state.pool, &relying_party, &response).await {
+        Some(passkey) => passkey,
+        None => {
+            // Unknown passkeys can't be attributed to any user
+            if let Ok(passkey) = passkey::get_by_credential_id(&state.pool, &response.credential_id).await {
+                let user = user::get_by_id(&state.pool, &passkey.user_id)
+                    .await
+                    .unwrap();
+                record_login_attempt(&state, &user, false, client_ip, user_agent).await;
+            }
+
+            return Err(StatusCode::UNAUTHORIZED);
+        },
+    };
+
+    let user = user::get_by_id(&state.pool, &passkey.user_id)
+        .await
+        .unwrap();
+    if user.is_deleted() {
         return Err(StatusCode::UNAUTHORIZED);
     }
 
-    let mut token_buf = [0u8; 64];
-    getrandom::getrandom(&mut token_buf).unwrap();
-    let token = const_hex::encode(token_buf);
+    Ok(start_session(&state, cookie_jar, user, false, client_ip, user_agent).await)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/login/two-factor")]
+pub struct LoginSecondFactorPath;
+
+pub async fn view_second_factor_form(
+    _path: LoginSecondFactorPath,
+    State(state): State<Arc<AppState>>,
+    cookie_jar: CookieJar,
+) -> impl IntoResponse {
+    if get_pending_auth_session(&state, &cookie_jar).await.is_none() {
+        return Redirect::to(LoginPath.to_string().as_str()).into_response();
+    }
+
+    template::page::authentication::second_factor().into_response()
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SecondFactorPayload {
+    code: String,
+}
+
+/// A wrong code ends the pending session, so that the password has to be
+/// entered again before the next attempt
+pub async fn verify_second_factor(
+    _path: LoginSecondFactorPath,
+    State(state): State<Arc<AppState>>,
+    ClientIp(client_ip): ClientIp,
+    UserAgent(user_agent): UserAgent,
+    cookie_jar: CookieJar,
+    Form(payload): Form<SecondFactorPayload>,
+) -> Result<(CookieJar, Redirect), StatusCode> {
+    let mut auth_session = match get_pending_auth_session(&state, &cookie_jar).await {
+        Some(auth_session) => auth_session,
+        None => return Err(StatusCode::UNAUTHORIZED),
+    };
+
+    let user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    let email = user.email.as_deref().unwrap_or_default();
+    if state.login_throttle.get_lockout(client_ip, email).is_some() {
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    if !service::user::verify_second_factor(&state.pool, &user, &payload.code).await {
+        authentication_session::delete(&state.pool, &auth_session)
+            .await
+            .unwrap();
+
+        state.login_throttle.record_failure(client_ip, email);
+        record_login_attempt(&state, &user, false, client_ip, user_agent).await;
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+
+    state.login_throttle.record_success(email);
+
+    auth_session.is_second_factor_pending = false;
+    auth_session.user_agent = user_agent.clone();
+    auth_session.ip_address = Some(client_ip.to_string());
+    auth_session.date_last_used = DateTime::now();
+    auth_session.date_expires = DateTime::from(*DateTime::now().as_ref() + SESSION_LIFETIME);
+
+    authentication_session::update(&state.pool, &auth_session)
+        .await
+        .unwrap();
+
+    record_login_attempt(&state, &user, true, client_ip, user_agent).await;
+
+    let cookie_jar = csrf::rotate(cookie_jar.add(make_session_cookie(&auth_session)));
+
+    Ok((cookie_jar, Redirect::to("/")))
+}
+
+/// A pending session is short-lived and only becomes usable once the user
+/// has entered their second factor
+async fn start_session(
+    state: &Arc<AppState>,
+    cookie_jar: CookieJar,
+    user: user::User,
+    is_second_factor_pending: bool,
+    client_ip: IpAddr,
+    user_agent: Option<String>,
+) -> (CookieJar, Redirect) {
+    let date_expires = if is_second_factor_pending {
+        DateTime::from(*DateTime::now().as_ref() + TimeDelta::minutes(10))
+    } else {
+        DateTime::from(*DateTime::now().as_ref() + SESSION_LIFETIME)
+    };
 
     let auth_session = AuthenticationSession {
         id: AuthenticationSessionId::new(),
-        token,
+        token: generate_token(),
         user_id: user.id,
         last_used_language: user.last_used_language,
-        date_expires: DateTime::from(DateTime::now().as_ref().clone() + Days::new(30)),
+        is_second_factor_pending,
+        user_agent: user_agent.clone(),
+        ip_address: Some(client_ip.to_string()),
+        date_last_used: DateTime::now(),
+        date_expires,
         date_created: DateTime::now(),
     };
 
@@ -80,7 +423,121 @@ pub async fn login(
         .await
         .unwrap();
 
-    let cookie = Cookie::build((COOKIE_NAME, auth_session.token.clone()))
+    // With two-factor authentication the login only counts once the second
+    // factor has been verified
+    if !is_second_factor_pending {
+        record_login_attempt(state, &user, true, client_ip, user_agent).await;
+    }
+
+    let cookie_jar = csrf::rotate(cookie_jar.add(make_session_cookie(&auth_session)));
+
+    if is_second_factor_pending {
+        (cookie_jar, Redirect::to(LoginSecondFactorPath.to_string().as_str()))
+    } else {
+        (cookie_jar, Redirect::to("/"))
+    }
+}
+
+/// Successful logins from a user agent or IP address that the account hasn't
+/// been logged into from before are notified by mail, so that the user can
+/// log out the session if it wasn't them
+async fn record_login_attempt(
+    state: &Arc<AppState>,
+    user: &user::User,
+    is_successful: bool,
+    client_ip: IpAddr,
+    user_agent: Option<String>,
+) {
+    let login_attempt = LoginAttempt {
+        id: LoginAttemptId::new(),
+        user_id: user.id,
+        is_successful,
+        user_agent,
+        ip_address: client_ip.to_string(),
+        date_created: DateTime::now(),
+    };
+
+    let mut is_from_new_device = false;
+    if is_successful {
+        let previous_login_attempts = login_attempt::get_all_successful_for_user(&state.pool, &user.id)
+            .await
+            .unwrap();
+
+        // The very first login of an account is expected to be from a new device
+        is_from_new_device = !previous_login_attempts.is_empty() && (
+            !previous_login_attempts.iter().any(|previous_login_attempt| previous_login_attempt.user_agent == login_attempt.user_agent)
+            || !previous_login_attempts.iter().any(|previous_login_attempt| previous_login_attempt.ip_address == login_attempt.ip_address)
+        );
+    }
+
+    login_attempt::create(&state.pool, &login_attempt)
+        .await
+        .unwrap();
+
+    login_attempt::delete_all_older_than(&state.pool, DateTime::from(*DateTime::now().as_ref() - LOGIN_HISTORY_RETENTION))
+        .await
+        .unwrap();
+
+    if !is_from_new_device {
+        return;
+    }
+
+    let url = format!("{}{}", state.base_url, AuthenticationSessionIndexPath);
+    let mail_message = wg_mail::message::new_login(user, &login_attempt, &url);
+
+    // A slow mail server must not hold up the login
+    let state = state.clone();
+    let user_id = user.id;
+    tokio::spawn(async move {
+        if let Err(err) = state.mail_transport.send(mail_message).await {
+            tracing::error!(user_id = ?user_id, error = %err, "Failed to send new login mail");
+        }
+    });
+}
+
+async fn get_pending_auth_session(
+    state: &AppState,
+    cookie_jar: &CookieJar,
+) -> Option<AuthenticationSession> {
+    let auth_token = cookie_jar.get(COOKIE_NAME)?.value();
+
+    match authentication_session::get_by_token(&state.pool, auth_token).await {
+        Ok(auth_session) if auth_session.is_second_factor_pending && !auth_session.is_expired() => Some(auth_session),
+        Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => None,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Moves the expiry of the session forward and records the client it is
+/// used from. To spare a write on every request, this only happens once in a
+/// while or when the client changes. Returns whether the session was updated.
+pub(crate) async fn renew_session(
+    state: &AppState,
+    auth_session: &mut AuthenticationSession,
+    client_ip: IpAddr,
+    user_agent: Option<String>,
+) -> bool {
+    let ip_address = Some(client_ip.to_string());
+    let is_recently_used = *auth_session.date_last_used.as_ref() + TimeDelta::minutes(5) > *DateTime::now().as_ref();
+
+    if is_recently_used && auth_session.ip_address == ip_address && auth_session.user_agent == user_agent {
+        return false;
+    }
+
+    auth_session.user_agent = user_agent;
+    auth_session.ip_address = ip_address;
+    auth_session.date_last_used = DateTime::now();
+    auth_session.date_expires = DateTime::from(*DateTime::now().as_ref() + SESSION_LIFETIME);
+
+    authentication_session::update(&state.pool, auth_session)
+        .await
+        .unwrap();
+
+    true
+}
+
+pub(crate) fn make_session_cookie(auth_session: &AuthenticationSession) -> Cookie<'static> {
+    Cookie::build((COOKIE_NAME, auth_session.token.clone()))
         .secure(true)
         .http_only(true)
         .same_site(axum_extra::extract::cookie::SameSite::Lax)
@@ -91,10 +548,7 @@ pub async fn login(
             )
             .unwrap(),
         )
-        .build();
-    let cookie_jar = cookie_jar.add(cookie);
-
-    Ok((cookie_jar, Redirect::to("/")))
+        .build()
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -117,5 +571,159 @@ pub async fn logout(
         cookie_jar = cookie_jar.remove(COOKIE_NAME);
     }
 
-    (cookie_jar, Redirect::to(LoginPath.to_string().as_str()))
+    (csrf::rotate(cookie_jar), Redirect::to(LoginPath.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/forgot-password")]
+pub struct ForgotPasswordPath;
+
+pub async fn view_forgot_password_form(
+    _path: ForgotPasswordPath,
+    auth_session: Option<AuthSession>,
+) -> impl IntoResponse {
+    if auth_session.is_some() {
+        return Redirect::to("/").into_response();
+    }
+
+    template::page::authentication::forgot_password().into_response()
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ForgotPasswordPayload {
+    email: String,
+}
+
+/// Responds the same whether or not a user with the email address exists, so
+/// that the form can't be used to find out who has an account
+pub async fn request_password_reset(
+    _path: ForgotPasswordPath,
+    State(state): State<Arc<AppState>>,
+    Form(payload): Form<ForgotPasswordPayload>,
+) -> Markup {
+    let user = match user::get_by_email(&state.pool, &payload.email).await {
+        Ok(user) => Some(user),
+        Err(wg_core::db::sqlx::Error::RowNotFound) => None,
+        Err(err) => panic!("{}", err),
+    };
+
+    if let Some(user) = user.filter(|user| !user.is_deleted() && user.password_hash.is_some()) {
+        // Sending the mail takes a while, which would give away that the
+        // account exists
+        tokio::spawn(async move {
+            match password_reset_token::get_latest_for_user(&state.pool, &user.id).await {
+                Ok(latest_reset_token) if !is_token_mail_cooldown_over(&latest_reset_token.date_created) => {
+                    tracing::info!(user_id = ?user.id, "Not sending another password reset mail yet");
+                    return;
+                },
+                Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => {},
+                Err(err) => panic!("{}", err),
+            }
+
+            let token = generate_token();
+            let reset_token = PasswordResetToken {
+                id: PasswordResetTokenId::new(),
+                token_hash: TokenHash::from_token(&token),
+                user_id: user.id,
+                date_expires: DateTime::from(*DateTime::now().as_ref() + TimeDelta::hours(1)),
+                date_created: DateTime::now(),
+            };
+
+            password_reset_token::create(&state.pool, &reset_token)
+                .await
+                .unwrap();
+
+            password_reset_token::delete_all_expired(&state.pool)
+                .await
+                .unwrap();
+
+            let url = format!("{}{}", state.base_url, ResetPasswordPath { token });
+            let mail_message = wg_mail::message::password_reset(&user, &url);
+            if let Err(err) = state.mail_transport.send(mail_message).await {
+                tracing::error!(user_id = ?user.id, error = %err, "Failed to send password reset mail");
+            }
+        });
+    }
+
+    template::page::authentication::password_reset_requested()
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/reset-password/{token}")]
+pub struct ResetPasswordPath {
+    pub token: String,
+}
+
+pub async fn view_reset_password_form(
+    path: ResetPasswordPath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Markup, StatusCode> {
+    get_valid_reset_token(&state, &path.token).await?;
+
+    Ok(template::page::authentication::reset_password())
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct ResetPasswordPayload {
+    password: SecretString,
+}
+
+/// Also logs the user out everywhere, in case somebody else got hold of the
+/// old password
+pub async fn reset_password(
+    path: ResetPasswordPath,
+    State(state): State<Arc<AppState>>,
+    Form(payload): Form<ResetPasswordPayload>,
+) -> Result<Redirect, StatusCode> {
+    if payload.password.expose_secret().trim().chars().count() < PasswordHash::MIN_LENGTH {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let reset_token = match password_reset_token::consume_by_token(&state.pool, &path.token).await {
+        Ok(reset_token) if !reset_token.is_expired() => reset_token,
+        Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    };
+
+    let mut user = user::get_by_id(&state.pool, &reset_token.user_id)
+        .await
+        .unwrap();
+    if user.is_deleted() {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    user.password_hash = Some(PasswordHash::from_plain_password(payload.password));
+
+    user::update(&state.pool, &user).await.unwrap();
+
+    password_reset_token::delete_all_for_user(&state.pool, &user.id)
+        .await
+        .unwrap();
+
+    authentication_session::delete_all_for_user(&state.pool, &user.id)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(LoginPath.to_string().as_str()))
+}
+
+async fn get_valid_reset_token(
+    state: &AppState,
+    token: &str,
+) -> Result<PasswordResetToken, StatusCode> {
+    match password_reset_token::get_by_token(&state.pool, token).await {
+        Ok(reset_token) if !reset_token.is_expired() => Ok(reset_token),
+        Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn is_token_mail_cooldown_over(date_last_token_created: &DateTime) -> bool {
+    *date_last_token_created.as_ref() + TOKEN_MAIL_COOLDOWN < *DateTime::now().as_ref()
+}
+
+pub(crate) fn generate_token() -> String {
+    let mut token_buf = [0u8; 64];
+    getrandom::getrandom(&mut token_buf).unwrap();
+    const_hex::encode(token_buf)
 }
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use wg_core::model::authentication_session::{self, AuthenticationSession, AuthenticationSessionId};
+use crate::extractor::authentication::AuthSession;
+use crate::template;
+use crate::AppState;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/sessions")]
+pub struct AuthenticationSessionIndexPath;
+
+pub async fn view_list(
+    _path: AuthenticationSessionIndexPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Markup {
+    let mut auth_sessions = authentication_session::get_all_for_user(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|other_auth_session| !other_auth_session.is_expired() && !other_auth_session.is_second_factor_pending)
+        .collect::<Vec<AuthenticationSession>>();
+
+    auth_sessions.sort_by_key(|other_auth_session| std::cmp::Reverse(other_auth_session.date_last_used));
+
+    template::page::authentication_session::list(auth_session, auth_sessions)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/sessions/{authentication_session_id}/revoke")]
+pub struct AuthenticationSessionRevokePath {
+    pub authentication_session_id: AuthenticationSessionId,
+}
+
+pub async fn revoke(
+    path: AuthenticationSessionRevokePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    let revoked_auth_session = match authentication_session::get_by_id(&state.pool, &path.authentication_session_id).await {
+        Ok(revoked_auth_session) => revoked_auth_session,
+        Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    };
+
+    if revoked_auth_session.user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    authentication_session::delete(&state.pool, &revoked_auth_session)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(AuthenticationSessionIndexPath.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/sessions/revoke-others")]
+pub struct AuthenticationSessionRevokeOthersPath;
+
+pub async fn revoke_others(
+    _path: AuthenticationSessionRevokeOthersPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Redirect {
+    authentication_session::delete_all_for_user_except(&state.pool, &auth_session.user_id, &auth_session.id)
+        .await
+        .unwrap();
+
+    Redirect::to(AuthenticationSessionIndexPath.to_string().as_str())
+}
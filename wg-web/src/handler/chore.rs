@@ -1,23 +1,32 @@
 use wg_core::model::chore;
 use wg_core::model::chore::ChoreId;
+use wg_core::model::chore::PointsVotingMethod;
 use wg_core::model::chore_activity;
+use wg_core::model::chore_checklist_item;
+use wg_core::model::chore_exclusion;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_vote;
+use wg_core::model::chore_vote::ChoreVoteId;
 use wg_core::model::chore_list::ChoreListId;
+use wg_core::model::chore_postponement;
+use wg_core::model::chore_postponement::ChorePostponementKind;
 use wg_core::model::user;
+use wg_core::model::user::UserId;
 use wg_core::service;
 use crate::extractor::authentication::AuthSession;
 use crate::extractor::model::Chore;
 use crate::extractor::model::ChoreList;
 use crate::template;
 use crate::AppState;
-use wg_core::value::{Date, DateTime};
+use wg_core::value::{Date, DateTime, Time};
 use axum::{
-    Form,
     extract::State,
     http::StatusCode,
     response::Redirect,
 };
+use axum_extra::extract::Form;
 use axum_extra::routing::TypedPath;
-use chrono::Days;
+use chrono::{Days, DurationRound, TimeDelta};
 use maud::Markup;
 use serde_with::serde_as;
 use std::sync::Arc;
@@ -32,15 +41,30 @@ pub async fn view_list(
     _path: ChoreIndexPath,
     ChoreList(chore_list): ChoreList,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
-    let (chores, deleted_chores) = chore::get_all_for_chore_list(&state.pool, &chore_list.id)
-        .await
-        .unwrap()
+    let (all_chores, handover_offers, users) = tokio::try_join!(
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_swap_request::get_all_pending_handover_offers_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+    ).unwrap();
+
+    let (chores, deleted_chores): (Vec<_>, Vec<_>) = all_chores
         .into_iter()
         .partition(|chore| !chore.is_deleted());
+    let (chores, done_tasks) = chores
+        .into_iter()
+        .partition(|chore| !chore.is_completed());
 
-    Ok(template::page::chore_list::chore::list(chore_list, chores, deleted_chores))
+    Ok(template::page::chore_list::chore::list(
+        chore_list,
+        chores,
+        done_tasks,
+        deleted_chores,
+        handover_offers,
+        users,
+        auth_session,
+    ))
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -54,13 +78,36 @@ pub async fn view_detail(
     _path: ChoreDetailPath,
     ChoreList(chore_list): ChoreList,
     Chore(chore): Chore,
-    AuthSession(_auth_session): AuthSession,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
     if chore.chore_list_id != chore_list.id {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    Ok(template::page::chore_list::chore::detail(chore, chore_list))
+    let (users, votes, checklist_items, chores, exclusions) = tokio::try_join!(
+        user::get_all(&state.pool),
+        chore_vote::get_all_for_chore(&state.pool, &chore.id),
+        chore_checklist_item::get_all_for_chore(&state.pool, &chore.id),
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_exclusion::get_all_for_chore(&state.pool, &chore.id),
+    ).unwrap();
+
+    let users = users
+        .into_iter()
+        .filter(|user| !user.is_deleted() && user.is_scoring())
+        .collect();
+
+    Ok(template::page::chore_list::chore::detail(
+        chore,
+        chore_list,
+        votes,
+        checklist_items,
+        chores,
+        exclusions,
+        users,
+        auth_session,
+    ))
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -72,13 +119,24 @@ pub struct ChoreCreatePath {
 pub async fn view_create_form(
     _path: ChoreCreatePath,
     ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
     AuthSession(_auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
     if chore_list.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
 
-    Ok(template::page::chore_list::chore::create(chore_list))
+    let (chores, users) = tokio::try_join!(
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+    ).unwrap();
+
+    let users = users
+        .into_iter()
+        .filter(|user| !user.is_deleted() && user.is_scoring())
+        .collect();
+
+    Ok(template::page::chore_list::chore::create(chore_list, chores, users))
 }
 
 #[serde_as]
@@ -87,7 +145,33 @@ pub struct CreatePayload {
     name: String,
     points: u32,
     #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    points_voting_method: Option<PointsVotingMethod>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
     interval_days: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    interval_hours: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    cooldown_days: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_points_per_day: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_max_points: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    max_quantity: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    follow_up_of_chore_id: Option<ChoreId>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    follow_up_delay_days: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    active_from_month: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    active_until_month: Option<u32>,
+    /// One checklist item per line
+    checklist: String,
+    #[serde(default)]
+    award_partial_checklist_points: bool,
+    #[serde(default)]
+    excluded_user_ids: Vec<UserId>,
     description: String,
 }
 
@@ -102,17 +186,57 @@ pub async fn create(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let next_due_date = payload.interval_days.map(|interval_days| {
-        Date::from(Date::now().as_ref().clone() + Days::new(interval_days.into()))
-    });
+    if let Some(follow_up_of_chore_id) = payload.follow_up_of_chore_id
+        && !is_valid_follow_up_parent(&state.pool, &chore_list.id, &follow_up_of_chore_id, None).await
+    {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
 
-    let chore = chore::Chore {
+    if !is_valid_active_window(payload.active_from_month, payload.active_until_month) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let excluded_user_ids = validate_excluded_user_ids(&state.pool, &payload.excluded_user_ids)
+        .await
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    if payload.interval_days.is_some() && payload.interval_hours.is_some() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let (next_due_date, next_due_time) = match (payload.interval_hours, payload.interval_days) {
+        (Some(interval_hours), _) => {
+            let due_at = chrono::Utc::now().naive_utc().duration_trunc(TimeDelta::minutes(1)).unwrap()
+                + TimeDelta::hours(interval_hours.into());
+            (Some(Date::from(due_at)), Some(Time::from(due_at)))
+        }
+        (None, Some(interval_days)) => {
+            (Some(Date::from(*Date::now().as_ref() + Days::new(interval_days.into()))), None)
+        }
+        (None, None) => (None, None),
+    };
+
+    let mut chore = chore::Chore {
         id: ChoreId::new(),
         chore_list_id: chore_list.id,
         name: payload.name,
         points: payload.points,
+        points_voting_method: payload.points_voting_method,
         interval_days: payload.interval_days,
+        interval_hours: payload.interval_hours,
+        cooldown_days: payload.cooldown_days,
+        bounty_points_per_day: payload.bounty_points_per_day,
+        bounty_max_points: payload.bounty_max_points,
+        max_quantity: payload.max_quantity,
+        award_partial_checklist_points: payload.award_partial_checklist_points,
+        follow_up_of_chore_id: payload.follow_up_of_chore_id,
+        follow_up_delay_days: payload.follow_up_delay_days,
+        active_from_month: payload.active_from_month,
+        active_until_month: payload.active_until_month,
         next_due_date,
+        next_due_time,
+        is_one_off: false,
+        date_completed: None,
         description: match payload.description.trim() {
             "" => None,
             description => Some(description.to_string()),
@@ -121,14 +245,197 @@ pub async fn create(
         date_deleted: None,
     };
 
+    if chore.follow_up_of_chore_id.is_some() {
+        service::chore::update_next_due_date(&mut chore, &state.pool, false)
+            .await
+            .unwrap();
+    }
+
     chore::create(&state.pool, &chore).await.unwrap();
 
+    service::chore::update_checklist(&chore, &state.pool, &parse_checklist(&payload.checklist))
+        .await
+        .unwrap();
+
+    service::chore::update_exclusions(&chore, &state.pool, &excluded_user_ids)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(ChoreDetailPath {
         chore_list_id: chore_list.id,
         chore_id: chore.id,
     }.to_string().as_str()))
 }
 
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/chores/create-task")]
+pub struct ChoreCreateTaskPath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_create_task_form(
+    _path: ChoreCreateTaskPath,
+    ChoreList(chore_list): ChoreList,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if chore_list.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(template::page::chore_list::chore::create_task(chore_list))
+}
+
+#[serde_as]
+#[derive(serde::Deserialize, Debug)]
+pub struct CreateTaskPayload {
+    name: String,
+    points: u32,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    due_date: Option<Date>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_points_per_day: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_max_points: Option<u32>,
+    description: String,
+}
+
+pub async fn create_task(
+    _path: ChoreCreateTaskPath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(_auth_session): AuthSession,
+    Form(payload): Form<CreateTaskPayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let chore = chore::Chore {
+        id: ChoreId::new(),
+        chore_list_id: chore_list.id,
+        name: payload.name,
+        points: payload.points,
+        points_voting_method: None,
+        interval_days: None,
+        interval_hours: None,
+        cooldown_days: None,
+        bounty_points_per_day: payload.bounty_points_per_day,
+        bounty_max_points: payload.bounty_max_points,
+        max_quantity: None,
+        award_partial_checklist_points: false,
+        follow_up_of_chore_id: None,
+        follow_up_delay_days: None,
+        active_from_month: None,
+        active_until_month: None,
+        next_due_date: payload.due_date,
+        next_due_time: None,
+        is_one_off: true,
+        date_completed: None,
+        description: match payload.description.trim() {
+            "" => None,
+            description => Some(description.to_string()),
+        },
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+
+    chore::create(&state.pool, &chore).await.unwrap();
+
+    Ok(Redirect::to(ChoreDetailPath {
+        chore_list_id: chore_list.id,
+        chore_id: chore.id,
+    }.to_string().as_str()))
+}
+
+/// The parent of a follow-up chore has to be a recurring chore of the same
+/// chore list. It cannot be the chore itself or one of its follow-ups, as
+/// the chores would trigger each other forever.
+async fn is_valid_follow_up_parent(
+    pool: &wg_core::db::Pool,
+    chore_list_id: &ChoreListId,
+    parent_chore_id: &ChoreId,
+    chore_id: Option<&ChoreId>,
+) -> bool {
+    let parent_chore = match chore::get_by_id(pool, parent_chore_id).await {
+        Ok(parent_chore) => parent_chore,
+        Err(wg_core::db::sqlx::Error::RowNotFound) => return false,
+        Err(err) => panic!("{}", err),
+    };
+    if &parent_chore.chore_list_id != chore_list_id || parent_chore.is_one_off {
+        return false;
+    }
+
+    let chore_id = match chore_id {
+        Some(chore_id) => chore_id,
+        None => return true,
+    };
+
+    let mut visited_chore_ids = vec![];
+    let mut ancestor_chore = parent_chore;
+    loop {
+        if &ancestor_chore.id == chore_id {
+            return false;
+        }
+        if visited_chore_ids.contains(&ancestor_chore.id) {
+            return true;
+        }
+        visited_chore_ids.push(ancestor_chore.id);
+
+        ancestor_chore = match ancestor_chore.follow_up_of_chore_id {
+            Some(ancestor_chore_id) => match chore::get_by_id(pool, &ancestor_chore_id).await {
+                Ok(ancestor_chore) => ancestor_chore,
+                Err(wg_core::db::sqlx::Error::RowNotFound) => return true,
+                Err(err) => panic!("{}", err),
+            },
+            None => return true,
+        };
+    }
+}
+
+/// Only participants who score can be excluded from a chore. Duplicates are
+/// removed.
+///
+/// Returns None if one of the users can't be excluded
+async fn validate_excluded_user_ids(
+    pool: &wg_core::db::Pool,
+    user_ids: &[UserId],
+) -> Option<Vec<UserId>> {
+    let users = user::get_all(pool).await.unwrap();
+
+    let mut excluded_user_ids = Vec::with_capacity(user_ids.len());
+    for user_id in user_ids {
+        let is_valid = users.iter()
+            .any(|user| &user.id == user_id && !user.is_deleted() && user.is_scoring());
+        if !is_valid {
+            return None;
+        }
+
+        if !excluded_user_ids.contains(user_id) {
+            excluded_user_ids.push(*user_id);
+        }
+    }
+
+    Some(excluded_user_ids)
+}
+
+/// Seasonal chores need both the first and the last month of their window
+fn is_valid_active_window(active_from_month: Option<u32>, active_until_month: Option<u32>) -> bool {
+    match (active_from_month, active_until_month) {
+        (Some(from_month), Some(until_month)) => (1..=12).contains(&from_month) && (1..=12).contains(&until_month),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+fn parse_checklist(checklist: &str) -> Vec<String> {
+    checklist
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.to_string())
+        .collect()
+}
+
 #[derive(TypedPath, serde::Deserialize)]
 #[typed_path("/chore-lists/{chore_list_id}/chores/{chore_id}/update")]
 pub struct ChoreUpdatePath {
@@ -140,6 +447,7 @@ pub async fn view_update_form(
     _path: ChoreUpdatePath,
     ChoreList(chore_list): ChoreList,
     Chore(chore): Chore,
+    State(state): State<Arc<AppState>>,
     AuthSession(_auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
     if chore_list.is_deleted() || chore.is_deleted() {
@@ -149,7 +457,19 @@ pub async fn view_update_form(
         return Err(StatusCode::NOT_FOUND);
     }
 
-    Ok(template::page::chore_list::chore::update(chore, chore_list))
+    let (checklist_items, chores, users, exclusions) = tokio::try_join!(
+        chore_checklist_item::get_all_for_chore(&state.pool, &chore.id),
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+        chore_exclusion::get_all_for_chore(&state.pool, &chore.id),
+    ).unwrap();
+
+    let users = users
+        .into_iter()
+        .filter(|user| !user.is_deleted() && user.is_scoring())
+        .collect();
+
+    Ok(template::page::chore_list::chore::update(chore, chore_list, checklist_items, chores, users, exclusions))
 }
 
 #[serde_as]
@@ -158,7 +478,51 @@ pub struct UpdatePayload {
     name: String,
     points: u32,
     #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    points_voting_method: Option<PointsVotingMethod>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
     interval_days: Option<u32>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    interval_hours: Option<u32>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    cooldown_days: Option<u32>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    follow_up_of_chore_id: Option<ChoreId>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    follow_up_delay_days: Option<u32>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    active_from_month: Option<u32>,
+    /// Not used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    active_until_month: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_points_per_day: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    bounty_max_points: Option<u32>,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    max_quantity: Option<u32>,
+    /// One checklist item per line
+    checklist: String,
+    #[serde(default)]
+    award_partial_checklist_points: bool,
+    #[serde(default)]
+    excluded_user_ids: Vec<UserId>,
+    /// Only used for one-off tasks
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    due_date: Option<Date>,
     description: String,
 }
 
@@ -177,9 +541,44 @@ pub async fn update(
         return Err(StatusCode::NOT_FOUND);
     }
 
+    if let Some(follow_up_of_chore_id) = payload.follow_up_of_chore_id
+        && !is_valid_follow_up_parent(&state.pool, &chore_list.id, &follow_up_of_chore_id, Some(&chore.id)).await
+    {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    if !is_valid_active_window(payload.active_from_month, payload.active_until_month) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let excluded_user_ids = validate_excluded_user_ids(&state.pool, &payload.excluded_user_ids)
+        .await
+        .ok_or(StatusCode::UNPROCESSABLE_ENTITY)?;
+
+    if payload.interval_days.is_some() && payload.interval_hours.is_some() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let previous_cooldown_days = chore.cooldown_days;
+
     chore.name = payload.name;
     chore.points = payload.points;
-    chore.interval_days = payload.interval_days;
+    chore.points_voting_method = payload.points_voting_method;
+    if chore.is_one_off {
+        chore.next_due_date = payload.due_date;
+    } else {
+        chore.interval_days = payload.interval_days;
+        chore.interval_hours = payload.interval_hours;
+        chore.cooldown_days = payload.cooldown_days;
+        chore.follow_up_of_chore_id = payload.follow_up_of_chore_id;
+        chore.follow_up_delay_days = payload.follow_up_delay_days;
+        chore.active_from_month = payload.active_from_month;
+        chore.active_until_month = payload.active_until_month;
+    }
+    chore.bounty_points_per_day = payload.bounty_points_per_day;
+    chore.bounty_max_points = payload.bounty_max_points;
+    chore.max_quantity = payload.max_quantity;
+    chore.award_partial_checklist_points = payload.award_partial_checklist_points;
     chore.description = match payload.description.trim() {
         "" => None,
         description => Some(description.to_string()),
@@ -189,8 +588,26 @@ pub async fn update(
         .await
         .unwrap();
 
+    service::chore::update_points_from_votes(&mut chore, &state.pool, false)
+        .await
+        .unwrap();
+
     chore::update(&state.pool, &chore).await.unwrap();
 
+    if chore.cooldown_days != previous_cooldown_days {
+        service::chore_activity::update_cooldown_violations(&state.pool, &chore)
+            .await
+            .unwrap();
+    }
+
+    service::chore::update_checklist(&chore, &state.pool, &parse_checklist(&payload.checklist))
+        .await
+        .unwrap();
+
+    service::chore::update_exclusions(&chore, &state.pool, &excluded_user_ids)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(ChoreDetailPath {
         chore_list_id: chore_list.id,
         chore_id: chore.id,
@@ -222,6 +639,10 @@ pub async fn delete(
 
     chore::update(&state.pool, &chore).await.unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(ChoreDetailPath {
         chore_list_id: chore_list.id,
         chore_id: chore.id,
@@ -253,6 +674,112 @@ pub async fn restore(
 
     chore::update(&state.pool, &chore).await.unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(ChoreDetailPath {
+        chore_list_id: chore_list.id,
+        chore_id: chore.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/chores/{chore_id}/vote")]
+pub struct ChoreVotePath {
+    pub chore_list_id: ChoreListId,
+    pub chore_id: ChoreId,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct VotePayload {
+    points: u32,
+}
+
+pub async fn vote(
+    _path: ChoreVotePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(mut chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<VotePayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() || chore.points_voting_method.is_none() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    if !(1..=chore_vote::MAX_POINTS).contains(&payload.points) {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    match chore_vote::get_by_chore_and_user(&state.pool, &chore.id, &auth_session.user_id).await {
+        Ok(mut vote) => {
+            vote.points = payload.points;
+            vote.date_updated = DateTime::now();
+
+            chore_vote::update(&state.pool, &vote).await.unwrap();
+        },
+        Err(wg_core::db::sqlx::Error::RowNotFound) => {
+            let vote = chore_vote::ChoreVote {
+                id: ChoreVoteId::new(),
+                chore_id: chore.id,
+                user_id: auth_session.user_id,
+                points: payload.points,
+                date_created: DateTime::now(),
+                date_updated: DateTime::now(),
+            };
+
+            chore_vote::create(&state.pool, &vote).await.unwrap();
+        },
+        Err(err) => panic!("{}", err),
+    };
+
+    service::chore::update_points_from_votes(&mut chore, &state.pool, true)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(ChoreDetailPath {
+        chore_list_id: chore_list.id,
+        chore_id: chore.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/chores/{chore_id}/vote/delete")]
+pub struct ChoreVoteDeletePath {
+    pub chore_list_id: ChoreListId,
+    pub chore_id: ChoreId,
+}
+
+pub async fn delete_vote(
+    _path: ChoreVoteDeletePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(mut chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let vote = match chore_vote::get_by_chore_and_user(&state.pool, &chore.id, &auth_session.user_id).await {
+        Ok(vote) => vote,
+        Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    };
+
+    chore_vote::delete(&state.pool, &vote).await.unwrap();
+
+    service::chore::update_points_from_votes(&mut chore, &state.pool, true)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(ChoreDetailPath {
         chore_list_id: chore_list.id,
         chore_id: chore.id,
@@ -277,9 +804,10 @@ pub async fn view_activity_list(
         return Err(StatusCode::NOT_FOUND);
     }
 
-    let (users, all_activities) = tokio::try_join!(
+    let (users, all_activities, postponements) = tokio::try_join!(
         user::get_all(&state.pool),
         chore_activity::get_all_for_chore(&state.pool, &chore.id),
+        chore_postponement::get_all_for_chore(&state.pool, &chore.id),
     ).unwrap();
 
     let (activities, deleted_activities): (Vec<_>, Vec<_>) = all_activities
@@ -292,6 +820,87 @@ pub async fn view_activity_list(
         chore_list,
         activities_by_date,
         deleted_activities,
+        postponements,
         users,
     ))
 }
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/chores/{chore_id}/postpone")]
+pub struct ChorePostponePath {
+    pub chore_list_id: ChoreListId,
+    pub chore_id: ChoreId,
+}
+
+pub async fn view_postpone_form(
+    _path: ChorePostponePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(chore): Chore,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    if chore.is_due() != Some(true) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(template::page::chore_list::chore::postpone(chore, chore_list))
+}
+
+#[serde_as]
+#[derive(serde::Deserialize, Debug)]
+pub struct PostponePayload {
+    kind: ChorePostponementKind,
+    /// Only used for snoozing
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    snooze_days: Option<u32>,
+    reason: String,
+}
+
+pub async fn postpone(
+    _path: ChorePostponePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(mut chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<PostponePayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    if chore.is_due() != Some(true) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let reason = match payload.reason.trim() {
+        "" => None,
+        reason => Some(reason.to_string()),
+    };
+
+    let postponement = service::chore::postpone(
+        &mut chore,
+        &state.pool,
+        auth_session.user_id,
+        payload.kind,
+        payload.snooze_days,
+        reason,
+    )
+        .await
+        .unwrap();
+    if postponement.is_none() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    Ok(Redirect::to(ChoreDetailPath {
+        chore_list_id: chore_list.id,
+        chore_id: chore.id,
+    }.to_string().as_str()))
+}
@@ -2,24 +2,30 @@ use wg_core::model::chore;
 use wg_core::model::chore::ChoreId;
 use wg_core::model::chore_activity;
 use wg_core::model::chore_activity::ChoreActivityId;
+use wg_core::model::chore_activity_checklist_item;
+use wg_core::model::chore_checklist_item;
+use wg_core::model::chore_checklist_item::ChoreChecklistItemId;
 use wg_core::model::chore_list::ChoreListId;
 use wg_core::model::user;
+use wg_core::model::user::UserId;
+use wg_core::model::user::Permission;
 use wg_core::service;
 use crate::extractor::authentication::AuthSession;
 use crate::extractor::model::ChoreActivity;
 use crate::extractor::model::ChoreList;
 use crate::template;
 use crate::AppState;
-use wg_core::value::{Date, DateTime};
+use wg_core::value::{Date, DateTime, Time};
 use axum::{
-    Form,
     extract::State,
     http::StatusCode,
-    response::Redirect,
+    response::{IntoResponse, Redirect, Response},
 };
+use axum_extra::extract::Form;
 use axum_extra::routing::TypedPath;
 use chrono::Days;
 use maud::Markup;
+use serde_with::serde_as;
 use std::sync::Arc;
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -54,6 +60,36 @@ pub async fn view_list(
     ))
 }
 
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/activities/cooldown-violations")]
+pub struct ChoreActivityCooldownViolationIndexPath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_cooldown_violation_list(
+    _path: ChoreActivityCooldownViolationIndexPath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ViewCooldownViolations).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let (chores, users, activities) = tokio::try_join!(
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+        chore_activity::get_all_cooldown_violations_for_chore_list(&state.pool, &chore_list.id),
+    ).unwrap();
+
+    Ok(template::page::chore_list::activity::list_cooldown_violations(
+        chore_list,
+        activities,
+        chores,
+        users,
+    ))
+}
+
 #[derive(TypedPath, serde::Deserialize)]
 #[typed_path("/chore-lists/{chore_list_id}/activities/{chore_activity_id}")]
 pub struct ChoreActivityDetailPath {
@@ -68,24 +104,26 @@ pub async fn view_detail(
     State(state): State<Arc<AppState>>,
     AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
-    let (chore, user) = tokio::try_join!(
+    let (chore, user, checklist_items) = tokio::try_join!(
         chore::get_by_id(&state.pool, &activity.chore_id),
         user::get_by_id(&state.pool, &activity.user_id),
+        chore_activity_checklist_item::get_all_for_chore_activity(&state.pool, &activity.id),
     ).unwrap();
 
     if chore.chore_list_id != chore_list.id {
         return Err(StatusCode::NOT_FOUND);
     }
 
-    let allow_edit = activity.date.as_ref() >= &(chrono::Utc::now() - Days::new(2)).date_naive();
-    let allow_delete_restore = activity.date.as_ref() >= &(chrono::Utc::now() - Days::new(4)).date_naive();
+    let can_be_managed = service::chore_activity::can_be_managed_by(&state.pool, &activity, &auth_session.user_id).await;
+    let allow_edit = can_be_managed && activity.date.as_ref() >= &(chrono::Utc::now() - Days::new(2)).date_naive();
+    let allow_delete_restore = can_be_managed && activity.date.as_ref() >= &(chrono::Utc::now() - Days::new(4)).date_naive();
 
     Ok(template::page::chore_list::activity::detail(
         activity,
         chore,
+        checklist_items,
         chore_list,
         user,
-        auth_session,
         allow_edit,
         allow_delete_restore,
     ))
@@ -101,15 +139,23 @@ pub async fn view_create_form(
     _path: ChoreActivityCreatePath,
     ChoreList(chore_list): ChoreList,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
     if chore_list.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let chores = chore::get_all_for_chore_list(&state.pool, &chore_list.id)
-        .await
-        .unwrap();
+    let (chores, checklist_items, users) = tokio::try_join!(
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_checklist_item::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+    ).unwrap();
+
+    // Activities can be recorded for yourself or for non-scoring participants
+    let users = users
+        .into_iter()
+        .filter(|user| !user.is_deleted() && (user.id == auth_session.user_id || !user.is_scoring()))
+        .collect();
     let min_date = Date::from((chrono::Utc::now() - Days::new(2)).date_naive());
     let max_date = Date::now();
     let now = DateTime::now();
@@ -117,17 +163,37 @@ pub async fn view_create_form(
     Ok(template::page::chore_list::activity::create(
         chore_list,
         chores,
+        checklist_items,
+        users,
+        auth_session,
         min_date,
         max_date,
         now,
     ))
 }
 
+#[serde_as]
 #[derive(serde::Deserialize, Debug)]
 pub struct CreatePayload {
     chore_id: ChoreId,
+    /// Non-scoring participant the activity is recorded for, the current
+    /// user otherwise
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    user_id: Option<UserId>,
     date: Date,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    time: Option<Time>,
+    /// Only used for quantity-based chores
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    quantity: Option<u32>,
+    #[serde(default)]
+    checklist_item_ids: Vec<ChoreChecklistItemId>,
     comment: String,
+    #[serde(default)]
+    confirm_cooldown_violation: bool,
 }
 
 pub async fn create(
@@ -136,7 +202,7 @@ pub async fn create(
     State(state): State<Arc<AppState>>,
     AuthSession(auth_session): AuthSession,
     Form(payload): Form<CreatePayload>,
-) -> Result<Redirect, StatusCode> {
+) -> Result<Response, StatusCode> {
     if chore_list.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
@@ -152,6 +218,9 @@ pub async fn create(
     if chore.chore_list_id != chore_list.id {
         return Err(StatusCode::UNPROCESSABLE_ENTITY);
     }
+    if chore.is_completed() {
+        return Err(StatusCode::FORBIDDEN);
+    }
 
     let min_date = (chrono::Utc::now() - Days::new(2)).date_naive();
     let max_date = chrono::Utc::now().date_naive();
@@ -160,30 +229,110 @@ pub async fn create(
         return Err(StatusCode::UNPROCESSABLE_ENTITY);
     }
 
+    let user_id = match payload.user_id {
+        Some(user_id) if user_id != auth_session.user_id => {
+            let user = match user::get_by_id(&state.pool, &user_id).await {
+                Ok(user) => user,
+                Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+                Err(err) => panic!("{}", err),
+            };
+            if user.is_deleted() || user.is_scoring() {
+                return Err(StatusCode::UNPROCESSABLE_ENTITY);
+            }
+
+            user.id
+        }
+        _ => auth_session.user_id,
+    };
+
+    let quantity = match chore.max_quantity {
+        Some(max_quantity) => match payload.quantity.unwrap_or(1) {
+            quantity if (1..=max_quantity).contains(&quantity) => quantity,
+            _ => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        },
+        None => 1,
+    };
+
+    let checklist_items = chore_checklist_item::get_all_for_chore(&state.pool, &chore.id)
+        .await
+        .unwrap();
+    let done_checklist_items = checklist_items
+        .iter()
+        .filter(|item| payload.checklist_item_ids.contains(&item.id))
+        .count();
+
     let activity = chore_activity::ChoreActivity {
         id: ChoreActivityId::new(),
         chore_id: chore.id,
-        user_id: auth_session.user_id,
+        user_id,
+        recorded_by_user_id: Some(auth_session.user_id),
         date: payload.date,
+        time: payload.time,
+        points: chore.get_points_for_checklist(done_checklist_items, checklist_items.len()),
+        quantity,
+        bounty_points: chore.get_current_bounty_for_checklist(done_checklist_items, checklist_items.len()),
         comment: match payload.comment.trim() {
             "" => None,
             comment => Some(comment.to_string()),
         },
+        is_cooldown_violation: false,
         date_created: DateTime::now(),
         date_deleted: None,
     };
 
+    let cooldown_conflict = service::chore_activity::find_cooldown_conflict(&state.pool, &chore, &activity)
+        .await
+        .unwrap();
+    if let Some(conflicting_activity) = cooldown_conflict {
+        if !payload.confirm_cooldown_violation {
+            return Ok(template::page::chore_list::activity::confirm_cooldown_violation(
+                activity,
+                payload.checklist_item_ids,
+                conflicting_activity,
+                chore,
+                chore_list,
+            ).into_response());
+        }
+
+        tracing::info!(chore_id = %chore.id, conflicting_activity_id = %conflicting_activity.id, "Activity violates chore cooldown");
+    }
+
     chore_activity::create(&state.pool, &activity)
         .await
         .unwrap();
 
+    // A backdated activity might take the credit from a later one instead
+    service::chore_activity::update_cooldown_violations(&state.pool, &chore)
+        .await
+        .unwrap();
+    let activity = chore_activity::get_by_id(&state.pool, &activity.id)
+        .await
+        .unwrap();
+
+    if !checklist_items.is_empty() {
+        service::chore_activity::save_checklist(&state.pool, &activity, &checklist_items, &payload.checklist_item_ids)
+            .await
+            .unwrap();
+    }
+
+    let completed_swap_request = service::chore_swap_request::complete_for_activity(&state.pool, &activity)
+        .await
+        .unwrap();
+    if let Some(swap_request) = completed_swap_request {
+        tracing::info!(chore_swap_request_id = %swap_request.id, chore_activity_id = %activity.id, "Completed chore swap request");
+    }
+
     service::chore::update_next_due_date(&mut chore, &state.pool, true)
         .await
         .unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(&ChoreActivityIndexPath {
         chore_list_id: chore_list.id,
-    }.to_string().as_str()))
+    }.to_string().as_str()).into_response())
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -204,13 +353,15 @@ pub async fn view_update_form(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    if activity.user_id != auth_session.user_id {
+    if !service::chore_activity::can_be_managed_by(&state.pool, &activity, &auth_session.user_id).await {
         return Err(StatusCode::FORBIDDEN);
     }
 
-    let (chore, all_chores) = tokio::try_join!(
+    let (chore, all_chores, checklist_items, activity_checklist_items) = tokio::try_join!(
         chore::get_by_id(&state.pool, &activity.chore_id),
         chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_checklist_item::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_activity_checklist_item::get_all_for_chore_activity(&state.pool, &activity.id),
     ).unwrap();
 
     if chore.is_deleted() {
@@ -231,16 +382,28 @@ pub async fn view_update_form(
     Ok(template::page::chore_list::activity::update(
         activity,
         all_chores,
+        checklist_items,
+        activity_checklist_items,
         chore_list,
         min_date,
         max_date,
     ))
 }
 
+#[serde_as]
 #[derive(serde::Deserialize, Debug)]
 pub struct UpdatePayload {
     chore_id: ChoreId,
     date: Date,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    time: Option<Time>,
+    /// Only used for quantity-based chores
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    #[serde(default)]
+    quantity: Option<u32>,
+    #[serde(default)]
+    checklist_item_ids: Vec<ChoreChecklistItemId>,
     comment: String,
 }
 
@@ -256,7 +419,7 @@ pub async fn update(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    if activity.user_id != auth_session.user_id {
+    if !service::chore_activity::can_be_managed_by(&state.pool, &activity, &auth_session.user_id).await {
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -283,26 +446,93 @@ pub async fn update(
 
     activity.chore_id = payload.chore_id;
     activity.date = payload.date;
+    activity.time = payload.time;
     activity.comment = match payload.comment.trim() {
         "" => None,
         comment => Some(comment.to_string()),
     };
 
+    let mut new_chore = chore::get_by_id(&state.pool, &activity.chore_id)
+        .await
+        .unwrap();
+    if new_chore.id != chore.id && new_chore.is_completed() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    let (checklist_items, previous_checklist_items) = tokio::try_join!(
+        chore_checklist_item::get_all_for_chore(&state.pool, &new_chore.id),
+        chore_activity_checklist_item::get_all_for_chore_activity(&state.pool, &activity.id),
+    ).unwrap();
+    let done_checklist_items = checklist_items
+        .iter()
+        .filter(|item| payload.checklist_item_ids.contains(&item.id))
+        .count();
+    let previously_done_checklist_items = previous_checklist_items
+        .iter()
+        .filter(|item| item.is_done)
+        .count();
+
+    if new_chore.id != chore.id {
+        activity.points = new_chore.get_points_for_checklist(done_checklist_items, checklist_items.len());
+        activity.bounty_points = new_chore.get_current_bounty_for_checklist(done_checklist_items, checklist_items.len());
+    } else if done_checklist_items != previously_done_checklist_items
+        || checklist_items.len() != previous_checklist_items.len()
+    {
+        // The points were locked in when the activity was recorded, only the
+        // share for the checklist changes
+        let previous_checklist = (previously_done_checklist_items, previous_checklist_items.len());
+        let checklist = (done_checklist_items, checklist_items.len());
+
+        match chore.rescale_for_checklist(activity.points, previous_checklist, checklist) {
+            Some(points) => {
+                activity.points = points;
+                activity.bounty_points = chore.rescale_for_checklist(activity.bounty_points, previous_checklist, checklist)
+                    .unwrap_or(0);
+            },
+            None => {
+                activity.points = chore.get_points_for_checklist(done_checklist_items, checklist_items.len());
+                activity.bounty_points = 0;
+            },
+        }
+    }
+    activity.quantity = match new_chore.max_quantity {
+        Some(max_quantity) => match payload.quantity.unwrap_or(1) {
+            quantity if (1..=max_quantity).contains(&quantity) => quantity,
+            _ => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        },
+        None => 1,
+    };
     chore_activity::update(&state.pool, &activity)
         .await
         .unwrap();
 
+    service::chore_activity::save_checklist(&state.pool, &activity, &checklist_items, &payload.checklist_item_ids)
+        .await
+        .unwrap();
+
+    service::chore_activity::update_cooldown_violations(&state.pool, &chore)
+        .await
+        .unwrap();
+
     service::chore::update_next_due_date(&mut chore, &state.pool, true)
         .await
         .unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
     if payload.chore_id != chore.id {
-        let mut new_chore = chore::get_by_id(&state.pool, &payload.chore_id)
+        service::chore_activity::update_cooldown_violations(&state.pool, &new_chore)
             .await
             .unwrap();
+
         service::chore::update_next_due_date(&mut new_chore, &state.pool, true)
             .await
             .unwrap();
+
+        service::chore::update_follow_up_due_dates(&new_chore, &state.pool)
+            .await
+            .unwrap();
     }
 
     Ok(Redirect::to(&ChoreActivityDetailPath {
@@ -329,7 +559,7 @@ pub async fn delete(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    if activity.user_id != auth_session.user_id {
+    if !service::chore_activity::can_be_managed_by(&state.pool, &activity, &auth_session.user_id).await {
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -354,10 +584,18 @@ pub async fn delete(
         .await
         .unwrap();
 
+    service::chore_activity::update_cooldown_violations(&state.pool, &chore)
+        .await
+        .unwrap();
+
     service::chore::update_next_due_date(&mut chore, &state.pool, true)
         .await
         .unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(&ChoreActivityDetailPath {
         chore_list_id: chore_list.id,
         chore_activity_id: activity.id,
@@ -382,7 +620,7 @@ pub async fn restore(
         return Err(StatusCode::FORBIDDEN);
     }
 
-    if activity.user_id != auth_session.user_id {
+    if !service::chore_activity::can_be_managed_by(&state.pool, &activity, &auth_session.user_id).await {
         return Err(StatusCode::FORBIDDEN);
     }
 
@@ -407,10 +645,18 @@ pub async fn restore(
         .await
         .unwrap();
 
+    service::chore_activity::update_cooldown_violations(&state.pool, &chore)
+        .await
+        .unwrap();
+
     service::chore::update_next_due_date(&mut chore, &state.pool, true)
         .await
         .unwrap();
 
+    service::chore::update_follow_up_due_dates(&chore, &state.pool)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(&ChoreActivityDetailPath {
         chore_list_id: chore_list.id,
         chore_activity_id: activity.id,
@@ -1,4 +1,6 @@
 use wg_core::model::chore_list::{self, ChoreListId};
+use wg_core::model::user::Permission;
+use wg_core::service;
 use crate::extractor::authentication::AuthSession;
 use crate::extractor::model::ChoreList;
 use crate::template;
@@ -137,8 +139,12 @@ pub async fn delete(
     _path: ChoreListDeletePath,
     ChoreList(mut chore_list): ChoreList,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::DeleteChoreLists).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     if chore_list.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
@@ -162,8 +168,12 @@ pub async fn restore(
     _path: ChoreListRestorePath,
     ChoreList(mut chore_list): ChoreList,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::DeleteChoreLists).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     if !chore_list.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
@@ -186,7 +196,11 @@ pub struct ChoreListSettingsPath {
 pub async fn view_settings(
     _path: ChoreListSettingsPath,
     ChoreList(chore_list): ChoreList,
-    AuthSession(_auth_session): AuthSession,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
-    Ok(template::page::chore_list::settings(chore_list))
+    let can_delete_chore_list = service::user::has_permission(&state.pool, &auth_session.user_id, Permission::DeleteChoreLists).await;
+    let can_view_cooldown_violations = service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ViewCooldownViolations).await;
+
+    Ok(template::page::chore_list::settings(chore_list, can_delete_chore_list, can_view_cooldown_violations))
 }
@@ -0,0 +1,303 @@
+use serde_with::serde_as;
+use wg_core::model::chore;
+use wg_core::model::chore::ChoreId;
+use wg_core::model::chore_list;
+use wg_core::model::chore_list::ChoreListId;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_swap_request::{ChoreSwapRequestId, ChoreSwapRequestStatus};
+use wg_core::model::user;
+use wg_core::model::user::UserId;
+use wg_core::service;
+use wg_core::value::DateTime;
+use wg_mail::lettre::AsyncTransport;
+use crate::extractor::authentication::AuthSession;
+use crate::extractor::model::Chore;
+use crate::extractor::model::ChoreList;
+use crate::extractor::model::ChoreSwapRequest;
+use crate::template;
+use crate::AppState;
+use axum::{
+    Form,
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use std::sync::Arc;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/swap-requests")]
+pub struct ChoreSwapRequestIndexPath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_list(
+    _path: ChoreSwapRequestIndexPath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    let (swap_requests, chores, users) = tokio::try_join!(
+        chore_swap_request::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+    ).unwrap();
+
+    let (open_swap_requests, closed_swap_requests) = swap_requests
+        .into_iter()
+        .partition(|swap_request| swap_request.is_open());
+
+    Ok(template::page::chore_list::swap_request::list(
+        chore_list,
+        open_swap_requests,
+        closed_swap_requests,
+        chores,
+        users,
+        auth_session,
+    ))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/chores/{chore_id}/swap-requests/create")]
+pub struct ChoreSwapRequestCreatePath {
+    pub chore_list_id: ChoreListId,
+    pub chore_id: ChoreId,
+}
+
+pub async fn view_create_form(
+    _path: ChoreSwapRequestCreatePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let users = user::get_all(&state.pool)
+        .await
+        .unwrap()
+        .into_iter()
+        .filter(|user| !user.is_deleted() && user.is_scoring() && user.id != auth_session.user_id)
+        .collect();
+
+    Ok(template::page::chore_list::swap_request::create(chore, chore_list, users))
+}
+
+#[serde_as]
+#[derive(serde::Deserialize, Debug)]
+pub struct CreatePayload {
+    target_user_id: UserId,
+    #[serde_as(as = "serde_with::NoneAsEmptyString")]
+    offered_points: Option<u32>,
+    comment: String,
+}
+
+pub async fn create(
+    _path: ChoreSwapRequestCreatePath,
+    ChoreList(chore_list): ChoreList,
+    Chore(chore): Chore,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<CreatePayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    if payload.target_user_id == auth_session.user_id {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let can_be_offered = service::chore_swap_request::can_be_offered(&state.pool, &chore)
+        .await
+        .unwrap();
+    if !can_be_offered {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let offered_points = payload.offered_points.unwrap_or(0);
+    if offered_points > 0 {
+        let balance = chore_list::get_balance_per_user(&state.pool, &chore_list)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|(user_id, _balance)| *user_id == auth_session.user_id)
+            .map(|(_user_id, balance)| balance)
+            .unwrap_or(0);
+        if i64::from(balance) < i64::from(offered_points) {
+            return Err(StatusCode::UNPROCESSABLE_ENTITY);
+        }
+    }
+
+    let (requesting_user, target_user) = tokio::try_join!(
+        user::get_by_id(&state.pool, &auth_session.user_id),
+        user::get_by_id(&state.pool, &payload.target_user_id),
+    ).map_err(|err| match err {
+        wg_core::db::sqlx::Error::RowNotFound => StatusCode::UNPROCESSABLE_ENTITY,
+        err => panic!("{}", err),
+    })?;
+    if target_user.is_deleted() || !target_user.is_scoring() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    let swap_request = chore_swap_request::ChoreSwapRequest {
+        id: ChoreSwapRequestId::new(),
+        chore_id: chore.id,
+        requesting_user_id: requesting_user.id,
+        target_user_id: Some(target_user.id),
+        absence_id: None,
+        offered_points,
+        comment: match payload.comment.trim() {
+            "" => None,
+            comment => Some(comment.to_string()),
+        },
+        status: ChoreSwapRequestStatus::Pending,
+        chore_activity_id: None,
+        date_created: DateTime::now(),
+        date_updated: DateTime::now(),
+    };
+
+    chore_swap_request::create(&state.pool, &swap_request).await.unwrap();
+
+    let url = format!("{}{}", state.base_url, ChoreSwapRequestIndexPath { chore_list_id: chore_list.id });
+    let mail_message = wg_mail::message::chore_swap_request(&target_user, &requesting_user, &chore, &swap_request, &url);
+    // Sending the mail takes a while, which shouldn't hold up the response
+    tokio::spawn(async move {
+        if let Err(err) = state.mail_transport.send(mail_message).await {
+            tracing::error!(user_id = ?target_user.id, error = %err, "Failed to send chore swap request notification");
+        }
+    });
+
+    Ok(Redirect::to(ChoreSwapRequestIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/swap-requests/{chore_swap_request_id}/accept")]
+pub struct ChoreSwapRequestAcceptPath {
+    pub chore_list_id: ChoreListId,
+    pub chore_swap_request_id: ChoreSwapRequestId,
+}
+
+pub async fn accept(
+    _path: ChoreSwapRequestAcceptPath,
+    ChoreList(chore_list): ChoreList,
+    ChoreSwapRequest(mut swap_request): ChoreSwapRequest,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || swap_request.status != ChoreSwapRequestStatus::Pending {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    match swap_request.target_user_id {
+        Some(target_user_id) if target_user_id != auth_session.user_id => return Err(StatusCode::FORBIDDEN),
+        None if swap_request.requesting_user_id == auth_session.user_id => return Err(StatusCode::FORBIDDEN),
+        _ => {},
+    }
+
+    let chore = chore::get_by_id(&state.pool, &swap_request.chore_id)
+        .await
+        .unwrap();
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+    if chore.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    swap_request.target_user_id = Some(auth_session.user_id);
+    swap_request.status = ChoreSwapRequestStatus::Accepted;
+    swap_request.date_updated = DateTime::now();
+
+    chore_swap_request::update(&state.pool, &swap_request).await.unwrap();
+
+    Ok(Redirect::to(ChoreSwapRequestIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/swap-requests/{chore_swap_request_id}/decline")]
+pub struct ChoreSwapRequestDeclinePath {
+    pub chore_list_id: ChoreListId,
+    pub chore_swap_request_id: ChoreSwapRequestId,
+}
+
+pub async fn decline(
+    _path: ChoreSwapRequestDeclinePath,
+    ChoreList(chore_list): ChoreList,
+    ChoreSwapRequest(mut swap_request): ChoreSwapRequest,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || !swap_request.is_open() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if swap_request.target_user_id != Some(auth_session.user_id) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let chore = chore::get_by_id(&state.pool, &swap_request.chore_id)
+        .await
+        .unwrap();
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    swap_request.status = ChoreSwapRequestStatus::Declined;
+    swap_request.date_updated = DateTime::now();
+
+    chore_swap_request::update(&state.pool, &swap_request).await.unwrap();
+
+    Ok(Redirect::to(ChoreSwapRequestIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/swap-requests/{chore_swap_request_id}/cancel")]
+pub struct ChoreSwapRequestCancelPath {
+    pub chore_list_id: ChoreListId,
+    pub chore_swap_request_id: ChoreSwapRequestId,
+}
+
+pub async fn cancel(
+    _path: ChoreSwapRequestCancelPath,
+    ChoreList(chore_list): ChoreList,
+    ChoreSwapRequest(mut swap_request): ChoreSwapRequest,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || !swap_request.is_open() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if swap_request.requesting_user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let chore = chore::get_by_id(&state.pool, &swap_request.chore_id)
+        .await
+        .unwrap();
+    if chore.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    swap_request.status = ChoreSwapRequestStatus::Cancelled;
+    swap_request.date_updated = DateTime::now();
+
+    chore_swap_request::update(&state.pool, &swap_request).await.unwrap();
+
+    Ok(Redirect::to(ChoreSwapRequestIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
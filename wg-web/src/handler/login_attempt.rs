@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use axum::extract::State;
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use wg_core::model::login_attempt;
+use crate::extractor::authentication::AuthSession;
+use crate::template;
+use crate::AppState;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/login-history")]
+pub struct LoginAttemptIndexPath;
+
+pub async fn view_list(
+    _path: LoginAttemptIndexPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Markup {
+    let login_attempts = login_attempt::get_latest_for_user(&state.pool, &auth_session.user_id, 50)
+        .await
+        .unwrap();
+
+    template::page::login_attempt::list(login_attempts)
+}
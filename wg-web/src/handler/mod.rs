@@ -1,12 +1,19 @@
 pub mod assets;
 pub mod authentication;
+pub mod authentication_session;
 pub mod chore;
 pub mod chore_activity;
 pub mod chore_list;
 pub mod chore_list_user;
+pub mod chore_swap_request;
 pub mod entry;
 pub mod health;
 pub mod legal;
+pub mod login_attempt;
+pub mod passkey;
+pub mod reward;
 pub mod user;
+pub mod user_invitation;
 pub mod settings;
+pub mod two_factor_authentication;
 pub mod absence;
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use axum::{
+    Form,
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use wg_core::model::passkey::{self, PasskeyId};
+use wg_core::model::user;
+use wg_core::service;
+use wg_core::service::passkey::{RegistrationResponse, RelyingParty};
+use crate::extractor::authentication::AuthSession;
+use crate::template;
+use crate::AppState;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/passkeys")]
+pub struct PasskeyIndexPath;
+
+/// Every view creates a new challenge for registering another passkey
+pub async fn view_list(
+    _path: PasskeyIndexPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Markup {
+    let user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    let passkeys = passkey::get_all_for_user(&state.pool, &user.id)
+        .await
+        .unwrap();
+
+    let passkey_challenge = service::passkey::create_challenge(&state.pool, user.id).await;
+    let relying_party = RelyingParty::from_base_url(&state.base_url);
+
+    template::page::passkey::list(user, passkeys, passkey_challenge, relying_party)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/passkeys/create")]
+pub struct PasskeyCreatePath;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CreatePayload {
+    name: String,
+    credential_id: String,
+    client_data_json: String,
+    authenticator_data: String,
+    public_key: String,
+    public_key_algorithm: i64,
+}
+
+pub async fn create(
+    _path: PasskeyCreatePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<CreatePayload>,
+) -> Result<Redirect, StatusCode> {
+    let user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    let response = RegistrationResponse {
+        credential_id: payload.credential_id,
+        client_data_json: payload.client_data_json,
+        authenticator_data: payload.authenticator_data,
+        public_key: payload.public_key,
+        public_key_algorithm: payload.public_key_algorithm,
+    };
+    let relying_party = RelyingParty::from_base_url(&state.base_url);
+
+    if service::passkey::register(&state.pool, &relying_party, &user, payload.name, &response).await.is_none() {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    Ok(Redirect::to(PasskeyIndexPath.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/passkeys/{passkey_id}/delete")]
+pub struct PasskeyDeletePath {
+    pub passkey_id: PasskeyId,
+}
+
+pub async fn delete(
+    path: PasskeyDeletePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    let passkey = match passkey::get_by_id(&state.pool, &path.passkey_id).await {
+        Ok(passkey) => passkey,
+        Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    };
+
+    if passkey.user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    passkey::delete(&state.pool, &passkey).await.unwrap();
+
+    Ok(Redirect::to(PasskeyIndexPath.to_string().as_str()))
+}
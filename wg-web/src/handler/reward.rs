@@ -0,0 +1,392 @@
+use wg_core::model::chore_list;
+use wg_core::model::chore_list::ChoreListId;
+use wg_core::model::reward;
+use wg_core::model::reward::RewardId;
+use wg_core::model::reward_redemption;
+use wg_core::model::reward_redemption::RewardRedemptionId;
+use wg_core::model::user;
+use wg_core::value::DateTime;
+use wg_mail::lettre::AsyncTransport;
+use crate::extractor::authentication::AuthSession;
+use crate::extractor::model::ChoreList;
+use crate::extractor::model::Reward;
+use crate::extractor::model::RewardRedemption;
+use crate::template;
+use crate::AppState;
+use axum::{
+    Form,
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use std::sync::Arc;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards")]
+pub struct RewardIndexPath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_list(
+    _path: RewardIndexPath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    let (all_rewards, balance_per_user) = tokio::try_join!(
+        reward::get_all_for_chore_list(&state.pool, &chore_list.id),
+        chore_list::get_balance_per_user(&state.pool, &chore_list),
+    ).unwrap();
+
+    let (rewards, deleted_rewards) = all_rewards
+        .into_iter()
+        .partition(|reward| !reward.is_deleted());
+
+    let balance = balance_per_user.iter()
+        .find(|(user_id, _balance)| *user_id == auth_session.user_id)
+        .map(|&(_user_id, balance)| balance)
+        .unwrap_or(0);
+
+    Ok(template::page::chore_list::reward::list(chore_list, rewards, deleted_rewards, balance))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/{reward_id}")]
+pub struct RewardDetailPath {
+    pub chore_list_id: ChoreListId,
+    pub reward_id: RewardId,
+}
+
+pub async fn view_detail(
+    _path: RewardDetailPath,
+    ChoreList(chore_list): ChoreList,
+    Reward(reward): Reward,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let balance = chore_list::get_balance_per_user(&state.pool, &chore_list)
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|(user_id, _balance)| *user_id == auth_session.user_id)
+        .map(|(_user_id, balance)| balance)
+        .unwrap_or(0);
+
+    Ok(template::page::chore_list::reward::detail(reward, chore_list, balance))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/create")]
+pub struct RewardCreatePath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_create_form(
+    _path: RewardCreatePath,
+    ChoreList(chore_list): ChoreList,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if chore_list.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(template::page::chore_list::reward::create(chore_list))
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CreatePayload {
+    name: String,
+    points: u32,
+    description: String,
+}
+
+pub async fn create(
+    _path: RewardCreatePath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(_auth_session): AuthSession,
+    Form(payload): Form<CreatePayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let reward = reward::Reward {
+        id: RewardId::new(),
+        chore_list_id: chore_list.id,
+        name: payload.name,
+        description: match payload.description.trim() {
+            "" => None,
+            description => Some(description.to_string()),
+        },
+        points: payload.points,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+
+    reward::create(&state.pool, &reward).await.unwrap();
+
+    Ok(Redirect::to(RewardDetailPath {
+        chore_list_id: chore_list.id,
+        reward_id: reward.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/{reward_id}/update")]
+pub struct RewardUpdatePath {
+    pub chore_list_id: ChoreListId,
+    pub reward_id: RewardId,
+}
+
+pub async fn view_update_form(
+    _path: RewardUpdatePath,
+    ChoreList(chore_list): ChoreList,
+    Reward(reward): Reward,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if chore_list.is_deleted() || reward.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    Ok(template::page::chore_list::reward::update(reward, chore_list))
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct UpdatePayload {
+    name: String,
+    points: u32,
+    description: String,
+}
+
+pub async fn update(
+    _path: RewardUpdatePath,
+    ChoreList(chore_list): ChoreList,
+    Reward(mut reward): Reward,
+    State(state): State<Arc<AppState>>,
+    AuthSession(_auth_session): AuthSession,
+    Form(payload): Form<UpdatePayload>,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || reward.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    reward.name = payload.name;
+    reward.points = payload.points;
+    reward.description = match payload.description.trim() {
+        "" => None,
+        description => Some(description.to_string()),
+    };
+
+    reward::update(&state.pool, &reward).await.unwrap();
+
+    Ok(Redirect::to(RewardDetailPath {
+        chore_list_id: chore_list.id,
+        reward_id: reward.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/{reward_id}/delete")]
+pub struct RewardDeletePath {
+    pub chore_list_id: ChoreListId,
+    pub reward_id: RewardId,
+}
+
+pub async fn delete(
+    _path: RewardDeletePath,
+    ChoreList(chore_list): ChoreList,
+    Reward(mut reward): Reward,
+    State(state): State<Arc<AppState>>,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || reward.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    reward.date_deleted = Some(DateTime::now());
+
+    reward::update(&state.pool, &reward).await.unwrap();
+
+    Ok(Redirect::to(RewardDetailPath {
+        chore_list_id: chore_list.id,
+        reward_id: reward.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/{reward_id}/restore")]
+pub struct RewardRestorePath {
+    pub chore_list_id: ChoreListId,
+    pub reward_id: RewardId,
+}
+
+pub async fn restore(
+    _path: RewardRestorePath,
+    ChoreList(chore_list): ChoreList,
+    Reward(mut reward): Reward,
+    State(state): State<Arc<AppState>>,
+    AuthSession(_auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || !reward.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    reward.date_deleted = None;
+
+    reward::update(&state.pool, &reward).await.unwrap();
+
+    Ok(Redirect::to(RewardDetailPath {
+        chore_list_id: chore_list.id,
+        reward_id: reward.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/{reward_id}/redeem")]
+pub struct RewardRedeemPath {
+    pub chore_list_id: ChoreListId,
+    pub reward_id: RewardId,
+}
+
+pub async fn redeem(
+    _path: RewardRedeemPath,
+    ChoreList(chore_list): ChoreList,
+    Reward(reward): Reward,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || reward.is_deleted() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    let reward_redemption = reward_redemption::RewardRedemption {
+        id: RewardRedemptionId::new(),
+        reward_id: reward.id,
+        user_id: auth_session.user_id,
+        points: reward.points,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+
+    let is_redeemed = reward_redemption::create_if_affordable(&state.pool, &chore_list, &reward_redemption)
+        .await
+        .unwrap();
+    if !is_redeemed {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let users = user::get_all(&state.pool).await.unwrap();
+    let redeeming_user = users.iter()
+        .find(|user| user.id == auth_session.user_id)
+        .unwrap();
+
+    let mail_messages = users.iter()
+        .filter(|user| !user.is_deleted() && user.is_scoring() && user.id != redeeming_user.id)
+        .map(|user| (user.id, wg_mail::message::reward_redeemed(user, redeeming_user, &reward, &chore_list)))
+        .collect::<Vec<_>>();
+
+    // Sending the notifications must not hold up the response
+    tokio::spawn(async move {
+        for (user_id, mail_message) in mail_messages {
+            if let Err(err) = state.mail_transport.send(mail_message).await {
+                tracing::error!(user_id = ?user_id, error = %err, "Failed to send reward redemption notification");
+            }
+        }
+    });
+
+    Ok(Redirect::to(RewardRedemptionIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/redemptions")]
+pub struct RewardRedemptionIndexPath {
+    pub chore_list_id: ChoreListId,
+}
+
+pub async fn view_redemption_list(
+    _path: RewardRedemptionIndexPath,
+    ChoreList(chore_list): ChoreList,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    let (rewards, users, all_redemptions) = tokio::try_join!(
+        reward::get_all_for_chore_list(&state.pool, &chore_list.id),
+        user::get_all(&state.pool),
+        reward_redemption::get_all_for_chore_list(&state.pool, &chore_list.id),
+    ).unwrap();
+
+    let (redemptions, deleted_redemptions) = all_redemptions
+        .into_iter()
+        .partition(|redemption| !redemption.is_deleted());
+
+    Ok(template::page::chore_list::reward::list_redemptions(
+        chore_list,
+        redemptions,
+        deleted_redemptions,
+        rewards,
+        users,
+        auth_session,
+    ))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/chore-lists/{chore_list_id}/rewards/redemptions/{reward_redemption_id}/delete")]
+pub struct RewardRedemptionDeletePath {
+    pub chore_list_id: ChoreListId,
+    pub reward_redemption_id: RewardRedemptionId,
+}
+
+pub async fn delete_redemption(
+    _path: RewardRedemptionDeletePath,
+    ChoreList(chore_list): ChoreList,
+    RewardRedemption(mut reward_redemption): RewardRedemption,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if chore_list.is_deleted() || !reward_redemption.can_be_undone() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    if reward_redemption.user_id != auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let reward = reward::get_by_id(&state.pool, &reward_redemption.reward_id)
+        .await
+        .unwrap();
+    if reward.chore_list_id != chore_list.id {
+        return Err(StatusCode::NOT_FOUND);
+    }
+
+    reward_redemption.date_deleted = Some(DateTime::now());
+
+    reward_redemption::update(&state.pool, &reward_redemption).await.unwrap();
+
+    Ok(Redirect::to(RewardRedemptionIndexPath {
+        chore_list_id: chore_list.id,
+    }.to_string().as_str()))
+}
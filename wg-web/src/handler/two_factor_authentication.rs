@@ -0,0 +1,172 @@
+use std::sync::Arc;
+
+use axum::{
+    Form,
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use wg_core::model::user;
+use wg_core::model::user_recovery_code;
+use wg_core::service;
+use wg_core::value::TotpSecret;
+use crate::extractor::authentication::AuthSession;
+use crate::extractor::client_ip::ClientIp;
+use crate::template;
+use crate::AppState;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/two-factor")]
+pub struct TwoFactorAuthenticationPath;
+
+/// Shows a freshly generated secret for enrollment unless two-factor
+/// authentication is already enabled
+pub async fn view(
+    _path: TwoFactorAuthenticationPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Markup {
+    let user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    if user.has_two_factor_authentication() {
+        return template::page::two_factor_authentication::enabled();
+    }
+
+    let totp_secret = TotpSecret::generate();
+    let url = totp_secret.get_url(user.email.as_deref().unwrap_or(&user.name));
+
+    template::page::two_factor_authentication::enroll(totp_secret, &url)
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/two-factor/enable")]
+pub struct TwoFactorAuthenticationEnablePath;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct EnablePayload {
+    /// The secret shown during enrollment
+    secret: TotpSecret,
+    code: String,
+}
+
+pub async fn enable(
+    _path: TwoFactorAuthenticationEnablePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<EnablePayload>,
+) -> Result<Markup, StatusCode> {
+    let mut user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    if user.has_two_factor_authentication() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Makes sure the authenticator app has been set up correctly
+    let Some(step) = payload.secret.verify(&payload.code) else {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    };
+
+    user.totp_secret = Some(payload.secret);
+
+    user::update(&state.pool, &user).await.unwrap();
+
+    // The code used for enrollment must not work for the next login
+    user::update_totp_last_used_step(&state.pool, &user.id, step).await.unwrap();
+
+    let recovery_codes = service::user::regenerate_recovery_codes(&state.pool, &user).await;
+
+    Ok(template::page::two_factor_authentication::recovery_codes(recovery_codes))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/two-factor/recovery-codes")]
+pub struct TwoFactorAuthenticationRecoveryCodesPath;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct CodePayload {
+    code: String,
+}
+
+pub async fn regenerate_recovery_codes(
+    _path: TwoFactorAuthenticationRecoveryCodesPath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    ClientIp(client_ip): ClientIp,
+    Form(payload): Form<CodePayload>,
+) -> Result<Markup, StatusCode> {
+    let user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    if !user.has_two_factor_authentication() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Shares the throttle with the login, as the codes could otherwise be
+    // guessed here
+    let email = user.email.as_deref().unwrap_or_default();
+    if state.login_throttle.get_lockout(client_ip, email).is_some() {
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    if !service::user::verify_second_factor(&state.pool, &user, &payload.code).await {
+        state.login_throttle.record_failure(client_ip, email);
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    state.login_throttle.record_success(email);
+
+    let recovery_codes = service::user::regenerate_recovery_codes(&state.pool, &user).await;
+
+    Ok(template::page::two_factor_authentication::recovery_codes(recovery_codes))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/settings/two-factor/disable")]
+pub struct TwoFactorAuthenticationDisablePath;
+
+pub async fn disable(
+    _path: TwoFactorAuthenticationDisablePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    ClientIp(client_ip): ClientIp,
+    Form(payload): Form<CodePayload>,
+) -> Result<Redirect, StatusCode> {
+    let mut user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    if !user.has_two_factor_authentication() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Shares the throttle with the login, as the codes could otherwise be
+    // guessed here
+    let email = user.email.as_deref().unwrap_or_default();
+    if state.login_throttle.get_lockout(client_ip, email).is_some() {
+        return Err(StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    if !service::user::verify_second_factor(&state.pool, &user, &payload.code).await {
+        state.login_throttle.record_failure(client_ip, email);
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    state.login_throttle.record_success(email);
+
+    user.totp_secret = None;
+
+    user::update(&state.pool, &user).await.unwrap();
+
+    user_recovery_code::delete_all_for_user(&state.pool, &user.id)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(TwoFactorAuthenticationPath.to_string().as_str()))
+}
@@ -1,10 +1,12 @@
-use wg_core::model::user::{self, UserId};
+use wg_core::model::user::{self, Permission, UserId, UserKind, UserRole};
 use crate::extractor::authentication::AuthSession;
 use crate::extractor::model::User;
 use crate::template;
-use wg_core::value::{DateTime, PasswordHash};
+use wg_core::value::{DateTime, PasswordHash, TokenHash};
 use crate::AppState;
 use wg_core::model::authentication_session;
+use wg_core::service;
+use wg_core::model::user_invitation::{self, UserInvitation, UserInvitationId};
 use axum::{
     Form,
     extract::State,
@@ -12,10 +14,14 @@ use axum::{
     response::Redirect,
 };
 use axum_extra::routing::TypedPath;
+use chrono::Days;
 use maud::Markup;
 use secrecy::{ExposeSecret, SecretString};
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
+use wg_mail::lettre::AsyncTransport;
+use super::authentication::generate_token;
 use super::settings::SettingsIndexPath;
+use super::user_invitation::UserInvitationAcceptPath;
 
 #[derive(TypedPath, serde::Deserialize)]
 #[typed_path("/users")]
@@ -24,15 +30,25 @@ pub struct UserIndexPath;
 pub async fn view_list(
     _path: UserIndexPath,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Markup {
+    let can_manage_users = service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await;
+
     let (users, deleted_users) = user::get_all(&state.pool)
         .await
         .unwrap()
         .into_iter()
         .partition(|user| !user.is_deleted());
 
-    template::page::user::list(users, deleted_users)
+    let invitations = if can_manage_users {
+        user_invitation::get_all_pending(&state.pool)
+            .await
+            .unwrap()
+    } else {
+        Vec::new()
+    };
+
+    template::page::user::list(users, deleted_users, invitations, can_manage_users)
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -44,9 +60,14 @@ pub struct UserDetailPath {
 pub async fn view_detail(
     _path: UserDetailPath,
     User(user): User,
-    AuthSession(_auth_session): AuthSession,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Markup, StatusCode> {
-    Ok(template::page::user::detail(user))
+    let current_user = user::get_by_id(&state.pool, &auth_session.user_id)
+        .await
+        .unwrap();
+
+    Ok(template::page::user::detail(user, current_user))
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -55,39 +76,111 @@ pub struct UserCreatePath;
 
 pub async fn view_create_form(
     _path: UserCreatePath,
-    AuthSession(_auth_session): AuthSession,
-) -> Markup {
-    template::page::user::create()
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Markup, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(template::page::user::create())
 }
 
 #[derive(serde::Deserialize, Debug)]
 pub struct CreatePayload {
     name: String,
+    kind: UserKind,
+    /// Only used for members
+    #[serde(default)]
     email: String,
-    password: SecretString,
 }
 
+/// Members are invited by email and set their password themselves, while
+/// non-scoring participants are created right away
 pub async fn create(
     _path: UserCreatePath,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
     Form(payload): Form<CreatePayload>,
-) -> Redirect {
-    let user = user::User {
-        id: UserId::new(),
-        name: payload.name,
-        email: payload.email,
-        password_hash: PasswordHash::from_plain_password(payload.password),
-        last_used_language: None,
-        date_created: DateTime::now(),
-        date_deleted: None,
-    };
-
-    user::create(&state.pool, &user).await.unwrap();
+) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
 
-    Redirect::to(UserDetailPath {
-        user_id: user.id,
-    }.to_string().as_str())
+    match payload.kind {
+        UserKind::Member => {
+            let email = payload.email.trim();
+            // The invitation mail couldn't be sent to a malformed address
+            if wg_mail::lettre::Address::from_str(email).is_err() {
+                return Err(StatusCode::UNPROCESSABLE_ENTITY);
+            }
+
+            match user::get_by_email(&state.pool, email).await {
+                Ok(_) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+                Err(wg_core::db::sqlx::Error::RowNotFound) => {},
+                Err(err) => panic!("{}", err),
+            }
+
+            let inviting_user = user::get_by_id(&state.pool, &auth_session.user_id)
+                .await
+                .unwrap();
+
+            let token = generate_token();
+            let invitation = UserInvitation {
+                id: UserInvitationId::new(),
+                token_hash: TokenHash::from_token(&token),
+                name: payload.name,
+                email: email.to_string(),
+                invited_by_user_id: inviting_user.id,
+                date_expires: DateTime::from(*DateTime::now().as_ref() + Days::new(7)),
+                date_created: DateTime::now(),
+            };
+
+            user_invitation::delete_all_for_email(&state.pool, &invitation.email)
+                .await
+                .unwrap();
+
+            user_invitation::delete_all_expired(&state.pool)
+                .await
+                .unwrap();
+
+            user_invitation::create(&state.pool, &invitation)
+                .await
+                .unwrap();
+
+            // Sending the mail takes a while, which shouldn't hold up the
+            // response
+            tokio::spawn(async move {
+                let url = format!("{}{}", state.base_url, UserInvitationAcceptPath { token });
+                let mail_message = wg_mail::message::user_invitation(&invitation, &inviting_user, &url);
+                if let Err(err) = state.mail_transport.send(mail_message).await {
+                    tracing::error!(user_invitation_id = ?invitation.id, error = %err, "Failed to send user invitation");
+                }
+            });
+
+            Ok(Redirect::to(UserIndexPath.to_string().as_str()))
+        }
+        UserKind::NonScoring => {
+            let user = user::User {
+                id: UserId::new(),
+                name: payload.name,
+                email: None,
+                password_hash: None,
+                kind: payload.kind,
+                role: UserRole::Member,
+                totp_secret: None,
+                last_used_language: None,
+                date_created: DateTime::now(),
+                date_deleted: None,
+            };
+
+            user::create(&state.pool, &user).await.unwrap();
+
+            Ok(Redirect::to(UserDetailPath {
+                user_id: user.id,
+            }.to_string().as_str()))
+        }
+    }
 }
 
 #[derive(TypedPath, serde::Deserialize)]
@@ -135,10 +228,10 @@ pub async fn update(
     }
 
     user.name = payload.name;
-    user.email = payload.email;
+    user.email = Some(payload.email);
 
     if !payload.password.expose_secret().trim().is_empty() {
-        user.password_hash = PasswordHash::from_plain_password(payload.password);
+        user.password_hash = Some(PasswordHash::from_plain_password(payload.password));
     }
 
     user::update(&state.pool, &user).await.unwrap();
@@ -156,12 +249,21 @@ pub async fn delete(
     _path: UserDeletePath,
     User(mut user): User,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     if user.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
 
+    // Otherwise the last admin could lock everybody out
+    if user.id == auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     user.date_deleted = Some(DateTime::now());
 
     user::update(&state.pool, &user).await.unwrap();
@@ -175,6 +277,10 @@ pub async fn delete(
         .map(|auth_session| authentication_session::delete(&state.pool, auth_session));
     futures::future::join_all(auth_session_deletions).await;
 
+    service::chore::update_points_from_votes_of_user(&user.id, &state.pool)
+        .await
+        .unwrap();
+
     Ok(Redirect::to(UserDetailPath {
         user_id: user.id,
     }.to_string().as_str()))
@@ -190,8 +296,12 @@ pub async fn restore(
     _path: UserRestorePath,
     User(mut user): User,
     State(state): State<Arc<AppState>>,
-    AuthSession(_auth_session): AuthSession,
+    AuthSession(auth_session): AuthSession,
 ) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
     if !user.is_deleted() {
         return Err(StatusCode::FORBIDDEN);
     }
@@ -200,6 +310,50 @@ pub async fn restore(
 
     user::update(&state.pool, &user).await.unwrap();
 
+    service::chore::update_points_from_votes_of_user(&user.id, &state.pool)
+        .await
+        .unwrap();
+
+    Ok(Redirect::to(UserDetailPath {
+        user_id: user.id,
+    }.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/users/{user_id}/role")]
+pub struct UserRoleUpdatePath {
+    pub user_id: UserId,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct UpdateRolePayload {
+    role: UserRole,
+}
+
+pub async fn update_role(
+    _path: UserRoleUpdatePath,
+    User(mut user): User,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+    Form(payload): Form<UpdateRolePayload>,
+) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    if user.is_deleted() || !user.is_scoring() {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    // Otherwise the last admin could lock everybody out
+    if user.id == auth_session.user_id {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    user.role = payload.role;
+
+    user::update(&state.pool, &user).await.unwrap();
+
     Ok(Redirect::to(UserDetailPath {
         user_id: user.id,
     }.to_string().as_str()))
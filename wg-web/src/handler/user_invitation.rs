@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use axum::{
+    Form,
+    extract::State,
+    http::StatusCode,
+    response::Redirect,
+};
+use axum_extra::routing::TypedPath;
+use maud::Markup;
+use secrecy::{ExposeSecret, SecretString};
+use wg_core::model::user::{self, Permission, UserId, UserKind, UserRole};
+use wg_core::service;
+use wg_core::model::user_invitation::{self, UserInvitation, UserInvitationId};
+use wg_core::value::{DateTime, PasswordHash};
+use crate::extractor::authentication::AuthSession;
+use crate::template;
+use crate::AppState;
+use super::authentication::LoginPath;
+use super::user::UserIndexPath;
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/users/invitations/{user_invitation_id}/revoke")]
+pub struct UserInvitationRevokePath {
+    pub user_invitation_id: UserInvitationId,
+}
+
+pub async fn revoke(
+    path: UserInvitationRevokePath,
+    State(state): State<Arc<AppState>>,
+    AuthSession(auth_session): AuthSession,
+) -> Result<Redirect, StatusCode> {
+    if !service::user::has_permission(&state.pool, &auth_session.user_id, Permission::ManageUsers).await {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    let invitation = match user_invitation::get_by_id(&state.pool, &path.user_invitation_id).await {
+        Ok(invitation) => invitation,
+        Err(wg_core::db::sqlx::Error::RowNotFound) => return Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    };
+
+    user_invitation::delete(&state.pool, &invitation).await.unwrap();
+
+    Ok(Redirect::to(UserIndexPath.to_string().as_str()))
+}
+
+#[derive(TypedPath, serde::Deserialize)]
+#[typed_path("/accept-invitation/{token}")]
+pub struct UserInvitationAcceptPath {
+    pub token: String,
+}
+
+pub async fn view_accept_form(
+    path: UserInvitationAcceptPath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Markup, StatusCode> {
+    let invitation = get_pending_invitation(&state, &path.token).await?;
+
+    Ok(template::page::user_invitation::accept(invitation))
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct AcceptPayload {
+    password: SecretString,
+}
+
+pub async fn accept(
+    path: UserInvitationAcceptPath,
+    State(state): State<Arc<AppState>>,
+    Form(payload): Form<AcceptPayload>,
+) -> Result<Redirect, StatusCode> {
+    let invitation = get_pending_invitation(&state, &path.token).await?;
+
+    if payload.password.expose_secret().trim().chars().count() < PasswordHash::MIN_LENGTH {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    // The email address might have been taken in the meantime
+    match user::get_by_email(&state.pool, &invitation.email).await {
+        Ok(_) => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+        Err(wg_core::db::sqlx::Error::RowNotFound) => {},
+        Err(err) => panic!("{}", err),
+    }
+
+    let user = user::User {
+        id: UserId::new(),
+        name: invitation.name.clone(),
+        email: Some(invitation.email.clone()),
+        password_hash: Some(PasswordHash::from_plain_password(payload.password)),
+        kind: UserKind::Member,
+        role: UserRole::Member,
+        totp_secret: None,
+        last_used_language: None,
+        date_created: DateTime::now(),
+        date_deleted: None,
+    };
+
+    let is_accepted = user_invitation::accept(&state.pool, &invitation, &user)
+        .await
+        .unwrap();
+    if !is_accepted {
+        return Err(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    Ok(Redirect::to(LoginPath.to_string().as_str()))
+}
+
+async fn get_pending_invitation(
+    state: &AppState,
+    token: &str,
+) -> Result<UserInvitation, StatusCode> {
+    match user_invitation::get_by_token(&state.pool, token).await {
+        Ok(invitation) if !invitation.is_expired() => Ok(invitation),
+        Ok(_) | Err(wg_core::db::sqlx::Error::RowNotFound) => Err(StatusCode::NOT_FOUND),
+        Err(err) => panic!("{}", err),
+    }
+}
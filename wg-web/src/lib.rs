@@ -14,7 +14,9 @@
 
 pub mod template;
 pub mod handler;
+pub mod csrf;
 pub mod extractor;
+pub mod login_throttle;
 
 use extractor::{theme::Theme};
 use axum::{
@@ -26,11 +28,11 @@ use axum::{
     response::{IntoResponse, Response},
     routing::get,
 };
-use axum_extra::routing::RouterExt;
+use axum_extra::{extract::CookieJar, routing::RouterExt};
 use tokio::{net::TcpListener, task_local};
 use tokio_util::sync::CancellationToken;
 use wg_core::{db::Pool, model::authentication_session::AuthenticationSession, value::Language};
-use std::{any::Any, sync::Arc};
+use std::{any::Any, net::SocketAddr, sync::Arc};
 use tower_http::{
     catch_panic::CatchPanicLayer,
     request_id,
@@ -41,7 +43,7 @@ use tracing::Instrument;
 use tracing::Level;
 use fluent_static::{message_bundle, MessageBundle};
 
-use crate::extractor::{authentication::AuthSession};
+use crate::extractor::{authentication::AuthSession, client_ip::ClientIp, user_agent::UserAgent};
 
 #[message_bundle(
     resources = [
@@ -56,10 +58,21 @@ task_local! {
     pub static LANGUAGE: Language;
     pub static TRANSLATIONS: Translations;
     pub static THEME: Theme;
+    pub static CSRF_TOKEN: String;
 }
 
 pub struct AppState {
     pub pool: Pool,
+    pub mail_transport: wg_mail::MailTransport,
+    /// Public URL of the instance without trailing slash, used for links in mails
+    pub base_url: String,
+    /// Whether users can request a login link by email instead of entering
+    /// their password
+    pub is_magic_link_login_enabled: bool,
+    /// Header set by a trusted reverse proxy that contains the IP address of
+    /// the client, e.g. `X-Forwarded-For`
+    pub trusted_proxy_header: Option<HeaderName>,
+    pub login_throttle: login_throttle::LoginThrottle,
 }
 
 pub fn make_router(state: AppState) -> Router {
@@ -73,11 +86,33 @@ pub fn make_router(state: AppState) -> Router {
         .typed_get(handler::authentication::view_login_form)
         .typed_post(handler::authentication::login)
         .typed_post(handler::authentication::logout)
+        .typed_post(handler::authentication::request_login_link)
+        .typed_get(handler::authentication::view_login_link_form)
+        .typed_post(handler::authentication::login_with_link)
+        .typed_get(handler::authentication::view_second_factor_form)
+        .typed_post(handler::authentication::verify_second_factor)
+        .typed_post(handler::authentication::create_passkey_challenge)
+        .typed_post(handler::authentication::login_with_passkey)
+        .typed_get(handler::authentication::view_forgot_password_form)
+        .typed_post(handler::authentication::request_password_reset)
+        .typed_get(handler::authentication::view_reset_password_form)
+        .typed_post(handler::authentication::reset_password)
 
         // Settings
         .typed_get(handler::settings::view)
         .typed_get(handler::settings::view_appearance_form)
         .typed_post(handler::settings::update_appearance)
+        .typed_get(handler::two_factor_authentication::view)
+        .typed_post(handler::two_factor_authentication::enable)
+        .typed_post(handler::two_factor_authentication::regenerate_recovery_codes)
+        .typed_post(handler::two_factor_authentication::disable)
+        .typed_get(handler::passkey::view_list)
+        .typed_post(handler::passkey::create)
+        .typed_post(handler::passkey::delete)
+        .typed_get(handler::authentication_session::view_list)
+        .typed_post(handler::authentication_session::revoke)
+        .typed_post(handler::authentication_session::revoke_others)
+        .typed_get(handler::login_attempt::view_list)
 
         // User
         .typed_get(handler::user::view_list)
@@ -88,6 +123,12 @@ pub fn make_router(state: AppState) -> Router {
         .typed_post(handler::user::update)
         .typed_post(handler::user::delete)
         .typed_post(handler::user::restore)
+        .typed_post(handler::user::update_role)
+
+        // User Invitation
+        .typed_post(handler::user_invitation::revoke)
+        .typed_get(handler::user_invitation::view_accept_form)
+        .typed_post(handler::user_invitation::accept)
 
         // Absence
         .typed_get(handler::absence::view_list)
@@ -98,6 +139,8 @@ pub fn make_router(state: AppState) -> Router {
         .typed_post(handler::absence::update)
         .typed_post(handler::absence::delete)
         .typed_post(handler::absence::restore)
+        .typed_get(handler::absence::view_handover)
+        .typed_post(handler::absence::offer_handover)
 
         // Chore List
         .typed_get(handler::chore_list::view_list)
@@ -113,15 +156,22 @@ pub fn make_router(state: AppState) -> Router {
         .typed_get(handler::chore::view_list)
         .typed_get(handler::chore::view_create_form)
         .typed_post(handler::chore::create)
+        .typed_get(handler::chore::view_create_task_form)
+        .typed_post(handler::chore::create_task)
         .typed_get(handler::chore::view_detail)
         .typed_get(handler::chore::view_update_form)
         .typed_post(handler::chore::update)
         .typed_post(handler::chore::delete)
         .typed_post(handler::chore::restore)
+        .typed_post(handler::chore::vote)
+        .typed_post(handler::chore::delete_vote)
         .typed_get(handler::chore::view_activity_list)
+        .typed_get(handler::chore::view_postpone_form)
+        .typed_post(handler::chore::postpone)
 
         // Chore Activity
         .typed_get(handler::chore_activity::view_list)
+        .typed_get(handler::chore_activity::view_cooldown_violation_list)
         .typed_get(handler::chore_activity::view_create_form)
         .typed_post(handler::chore_activity::create)
         .typed_get(handler::chore_activity::view_detail)
@@ -135,10 +185,32 @@ pub fn make_router(state: AppState) -> Router {
         .typed_get(handler::chore_list_user::view_detail)
         .typed_get(handler::chore_list_user::view_activity_list)
 
+        // Chore Swap Request
+        .typed_get(handler::chore_swap_request::view_list)
+        .typed_get(handler::chore_swap_request::view_create_form)
+        .typed_post(handler::chore_swap_request::create)
+        .typed_post(handler::chore_swap_request::accept)
+        .typed_post(handler::chore_swap_request::decline)
+        .typed_post(handler::chore_swap_request::cancel)
+
+        // Reward
+        .typed_get(handler::reward::view_list)
+        .typed_get(handler::reward::view_create_form)
+        .typed_post(handler::reward::create)
+        .typed_get(handler::reward::view_redemption_list)
+        .typed_post(handler::reward::delete_redemption)
+        .typed_get(handler::reward::view_detail)
+        .typed_get(handler::reward::view_update_form)
+        .typed_post(handler::reward::update)
+        .typed_post(handler::reward::delete)
+        .typed_post(handler::reward::restore)
+        .typed_post(handler::reward::redeem)
+
         // Legal
         .typed_get(handler::legal::view_privacy_policy)
 
         .fallback_service(get(handler::assets::serve))
+        .layer(axum::middleware::from_fn(csrf::protect))
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             async |State(state): State<Arc<AppState>>, mut request: axum::extract::Request, next: Next| -> Response {
@@ -160,6 +232,32 @@ pub fn make_router(state: AppState) -> Router {
                 next.run(request).await
             }),
         )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            async |State(state): State<Arc<AppState>>, mut request: axum::extract::Request, next: Next| -> Response {
+                // Only page views renew the session, so that a logout isn't
+                // undone by the renewed cookie
+                if !request.method().is_safe() {
+                    return next.run(request).await;
+                }
+
+                let mut auth_session = match request.extract_parts_with_state::<Option<AuthSession>, _>(&state).await.unwrap() {
+                    Some(AuthSession(auth_session)) => auth_session,
+                    None => return next.run(request).await,
+                };
+                let ClientIp(client_ip) = request.extract_parts_with_state::<ClientIp, _>(&state).await.unwrap();
+                let UserAgent(user_agent) = request.extract_parts::<UserAgent>().await.unwrap();
+
+                if !handler::authentication::renew_session(&state, &mut auth_session, client_ip, user_agent).await {
+                    return next.run(request).await;
+                }
+
+                let cookie_jar = CookieJar::new().add(handler::authentication::make_session_cookie(&auth_session));
+                request.extensions_mut().insert(auth_session);
+
+                (cookie_jar, next.run(request).await).into_response()
+            }),
+        )
         .layer(axum::middleware::from_fn(async |request: axum::extract::Request, next: Next| -> Response {
             let request_id = request.headers().get("x-request-id")
                 .map(|v| v.to_str().unwrap().to_string());
@@ -211,7 +309,7 @@ pub fn make_router(state: AppState) -> Router {
         ))
         .layer(SetResponseHeaderLayer::if_not_present(
             header::CONTENT_SECURITY_POLICY,
-            HeaderValue::from_static("default-src 'none'; style-src 'unsafe-inline' 'self'; img-src data: 'self'; script-src 'self'; frame-ancestors 'none'; form-action 'self'; manifest-src 'self';"),
+            HeaderValue::from_static("default-src 'none'; style-src 'unsafe-inline' 'self'; img-src data: 'self'; script-src 'self'; connect-src 'self'; frame-ancestors 'none'; form-action 'self'; manifest-src 'self';"),
         ))
         .layer(SetResponseHeaderLayer::if_not_present(
             header::X_FRAME_OPTIONS,
@@ -286,7 +384,7 @@ pub fn make_router(state: AppState) -> Router {
 }
 
 pub async fn start(listener: TcpListener, router: Router, cancel_token: CancellationToken) {
-    axum::serve(listener, router)
+    axum::serve(listener, router.into_make_service_with_connect_info::<SocketAddr>())
         .with_graceful_shutdown(async move { cancel_token.cancelled().await })
         .await
         .unwrap();
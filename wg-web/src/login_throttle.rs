@@ -0,0 +1,134 @@
+use std::{collections::HashMap, net::IpAddr, sync::Mutex, time::{Duration, Instant}};
+
+/// Failed attempts after which an account is locked out
+const ACCOUNT_FREE_ATTEMPTS: u32 = 5;
+/// Failed attempts after which an IP address is locked out. Higher than for
+/// accounts, as several people might share an address.
+const IP_FREE_ATTEMPTS: u32 = 20;
+const MAX_LOCKOUT: Duration = Duration::from_secs(15 * 60);
+/// Failed attempts are forgotten after this long without another failure
+const RESET_AFTER: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+enum ThrottleKey {
+    Ip(IpAddr),
+    Account(String),
+}
+
+struct FailedAttempts {
+    count: u32,
+    last_failed_at: Instant,
+}
+
+/// Tracks failed logins per IP address and per account in memory. Once the
+/// free attempts are used up, the lockout doubles with every further failure.
+#[derive(Default)]
+pub struct LoginThrottle {
+    failed_attempts: Mutex<HashMap<ThrottleKey, FailedAttempts>>,
+}
+
+impl LoginThrottle {
+    /// Returns how long the client has to wait before the next attempt
+    pub fn get_lockout(&self, ip: IpAddr, email: &str) -> Option<Duration> {
+        let failed_attempts = self.failed_attempts.lock().unwrap();
+
+        [ThrottleKey::Ip(ip), ThrottleKey::Account(normalize_email(email))]
+            .iter()
+            .filter_map(|key| {
+                let attempts = failed_attempts.get(key)?;
+                let lockout = get_lockout_duration(key, attempts.count)?;
+
+                lockout.checked_sub(attempts.last_failed_at.elapsed())
+                    .filter(|remaining| !remaining.is_zero())
+            })
+            .max()
+    }
+
+    pub fn record_failure(&self, ip: IpAddr, email: &str) {
+        let mut failed_attempts = self.failed_attempts.lock().unwrap();
+
+        failed_attempts.retain(|_key, attempts| attempts.last_failed_at.elapsed() < RESET_AFTER);
+
+        for key in [ThrottleKey::Ip(ip), ThrottleKey::Account(normalize_email(email))] {
+            let attempts = failed_attempts.entry(key.clone())
+                .or_insert(FailedAttempts { count: 0, last_failed_at: Instant::now() });
+            attempts.count += 1;
+            attempts.last_failed_at = Instant::now();
+
+            if let Some(lockout) = get_lockout_duration(&key, attempts.count) {
+                tracing::warn!(key = ?key, failed_attempts = attempts.count, lockout_secs = lockout.as_secs(), "Locking out login attempts");
+            }
+        }
+    }
+
+    /// Only the account is reset, as other accounts might have been tried
+    /// from the same address
+    pub fn record_success(&self, email: &str) {
+        self.failed_attempts.lock().unwrap()
+            .remove(&ThrottleKey::Account(normalize_email(email)));
+    }
+}
+
+fn get_lockout_duration(key: &ThrottleKey, failed_attempts: u32) -> Option<Duration> {
+    let free_attempts = match key {
+        ThrottleKey::Ip(_) => IP_FREE_ATTEMPTS,
+        ThrottleKey::Account(_) => ACCOUNT_FREE_ATTEMPTS,
+    };
+
+    let exceeded_attempts = failed_attempts.checked_sub(free_attempts)?;
+    let lockout = Duration::from_secs(1)
+        .checked_mul(2u32.saturating_pow(exceeded_attempts))
+        .unwrap_or(MAX_LOCKOUT);
+
+    Some(lockout.min(MAX_LOCKOUT))
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::{IpAddr, Ipv4Addr}, time::Duration};
+    use super::{LoginThrottle, ThrottleKey, MAX_LOCKOUT};
+
+    const IP: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn account_is_locked_out_after_free_attempts() {
+        let login_throttle = LoginThrottle::default();
+
+        for _ in 0..4 {
+            login_throttle.record_failure(IP, "alice@example.com");
+        }
+        assert_eq!(login_throttle.get_lockout(IP, "alice@example.com"), None);
+
+        login_throttle.record_failure(IP, "alice@example.com");
+        assert!(login_throttle.get_lockout(IP, " Alice@Example.com ").is_some());
+        assert_eq!(login_throttle.get_lockout(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), "bob@example.com"), None);
+    }
+
+    #[test]
+    fn success_only_resets_the_account() {
+        let login_throttle = LoginThrottle::default();
+
+        for _ in 0..20 {
+            login_throttle.record_failure(IP, "alice@example.com");
+        }
+        login_throttle.record_success("alice@example.com");
+
+        assert!(login_throttle.get_lockout(IP, "alice@example.com").is_some());
+        assert!(login_throttle.get_lockout(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)), "alice@example.com").is_none());
+    }
+
+    #[test]
+    fn lockout_doubles_up_to_maximum() {
+        let account = ThrottleKey::Account("alice@example.com".to_string());
+
+        assert_eq!(super::get_lockout_duration(&account, 4), None);
+        assert_eq!(super::get_lockout_duration(&account, 5), Some(Duration::from_secs(1)));
+        assert_eq!(super::get_lockout_duration(&account, 7), Some(Duration::from_secs(4)));
+        assert_eq!(super::get_lockout_duration(&account, 100), Some(MAX_LOCKOUT));
+        assert_eq!(super::get_lockout_duration(&ThrottleKey::Ip(IP), 7), None);
+    }
+}
@@ -3,12 +3,20 @@ use icu_calendar::Gregorian;
 use icu_datetime::{fieldsets, FixedCalendarDateTimeFormatter};
 use icu_locale_core::Locale;
 use wg_core::value::Date;
-use crate::{Translations, LANGUAGE, TRANSLATIONS};
+use maud::{html, Markup};
+use crate::{csrf, Translations, CSRF_TOKEN, LANGUAGE, TRANSLATIONS};
 
 pub fn t() -> Translations {
     TRANSLATIONS.get()
 }
 
+/// Has to be part of every form that is posted
+pub fn csrf_token_input() -> Markup {
+    CSRF_TOKEN.with(|csrf_token| html! {
+        input type="hidden" name=(csrf::FIELD_NAME) value=(csrf_token);
+    })
+}
+
 fn date_to_icu_date(date: Date) -> icu_datetime::input::Date<Gregorian> {
     let chrono_date: chrono::NaiveDate = date.into();
 
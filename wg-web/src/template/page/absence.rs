@@ -2,18 +2,26 @@ use maud::{html, Markup};
 use crate::handler::absence::AbsenceCreatePath;
 use crate::handler::absence::AbsenceDeletePath;
 use crate::handler::absence::AbsenceDetailPath;
+use crate::handler::absence::AbsenceHandoverOfferPath;
+use crate::handler::absence::AbsenceHandoverPath;
 use crate::handler::absence::AbsenceIndexPath;
 use crate::handler::absence::AbsenceRestorePath;
 use crate::handler::absence::AbsenceUpdatePath;
+use crate::handler::chore::ChoreDetailPath;
 use crate::handler::user::UserDetailPath;
 use crate::template::partial::navigation::GlobalNavigationItem;
 use wg_core::model::authentication_session::AuthenticationSession;
 use wg_core::model::absence;
+use wg_core::model::chore;
+use wg_core::model::chore_list;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_swap_request::ChoreSwapRequestStatus;
 use wg_core::model::user;
 use wg_core::value::Date;
 use wg_core::value::DateTime;
 use crate::template::helper::format_date_long;
 use crate::template::helper::format_date_long_simple;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
@@ -169,7 +177,7 @@ pub fn detail(
                 @if absence.is_deleted() {
                     @if allow_delete_restore {
                         button.link.secondary.subtle.mb-0 type="submit" form="absence_restore" { "↻ " (t().restore_action()) }
-                        form #absence_restore method="post" action=(AbsenceRestorePath { absence_id: absence.id }) { }
+                        form #absence_restore method="post" action=(AbsenceRestorePath { absence_id: absence.id }) { (csrf_token_input()) }
                     }
                 } @else if absence.user_id == auth_session.user_id {
                     @if allow_delete_restore {
@@ -181,7 +189,7 @@ pub fn detail(
                     }
 
                     @if allow_delete_restore {
-                        form #absence_delete method="post" action=(AbsenceDeletePath { absence_id: absence.id }) { }
+                        form #absence_delete method="post" action=(AbsenceDeletePath { absence_id: absence.id }) { (csrf_token_input()) }
                     }
                 }
             })
@@ -223,11 +231,90 @@ pub fn detail(
                 dt { (t().user()) }
                 dd { a.inherit.subtle href=(UserDetailPath { user_id: user.id }) { "👤 " (user.name) } }
 
-                @if let Some(comment) = absence.comment {
+                @if let Some(comment) = &absence.comment {
                     dt { (t().comment()) }
                     dd { (comment) }
                 }
             }
+
+            @if absence.user_id == auth_session.user_id && !absence.is_deleted() && !absence.is_in_past() {
+                br;
+
+                nav style="flex-direction: column;" {
+                    ul.card-container.collapse {
+                        li {
+                            a.card href=(AbsenceHandoverPath { absence_id: absence.id }) {
+                                div.title { "🔁 " (t().absence_handover()) }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn handover(
+    absence: absence::Absence,
+    due_chores: Vec<chore::Chore>,
+    chore_lists: Vec<chore_list::ChoreList>,
+    swap_requests: Vec<chore_swap_request::ChoreSwapRequest>,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔁")
+            .title(&t().absence_handover())
+            .back_url(AbsenceDetailPath { absence_id: absence.id }.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Absences)))
+            .build(),
+        html! {
+            p { (t().absence_handover_help_text()) }
+
+            @if due_chores.is_empty() {
+                p.text-muted { (t().absence_no_due_chores()) }
+            }
+
+            ul.card-container.collapse {
+                @for chore in due_chores.iter() {
+                    @let chore_list = chore_lists.iter().find(|chore_list| chore_list.id == chore.chore_list_id).unwrap();
+                    @let swap_request = swap_requests.iter()
+                        .filter(|swap_request| swap_request.chore_id == chore.id)
+                        .max_by_key(|swap_request| swap_request.date_created);
+
+                    li {
+                        div.card {
+                            div.title {
+                                a href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) { (chore.name) }
+                            }
+
+                            small.text-muted {
+                                "📋 " (chore_list.name)
+
+                                @if let Some(next_due_date) = chore.next_due_date {
+                                    " – " time datetime=(next_due_date.format("%Y-%m-%d")) title=(next_due_date.format("%Y-%m-%d")) {
+                                        (format_date_long(next_due_date))
+                                    }
+                                }
+                            }
+
+                            div {
+                                @match swap_request.map(|swap_request| swap_request.status) {
+                                    Some(ChoreSwapRequestStatus::Pending) => small { (t().absence_handover_offered()) },
+                                    Some(ChoreSwapRequestStatus::Accepted) | Some(ChoreSwapRequestStatus::Completed) => small { (t().absence_handover_taken_over()) },
+                                    _ => {
+                                        form method="post" action=(AbsenceHandoverOfferPath { absence_id: absence.id, chore_id: chore.id }) {
+                                            (csrf_token_input())
+                                            button.link.secondary.subtle.mb-0 type="submit" { "🔁 " (t().absence_handover_offer_action()) }
+                                        }
+                                    },
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            a href=(AbsenceIndexPath) { (t().done_action()) }
         },
     )
 }
@@ -245,6 +332,7 @@ pub fn create(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="date_start" { (t().absence_start_date()) }
                 input #date_start name="date_start" type="date" min=(min_date.format("%Y-%m-%d")) value=(now.format("%Y-%m-%d")) required;
 
@@ -281,6 +369,7 @@ pub fn update(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="date_start" { (t().absence_start_date()) }
                 input #date_start name="date_start" type="date" min=(min_start_date.format("%Y-%m-%d")) value=(absence.date_start.format("%Y-%m-%d")) required;
 
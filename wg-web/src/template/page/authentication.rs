@@ -1,14 +1,22 @@
 use maud::{html, Markup};
+use crate::handler::authentication::ForgotPasswordPath;
+use crate::handler::authentication::LoginLinkRequestPath;
+use crate::handler::authentication::LoginPasskeyChallengePath;
+use crate::handler::authentication::LoginPasskeyPath;
+use crate::handler::authentication::LoginPath;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
+use wg_core::value::PasswordHash;
 
-pub fn login() -> Markup {
+pub fn login(is_magic_link_login_enabled: bool) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
             .title(&t().login())
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="email" { (t().email_address()) }
                 input #email name="email" type="email" required autocomplete="email" autofocus;
 
@@ -17,6 +25,131 @@ pub fn login() -> Markup {
 
                 button type="submit" { (t().login_action()) }
             }
+
+            // Only shown by the script if the browser supports passkeys
+            form method="post" action=(LoginPasskeyPath) hidden
+                data-passkey-login
+                data-challenge-url=(LoginPasskeyChallengePath)
+            {
+                (csrf_token_input())
+                input type="hidden" name="credential_id";
+                input type="hidden" name="client_data_json";
+                input type="hidden" name="authenticator_data";
+                input type="hidden" name="signature";
+
+                button.secondary type="submit" { "🔑 " (t().login_with_passkey_action()) }
+            }
+
+            p {
+                a href=(ForgotPasswordPath) { (t().forgot_password()) }
+            }
+
+            @if is_magic_link_login_enabled {
+                hr;
+
+                form method="post" action=(LoginLinkRequestPath) {
+                    (csrf_token_input())
+                    label for="login_link_email" { (t().email_address()) }
+                    input #login_link_email name="email" type="email" required autocomplete="email" aria-describedby="login-link-help-text";
+                    small #login-link-help-text { (t().login_link_help_text()) }
+
+                    button.secondary type="submit" { (t().send_login_link_action()) }
+                }
+            }
+        },
+    )
+}
+
+pub fn login_link_requested() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().login())
+            .back_url(LoginPath.to_string().as_str())
+            .build(),
+        html! {
+            p { (t().login_link_requested_text()) }
+        },
+    )
+}
+
+pub fn login_link() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().login())
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                button type="submit" { (t().login_action()) }
+            }
+        },
+    )
+}
+
+pub fn forgot_password() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().forgot_password())
+            .back_url(LoginPath.to_string().as_str())
+            .build(),
+        html! {
+            p { (t().forgot_password_help_text()) }
+
+            form method="post" {
+                (csrf_token_input())
+                label for="email" { (t().email_address()) }
+                input #email name="email" type="email" required autocomplete="email" autofocus;
+
+                button type="submit" { (t().send_reset_link_action()) }
+            }
+        },
+    )
+}
+
+pub fn password_reset_requested() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().forgot_password())
+            .back_url(LoginPath.to_string().as_str())
+            .build(),
+        html! {
+            p { (t().password_reset_requested_text()) }
+        },
+    )
+}
+
+pub fn reset_password() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().reset_password())
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="password" { (t().new_password()) }
+                input #password name="password" type="password" required minlength=(PasswordHash::MIN_LENGTH) autocomplete="new-password" autofocus;
+
+                button type="submit" { (t().reset_password_action()) }
+            }
+        },
+    )
+}
+
+pub fn second_factor() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().two_factor_authentication())
+            .back_url(LoginPath.to_string().as_str())
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="code" { (t().two_factor_code()) }
+                input #code name="code" type="text" required autocomplete="one-time-code" autofocus aria-describedby="code-help-text";
+                small #code-help-text { (t().two_factor_code_help_text()) }
+
+                button type="submit" { (t().login_action()) }
+            }
         },
     )
 }
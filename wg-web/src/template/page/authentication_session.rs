@@ -0,0 +1,68 @@
+use maud::{html, Markup};
+use wg_core::model::authentication_session::AuthenticationSession;
+use crate::handler::authentication_session::AuthenticationSessionRevokeOthersPath;
+use crate::handler::authentication_session::AuthenticationSessionRevokePath;
+use crate::handler::settings::SettingsIndexPath;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::GlobalNavigationItem;
+
+pub fn list(
+    current_auth_session: AuthenticationSession,
+    auth_sessions: Vec<AuthenticationSession>,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("💻")
+            .display_emoji(false)
+            .title(&t().sessions())
+            .back_url(SettingsIndexPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().sessions_help_text()) }
+
+            ul.card-container.collapse {
+                @for auth_session in auth_sessions.iter() {
+                    li {
+                        div.card {
+                            div.title {
+                                (auth_session.user_agent.as_deref().unwrap_or(&t().unknown_device()))
+
+                                @if auth_session.id == current_auth_session.id {
+                                    " "
+                                    small.text-muted { "(" (t().current_session()) ")" }
+                                }
+                            }
+
+                            small.text-muted {
+                                (t().session_created_on(auth_session.date_created.format("%Y-%m-%d %H:%M")))
+                                " – " (t().session_last_used_on(auth_session.date_last_used.format("%Y-%m-%d %H:%M")))
+
+                                @if let Some(ip_address) = &auth_session.ip_address {
+                                    " – " (t().session_ip_address(ip_address))
+                                }
+                            }
+
+                            @if auth_session.id != current_auth_session.id {
+                                form method="post" action=(AuthenticationSessionRevokePath { authentication_session_id: auth_session.id }) {
+                                    (csrf_token_input())
+                                    button.link.secondary.subtle.mb-0 type="submit" { "✗ " (t().revoke_action()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if auth_sessions.iter().any(|auth_session| auth_session.id != current_auth_session.id) {
+                form method="post" action=(AuthenticationSessionRevokeOthersPath) {
+                    (csrf_token_input())
+                    button.secondary type="submit" { (t().revoke_other_sessions_action()) }
+                }
+            }
+        },
+    )
+}
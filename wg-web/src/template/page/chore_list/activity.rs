@@ -7,16 +7,21 @@ use crate::handler::chore_activity::ChoreActivityIndexPath;
 use crate::handler::chore_activity::ChoreActivityRestorePath;
 use crate::handler::chore_activity::ChoreActivityUpdatePath;
 use crate::handler::chore_list::ChoreListIndexPath;
+use crate::handler::chore_list::ChoreListSettingsPath;
 use crate::handler::chore_list_user::ChoreListUserDetailPath;
 use wg_core::model::authentication_session::AuthenticationSession;
 use wg_core::model::chore_list;
 use wg_core::model::chore_activity;
+use wg_core::model::chore_activity_checklist_item;
+use wg_core::model::chore_checklist_item;
+use wg_core::model::chore_checklist_item::ChoreChecklistItemId;
 use wg_core::model::chore;
 use wg_core::model::user;
 use wg_core::value::Date;
 use wg_core::value::DateTime;
 use crate::template::helper::format_date_long;
 use crate::template::helper::format_date_long_simple;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
@@ -60,10 +65,22 @@ pub fn list(
                                     div.title { (chore.name) }
 
                                     small.text-muted {
-                                        (t().points_value_short(chore.points))
+                                        (t().points_value_short(activity.total_points()))
+
+                                        @if activity.quantity > 1 {
+                                            " (" (t().quantity_value_short(activity.quantity)) ")"
+                                        }
 
                                         " – " (user.name)
 
+                                        @if let Some(time) = activity.time {
+                                            " – " time datetime=(time.format("%H:%M")) { (time) }
+                                        }
+
+                                        @if activity.is_cooldown_violation {
+                                            " – " (t().cooldown_violation_hint())
+                                        }
+
                                         @if activity.comment.is_some() {
                                             " – " (t().has_comment())
                                         }
@@ -90,7 +107,11 @@ pub fn list(
                                     div.title { (chore.name) }
 
                                     small.text-muted {
-                                        (t().points_value_short(chore.points))
+                                        (t().points_value_short(activity.total_points()))
+
+                                        @if activity.quantity > 1 {
+                                            " (" (t().quantity_value_short(activity.quantity)) ")"
+                                        }
 
                                         " – " (user.name)
 
@@ -98,6 +119,14 @@ pub fn list(
                                             (format_date_long(activity.date))
                                         }
 
+                                        @if let Some(time) = activity.time {
+                                            ", " time datetime=(time.format("%H:%M")) { (time) }
+                                        }
+
+                                        @if activity.is_cooldown_violation {
+                                            " – " (t().cooldown_violation_hint())
+                                        }
+
                                         @if activity.comment.is_some() {
                                             " – " (t().has_comment())
                                         }
@@ -115,9 +144,9 @@ pub fn list(
 pub fn detail(
     activity: chore_activity::ChoreActivity,
     chore: chore::Chore,
+    checklist_items: Vec<chore_activity_checklist_item::ChoreActivityChecklistItem>,
     chore_list: chore_list::ChoreList,
     user: user::User,
-    auth_session: AuthenticationSession,
     allow_edit: bool,
     allow_delete_restore: bool,
 ) -> Markup {
@@ -131,9 +160,9 @@ pub fn detail(
                 @if activity.is_deleted() {
                     @if allow_delete_restore {
                         button.link.secondary.subtle.mb-0 type="submit" form="activity_restore" { "↻ " (t().restore_action()) }
-                        form #activity_restore method="post" action=(ChoreActivityRestorePath {chore_list_id:chore_list.id, chore_activity_id: activity.id }) { }
+                        form #activity_restore method="post" action=(ChoreActivityRestorePath {chore_list_id:chore_list.id, chore_activity_id: activity.id }) { (csrf_token_input()) }
                     }
-                } @else if !chore.is_deleted() && !chore_list.is_deleted() && activity.user_id == auth_session.user_id {
+                } @else if !chore.is_deleted() && !chore_list.is_deleted() {
                     @if allow_delete_restore {
                         button.link.secondary.subtle.mb-0 type="submit" form="activity_delete" { "✗ " (t().delete_action()) }
                     }
@@ -143,7 +172,7 @@ pub fn detail(
                     }
 
                     @if allow_delete_restore {
-                        form #activity_delete method="post" action=(ChoreActivityDeletePath {chore_list_id:chore_list.id, chore_activity_id: activity.id }) { }
+                        form #activity_delete method="post" action=(ChoreActivityDeletePath {chore_list_id:chore_list.id, chore_activity_id: activity.id }) { (csrf_token_input()) }
                     }
                 }
             })
@@ -164,6 +193,10 @@ pub fn detail(
                     time datetime=(activity.date.format("%Y-%m-%d")) title=(activity.date.format("%Y-%m-%d")) {
                         (format_date_long(activity.date))
                     }
+
+                    @if let Some(time) = activity.time {
+                        ", " time datetime=(time.format("%H:%M")) { (time) }
+                    }
                 }
 
                 dt { (t().user()) }
@@ -172,10 +205,39 @@ pub fn detail(
                 dt { (t().chore()) }
                 dd {
                     a.inherit.subtle href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) {
-                        "🧹 " (chore.name) " (" (chore.points) "P)"
+                        "🧹 " (chore.name) " (" (activity.total_points()) "P)"
                     }
                 }
 
+                @if chore.is_quantity_based() {
+                    dt { (t().quantity()) }
+                    dd { (t().quantity_times_points(activity.quantity, activity.points)) }
+                }
+
+                @if activity.bounty_points > 0 {
+                    dt { (t().bounty()) }
+                    dd { (t().points_value_short(activity.bounty_points)) }
+                }
+
+                @if !checklist_items.is_empty() {
+                    dt { (t().checklist()) }
+                    dd {
+                        ul.mb-0 style="list-style: none; padding-left: 0;" {
+                            @for checklist_item in checklist_items {
+                                li {
+                                    @if checklist_item.is_done { "✅ " } @else { "⬜ " }
+                                    (checklist_item.name)
+                                }
+                            }
+                        }
+                    }
+                }
+
+                @if activity.is_cooldown_violation {
+                    dt { (t().points()) }
+                    dd { (t().cooldown_violation_no_points()) }
+                }
+
                 @if let Some(comment) = activity.comment {
                     dt { (t().comment()) }
                     dd { (comment) }
@@ -185,9 +247,104 @@ pub fn detail(
     )
 }
 
+pub fn list_cooldown_violations(
+    chore_list: chore_list::ChoreList,
+    activities: Vec<chore_activity::ChoreActivity>,
+    chores: Vec<chore::Chore>,
+    users: Vec<user::User>,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("⏱️")
+            .title(&t().cooldown_violations())
+            .teaser(&t().of_x(format!("📋 {}", chore_list.name)))
+            .back_url(ChoreListSettingsPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Settings)))
+            .build(),
+        html! {
+            @if activities.is_empty() {
+                p.text-muted { (t().no_cooldown_violations()) }
+            }
+
+            ul.card-container.collapse {
+                @for activity in activities {
+                    @let chore = chores.iter().find(|chore| chore.id == activity.chore_id).unwrap();
+                    @let user = users.iter().find(|user| user.id == activity.user_id).unwrap();
+
+                    li {
+                        a.card href=(ChoreActivityDetailPath {chore_list_id: chore_list.id, chore_activity_id: activity.id }) {
+                            div.title { (chore.name) }
+
+                            small.text-muted {
+                                (user.name)
+
+                                " – " time datetime=(activity.date.format("%Y-%m-%d")) title=(activity.date.format("%Y-%m-%d")) {
+                                    (format_date_long(activity.date))
+                                }
+
+                                @if activity.is_deleted() {
+                                    " – " (t().deleted_hint())
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn confirm_cooldown_violation(
+    activity: chore_activity::ChoreActivity,
+    checklist_item_ids: Vec<ChoreChecklistItemId>,
+    conflicting_activity: chore_activity::ChoreActivity,
+    chore: chore::Chore,
+    chore_list: chore_list::ChoreList,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("⏱️")
+            .title(&t().cooldown_violation())
+            .back_url(ChoreActivityCreatePath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Activities)))
+            .build(),
+        html! {
+            p {
+                (t().cooldown_violation_warning(
+                    chore.name.clone(),
+                    chore.cooldown_days.unwrap_or_default(),
+                    format_date_long(conflicting_activity.date),
+                ))
+            }
+
+            form method="post" action=(ChoreActivityCreatePath { chore_list_id: chore_list.id }) {
+                (csrf_token_input())
+                input type="hidden" name="chore_id" value=(activity.chore_id);
+                input type="hidden" name="user_id" value=(activity.user_id);
+                input type="hidden" name="date" value=(activity.date.format("%Y-%m-%d"));
+                input type="hidden" name="time" value=[activity.time];
+                input type="hidden" name="quantity" value=(activity.quantity);
+                @for checklist_item_id in checklist_item_ids {
+                    input type="hidden" name="checklist_item_ids" value=(checklist_item_id);
+                }
+                input type="hidden" name="comment" value=(activity.comment.unwrap_or_default());
+                input type="hidden" name="confirm_cooldown_violation" value="true";
+
+                button type="submit" { (t().create_without_points_action()) }
+            }
+
+            a.secondary href=(ChoreActivityIndexPath { chore_list_id: chore_list.id }) { (t().cancel_action()) }
+        },
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn create(
     chore_list: chore_list::ChoreList,
     chores: Vec<chore::Chore>,
+    checklist_items: Vec<chore_checklist_item::ChoreChecklistItem>,
+    users: Vec<user::User>,
+    auth_session: AuthenticationSession,
     min_date: Date,
     max_date: Date,
     now: DateTime,
@@ -201,19 +358,46 @@ pub fn create(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="chore_id" { (t().chore()) }
-                select #chore_id name="chore_id" required {
+                select #chore_id name="chore_id" required data-checklist-select {
                     option selected disabled hidden value="" { }
-                    @for chore in chores {
-                        @if !chore.is_deleted() {
+                    @for chore in &chores {
+                        @if !chore.is_deleted() && !chore.is_completed() {
                             option value=(chore.id) { (chore.name) }
                         }
                     }
                 }
 
+                @if users.len() > 1 {
+                    label for="user_id" { (t().done_by()) }
+                    select #user_id name="user_id" required {
+                        @for user in &users {
+                            option value=(user.id) selected[user.id == auth_session.user_id] { (user.name) }
+                        }
+                    }
+                }
+
                 label for="date" { (t().date()) }
                 input #date name="date" type="date" min=(min_date.format("%Y-%m-%d")) max=(max_date.format("%Y-%m-%d")) value=(now.format("%Y-%m-%d")) required;
 
+                label for="time" {
+                    (t().time())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                input #time name="time" type="time" value=(now.format("%H:%M"));
+
+                label for="quantity" {
+                    (t().quantity())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                input #quantity name="quantity" type="number" min="1" step="1" aria-describedby="quantity-help-text";
+                small #quantity-help-text { (t().activity_quantity_help_text()) }
+
+                (checklists(&chores, &checklist_items, |_| false))
+
                 label for="comment" {
                     (t().comment())
                     " "
@@ -230,6 +414,8 @@ pub fn create(
 pub fn update(
     activity: chore_activity::ChoreActivity,
     chores: Vec<chore::Chore>,
+    checklist_items: Vec<chore_checklist_item::ChoreChecklistItem>,
+    activity_checklist_items: Vec<chore_activity_checklist_item::ChoreActivityChecklistItem>,
     chore_list: chore_list::ChoreList,
     min_date: Date,
     max_date: Date,
@@ -243,11 +429,12 @@ pub fn update(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="chore_id" { (t().chore()) }
-                select #chore_id name="chore_id" required {
+                select #chore_id name="chore_id" required data-checklist-select {
                     option disabled hidden value="" { }
-                    @for chore in chores {
-                        @if !chore.is_deleted() {
+                    @for chore in &chores {
+                        @if !chore.is_deleted() && (!chore.is_completed() || chore.id == activity.chore_id) {
                             option value=(chore.id) selected[chore.id == activity.chore_id] { (chore.name) }
                         }
                     }
@@ -256,6 +443,29 @@ pub fn update(
                 label for="date" { (t().date()) }
                 input #date name="date" type="date" min=(min_date.format("%Y-%m-%d")) max=(max_date.format("%Y-%m-%d")) value=(activity.date.format("%Y-%m-%d")) required;
 
+                label for="time" {
+                    (t().time())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                input #time name="time" type="time" value=[activity.time];
+
+                label for="quantity" {
+                    (t().quantity())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                input #quantity name="quantity" type="number" min="1" step="1" aria-describedby="quantity-help-text" value=(activity.quantity);
+                small #quantity-help-text { (t().activity_quantity_help_text()) }
+
+                (checklists(&chores, &checklist_items, |checklist_item| {
+                    checklist_item.chore_id == activity.chore_id && activity_checklist_items.iter().any(|activity_checklist_item| {
+                        activity_checklist_item.is_done
+                            && activity_checklist_item.position == checklist_item.position
+                            && activity_checklist_item.name == checklist_item.name
+                    })
+                }))
+
                 label for="comment" {
                     (t().comment())
                     " "
@@ -272,3 +482,34 @@ pub fn update(
         },
     )
 }
+
+/// One fieldset per chore with a checklist. Only the fieldset of the selected
+/// chore is shown, see `app.js`
+fn checklists(
+    chores: &[chore::Chore],
+    checklist_items: &[chore_checklist_item::ChoreChecklistItem],
+    is_done: impl Fn(&chore_checklist_item::ChoreChecklistItem) -> bool,
+) -> Markup {
+    html! {
+        @for chore in chores {
+            @let chore_checklist_items = checklist_items.iter().filter(|checklist_item| checklist_item.chore_id == chore.id).collect::<Vec<_>>();
+
+            @if !chore.is_deleted() && !chore_checklist_items.is_empty() {
+                fieldset data-checklist-chore-id=(chore.id) {
+                    legend { (t().checklist()) " – " (chore.name) }
+
+                    @for checklist_item in chore_checklist_items {
+                        label {
+                            input name="checklist_item_ids" type="checkbox" value=(checklist_item.id) checked[is_done(checklist_item)];
+                            (checklist_item.name)
+                        }
+                    }
+
+                    @if chore.award_partial_checklist_points {
+                        small.text-muted { (t().award_partial_checklist_points()) }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,30 +1,52 @@
 use maud::{html, Markup};
 use crate::handler::chore::ChoreActivitiesPath;
 use crate::handler::chore::ChoreCreatePath;
+use crate::handler::chore::ChoreCreateTaskPath;
 use crate::handler::chore::ChoreDeletePath;
 use crate::handler::chore::ChoreDetailPath;
 use crate::handler::chore::ChoreIndexPath;
+use crate::handler::chore::ChorePostponePath;
 use crate::handler::chore::ChoreRestorePath;
 use crate::handler::chore::ChoreUpdatePath;
+use crate::handler::chore::ChoreVoteDeletePath;
+use crate::handler::chore::ChoreVotePath;
+use crate::handler::chore_swap_request::ChoreSwapRequestAcceptPath;
+use crate::handler::chore_swap_request::ChoreSwapRequestCreatePath;
+use crate::handler::chore_swap_request::ChoreSwapRequestIndexPath;
 use crate::handler::chore_activity::ChoreActivityCreatePath;
 use crate::handler::chore_activity::ChoreActivityDetailPath;
 use crate::handler::chore_list::ChoreListIndexPath;
+use crate::handler::chore_list_user::ChoreListUserDetailPath;
 use wg_core::model::chore_list;
 use wg_core::model::chore_activity;
+use wg_core::model::chore_checklist_item;
+use wg_core::model::chore_exclusion;
+use wg_core::model::authentication_session::AuthenticationSession;
 use wg_core::model::chore;
+use wg_core::model::chore::PointsVotingMethod;
+use wg_core::model::chore_postponement;
+use wg_core::model::chore_postponement::ChorePostponementKind;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_vote;
 use wg_core::model::user;
 use wg_core::value::Date;
 use crate::template::helper::format_date_long;
 use crate::template::helper::format_date_long_simple;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
 use crate::template::partial::navigation::ChoreListNavigationItem;
+use strum::IntoEnumIterator;
 
 pub fn list(
     chore_list: chore_list::ChoreList,
     chores: Vec<chore::Chore>,
+    done_tasks: Vec<chore::Chore>,
     deleted_chores: Vec<chore::Chore>,
+    handover_offers: Vec<chore_swap_request::ChoreSwapRequest>,
+    users: Vec<user::User>,
+    auth_session: AuthenticationSession,
 ) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
@@ -34,24 +56,99 @@ pub fn list(
             .back_url(ChoreListIndexPath.to_string().as_str())
             .meta_actions(html! {
                 @if !chore_list.is_deleted() {
-                    a.secondary.subtle href=(ChoreCreatePath { chore_list_id: chore_list.id }) { "+ " (t().add_action()) }
+                    a.secondary.subtle href=(ChoreCreateTaskPath { chore_list_id: chore_list.id }) { "+ " (t().task()) }
+                    a.secondary.subtle href=(ChoreCreatePath { chore_list_id: chore_list.id }) style="margin-left: 1.25rem;" { "+ " (t().add_action()) }
                 }
             })
             .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
             .build(),
         html! {
+            @if !handover_offers.is_empty() {
+                h4 { (t().open_handover_offers()) }
+
+                ul.card-container.collapse {
+                    @for handover_offer in handover_offers.iter() {
+                        @let chore = chores.iter().find(|chore| chore.id == handover_offer.chore_id).unwrap();
+                        @let requesting_user = users.iter().find(|user| user.id == handover_offer.requesting_user_id).unwrap();
+
+                        li {
+                            div.card {
+                                div.title {
+                                    a href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) { (chore.name) }
+                                }
+
+                                small.text-muted {
+                                    (t().handover_offered_by(requesting_user.name.clone()))
+
+                                    @if let Some(next_due_date) = chore.next_due_date {
+                                        " – " time datetime=(next_due_date.format("%Y-%m-%d")) title=(next_due_date.format("%Y-%m-%d")) {
+                                            (format_date_long(next_due_date))
+                                        }
+                                    }
+                                }
+
+                                @if handover_offer.requesting_user_id != auth_session.user_id && !chore_list.is_deleted() {
+                                    form method="post" action=(ChoreSwapRequestAcceptPath { chore_list_id: chore_list.id, chore_swap_request_id: handover_offer.id }) {
+                                        (csrf_token_input())
+                                        button.link.secondary.subtle.mb-0 type="submit" { "✓ " (t().take_over_action()) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h4 { (t().chores()) }
+            }
+
             ul.card-container.collapse {
-                @for chore in chores {
+                @for chore in chores.iter() {
                     li {
                         a.card href={ (ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) } {
-                            div.title { (chore.name) }
+                            div.title {
+                                @if chore.is_one_off {
+                                    "📌 "
+                                }
+                                (chore.name)
+                            }
                             small.text-muted {
                                 (t().points_value_short(chore.points))
 
-                                @if let Some(next_due_date) = chore.next_due_date {
-                                    @if next_due_date.is_in_past_or_today() {
-                                        " – "
-                                        span.text-danger.fw-bold { (t().due_hint()) }
+                                @let bounty = chore.get_current_bounty();
+                                @if bounty > 0 {
+                                    " + " strong { (t().bounty_value_short(bounty)) }
+                                }
+
+                                @if chore.is_dormant() {
+                                    " – " (t().dormant_hint())
+                                } @else if chore.is_due() == Some(true) {
+                                    " – "
+                                    span.text-danger.fw-bold { (t().due_hint()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if ! done_tasks.is_empty() {
+                br;
+
+                details {
+                    summary.arrow-left.text-muted { (t().done_tasks()) }
+                    ul.card-container.collapse {
+                        @for chore in done_tasks {
+                            li {
+                                a.card href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) {
+                                    div.title { "📌 " (chore.name) }
+                                    small.text-muted {
+                                        (t().points_value_short(chore.points))
+
+                                        @if let Some(date_completed) = chore.date_completed {
+                                            " – " time datetime=(date_completed.format("%Y-%m-%d")) title=(date_completed.format("%Y-%m-%d")) {
+                                                (format_date_long(date_completed))
+                                            }
+                                        }
                                     }
                                 }
                             }
@@ -77,13 +174,33 @@ pub fn list(
                     }
                 }
             }
+
+            br;
+            br;
+
+            nav style="flex-direction: column;" {
+                ul.card-container.collapse {
+                    li {
+                        a.card href=(ChoreSwapRequestIndexPath { chore_list_id: chore_list.id }) {
+                            div.title { "🔁 " (t().swap_requests()) }
+                        }
+                    }
+                }
+            }
         },
     )
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn detail(
     chore: chore::Chore,
     chore_list: chore_list::ChoreList,
+    votes: Vec<chore_vote::ChoreVote>,
+    checklist_items: Vec<chore_checklist_item::ChoreChecklistItem>,
+    chores: Vec<chore::Chore>,
+    exclusions: Vec<chore_exclusion::ChoreExclusion>,
+    users: Vec<user::User>,
+    auth_session: AuthenticationSession,
 ) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
@@ -94,13 +211,13 @@ pub fn detail(
             .meta_actions(html! {
                 @if chore.is_deleted() {
                     button.link.secondary.subtle.mb-0 type="submit" form="chore_restore" { "↻ " (t().restore_action()) }
-                    form #chore_restore method="post" action=(ChoreRestorePath { chore_list_id: chore_list.id, chore_id: chore.id }) { }
+                    form #chore_restore method="post" action=(ChoreRestorePath { chore_list_id: chore_list.id, chore_id: chore.id }) { (csrf_token_input()) }
                 } @else if !chore_list.is_deleted() {
                     button.link.secondary.subtle.mb-0 type="submit" form="chore_delete" { "✗ " (t().delete_action()) }
 
                     a.secondary.subtle href=(ChoreUpdatePath { chore_list_id: chore_list.id, chore_id: chore.id }) style="margin-left: 1.25rem;" { "✎ " (t().edit_action()) }
 
-                    form #chore_delete method="post" action=(ChoreDeletePath { chore_list_id: chore_list.id, chore_id: chore.id }) { }
+                    form #chore_delete method="post" action=(ChoreDeletePath { chore_list_id: chore_list.id, chore_id: chore.id }) { (csrf_token_input()) }
                 }
             })
             .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
@@ -116,27 +233,167 @@ pub fn detail(
 
             dl {
                 dt { (t().points()) }
-                dd { (chore.points) }
+                dd {
+                    (chore.points)
+
+                    @let bounty = chore.get_current_bounty();
+                    @if bounty > 0 {
+                        " + " strong { (t().bounty_value_short(bounty)) }
+                    }
+                }
+
+                @if let Some(max_quantity) = chore.max_quantity {
+                    dt { (t().quantity()) }
+                    dd { (t().per_unit_up_to_n(max_quantity)) }
+                }
+
+                @if let Some(points_voting_method) = chore.points_voting_method {
+                    dt { (t().points_voting_method()) }
+                    dd { (points_voting_method_label(points_voting_method)) }
+                }
+
+                @if let Some(bounty_points_per_day) = chore.bounty_points_per_day {
+                    dt { (t().bounty()) }
+                    dd {
+                        @match chore.bounty_max_points {
+                            Some(bounty_max_points) => (t().bounty_per_day_with_max(bounty_points_per_day, bounty_max_points)),
+                            None => (t().bounty_per_day(bounty_points_per_day)),
+                        }
+                    }
+                }
+
+                @if chore.is_one_off {
+                    dt { (t().task()) }
+                    dd {
+                        @if let Some(date_completed) = chore.date_completed {
+                            (t().task_completed_on(format_date_long(date_completed)))
+                        } @else {
+                            (t().task_open())
+                        }
+                    }
+                }
 
-                @if let Some(interval_days) = chore.interval_days {
+                @if let Some(interval_hours) = chore.interval_hours {
+                    dt { (t().interval()) }
+                    dd { (t().every_n_hours(interval_hours)) }
+                } @else if let Some(interval_days) = chore.interval_days {
                     dt { (t().interval()) }
                     dd { (t().every_n_days(interval_days)) }
                 }
 
+                @if let Some(cooldown_days) = chore.cooldown_days {
+                    dt { (t().cooldown()) }
+                    dd { (t().n_days(cooldown_days)) }
+                }
+
+                @if let (Some(active_from_month), Some(active_until_month)) = (chore.active_from_month, chore.active_until_month) {
+                    dt { (t().season()) }
+                    dd {
+                        (t().month_name(active_from_month)) " – " (t().month_name(active_until_month))
+
+                        @if chore.is_dormant() {
+                            " (" (t().dormant_hint()) ")"
+                        }
+                    }
+                }
+
+                @if let Some(parent_chore) = chores.iter().find(|other_chore| Some(other_chore.id) == chore.follow_up_of_chore_id) {
+                    dt { (t().follow_up_of()) }
+                    dd {
+                        a.inherit.subtle href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: parent_chore.id }) { "🧹 " (parent_chore.name) }
+                        " – " (t().follow_up_delay_value(chore.follow_up_delay_days.unwrap_or_default()))
+                    }
+                }
+
+                @let follow_ups = chores.iter().filter(|other_chore| other_chore.follow_up_of_chore_id == Some(chore.id) && !other_chore.is_deleted()).collect::<Vec<_>>();
+                @if !follow_ups.is_empty() {
+                    dt { (t().follow_ups()) }
+                    dd {
+                        @for (index, follow_up) in follow_ups.iter().enumerate() {
+                            @if index > 0 { ", " }
+                            a.inherit.subtle href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: follow_up.id }) { "🧹 " (follow_up.name) }
+                        }
+                    }
+                }
+
                 @if let Some(next_due_date) = chore.next_due_date {
-                    @let is_due = next_due_date.is_in_past_or_today();
+                    @let is_due = chore.is_due() == Some(true);
                     dt { (t().next_due_date()) }
                     dd.text-danger[is_due].fw-bold[is_due] {
                         time datetime=(next_due_date.format("%Y-%m-%d")) title=(next_due_date.format("%Y-%m-%d")) {
                             (format_date_long(next_due_date))
                         }
+
+                        @if let Some(next_due_time) = chore.next_due_time {
+                            ", " time datetime=(next_due_time.format("%H:%M")) { (next_due_time) }
+                        }
                     }
                 }
 
-                @if let Some(description) = chore.description {
+                @if let Some(description) = &chore.description {
                     dt { (t().description()) }
                     dd { (description) }
                 }
+
+                @if !checklist_items.is_empty() {
+                    dt { (t().checklist()) }
+                    dd {
+                        ol.mb-0 {
+                            @for checklist_item in checklist_items {
+                                li { (checklist_item.name) }
+                            }
+                        }
+                        @if chore.award_partial_checklist_points {
+                            small.text-muted { (t().award_partial_checklist_points()) }
+                        }
+                    }
+                }
+
+                @let excluded_users = users.iter().filter(|user| exclusions.iter().any(|exclusion| exclusion.user_id == user.id)).collect::<Vec<_>>();
+                @if !excluded_users.is_empty() {
+                    dt { (t().excluded_users()) }
+                    dd {
+                        @for (index, user) in excluded_users.iter().enumerate() {
+                            @if index > 0 { ", " }
+                            a.inherit.subtle href=(ChoreListUserDetailPath { chore_list_id: chore_list.id, user_id: user.id }) { "👤 " (user.name) }
+                        }
+                    }
+                }
+            }
+
+            @if chore.points_voting_method.is_some() {
+                @let own_vote = votes.iter().find(|vote| vote.user_id == auth_session.user_id);
+
+                h4 { (t().votes()) }
+
+                ul {
+                    @for user in users.iter() {
+                        li {
+                            (user.name) ": "
+                            @if let Some(vote) = votes.iter().find(|vote| vote.user_id == user.id) {
+                                (t().points_value_short(vote.points))
+                            } @else {
+                                span.text-muted { (t().vote_pending()) }
+                            }
+                        }
+                    }
+                }
+
+                @if !chore.is_deleted() && !chore_list.is_deleted() {
+                    form method="post" action=(ChoreVotePath { chore_list_id: chore_list.id, chore_id: chore.id }) {
+                        (csrf_token_input())
+                        label for="vote_points" { (t().your_vote()) }
+                        div role="group" {
+                            input #vote_points name="points" type="number" min="1" max=(chore_vote::MAX_POINTS) step="1" required value=[own_vote.map(|vote| vote.points)];
+                            button type="submit" { (t().vote_action()) }
+                        }
+                    }
+
+                    @if own_vote.is_some() {
+                        button.link.secondary.subtle.mb-0 type="submit" form="chore_vote_delete" { "✗ " (t().retract_vote_action()) }
+                        form #chore_vote_delete method="post" action=(ChoreVoteDeletePath { chore_list_id: chore_list.id, chore_id: chore.id }) { (csrf_token_input()) }
+                    }
+                }
             }
 
             br;
@@ -149,6 +406,22 @@ pub fn detail(
                             div.title { "✅ " (t().activities()) }
                         }
                     }
+
+                    @if !chore.is_deleted() && !chore_list.is_deleted() && chore.is_due() == Some(true) {
+                        li {
+                            a.card href=(ChorePostponePath { chore_list_id: chore_list.id, chore_id: chore.id }) {
+                                div.title { "⏭️ " (t().postpone_chore()) }
+                            }
+                        }
+                    }
+
+                    @if !chore.is_deleted() && !chore_list.is_deleted() {
+                        li {
+                            a.card href=(ChoreSwapRequestCreatePath { chore_list_id: chore_list.id, chore_id: chore.id }) {
+                                div.title { "🔁 " (t().create_swap_request()) }
+                            }
+                        }
+                    }
                 }
             }
         },
@@ -157,6 +430,8 @@ pub fn detail(
 
 pub fn create(
     chore_list: chore_list::ChoreList,
+    chores: Vec<chore::Chore>,
+    users: Vec<user::User>,
 ) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
@@ -167,6 +442,7 @@ pub fn create(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required;
 
@@ -180,6 +456,19 @@ pub fn create(
                 label for="points" { (t().points()) }
                 input #points name="points" type="number" min="1" step="1" required;
 
+                label for="points_voting_method" {
+                    (t().points_voting_method())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                select #points_voting_method name="points_voting_method" aria-describedby="points_voting_method-help-text" {
+                    option selected value="" { (t().points_voting_method_none()) }
+                    @for points_voting_method in PointsVotingMethod::iter() {
+                        option value=(points_voting_method) { (points_voting_method_label(points_voting_method)) }
+                    }
+                }
+                small #points_voting_method-help-text { (t().points_voting_method_help_text()) }
+
                 label for="interval_days" {
                     (t().interval())
                     " "
@@ -191,6 +480,232 @@ pub fn create(
                 }
                 small #interval_days-help-text { (t().chore_interval_help_text()) }
 
+                label for="interval_hours" {
+                    (t().interval_in_hours())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #interval_hours name="interval_hours" type="number" min="1" step="1" aria-describedby="interval_hours-help-text";
+                    label for="interval_hours" { (t().hours()) }
+                }
+                small #interval_hours-help-text { (t().chore_interval_hours_help_text()) }
+
+                label for="cooldown_days" {
+                    (t().cooldown())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #cooldown_days name="cooldown_days" type="number" min="1" step="1" aria-describedby="cooldown_days-help-text";
+                    label for="cooldown_days" { (t().days()) }
+                }
+                small #cooldown_days-help-text { (t().chore_cooldown_help_text()) }
+
+                label for="follow_up_of_chore_id" {
+                    (t().follow_up_of())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                select #follow_up_of_chore_id name="follow_up_of_chore_id" aria-describedby="follow_up_of_chore_id-help-text" {
+                    option selected[true] value="" { (t().follow_up_of_none()) }
+                    @for other_chore in &chores {
+                        @if !other_chore.is_deleted() && !other_chore.is_one_off {
+                            option value=(other_chore.id) { (other_chore.name) }
+                        }
+                    }
+                }
+                small #follow_up_of_chore_id-help-text { (t().chore_follow_up_help_text()) }
+
+                label for="follow_up_delay_days" {
+                    (t().follow_up_delay())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #follow_up_delay_days name="follow_up_delay_days" type="number" min="0" step="1";
+                    label for="follow_up_delay_days" { (t().days()) }
+                }
+
+                label for="active_from_month" {
+                    (t().season())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" aria-describedby="active_months-help-text" {
+                    select #active_from_month name="active_from_month" aria-label=(t().season_from()) {
+                        option selected value="" { (t().season_from()) }
+                        @for month in 1..=12 {
+                            option value=(month) { (t().month_name(month)) }
+                        }
+                    }
+                    select #active_until_month name="active_until_month" aria-label=(t().season_until()) {
+                        option selected value="" { (t().season_until()) }
+                        @for month in 1..=12 {
+                            option value=(month) { (t().month_name(month)) }
+                        }
+                    }
+                }
+                small #active_months-help-text { (t().chore_season_help_text()) }
+
+                label for="bounty_points_per_day" {
+                    (t().bounty_per_overdue_day())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #bounty_points_per_day name="bounty_points_per_day" type="number" min="1" step="1" aria-describedby="bounty_points_per_day-help-text";
+                    label for="bounty_points_per_day" { (t().points()) }
+                }
+                small #bounty_points_per_day-help-text { (t().chore_bounty_help_text()) }
+
+                label for="bounty_max_points" {
+                    (t().bounty_max())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #bounty_max_points name="bounty_max_points" type="number" min="1" step="1" aria-describedby="bounty_max_points-help-text";
+                    label for="bounty_max_points" { (t().points()) }
+                }
+                small #bounty_max_points-help-text { (t().chore_bounty_max_help_text()) }
+
+                label for="max_quantity" {
+                    (t().max_quantity())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #max_quantity name="max_quantity" type="number" min="1" step="1" aria-describedby="max_quantity-help-text";
+                    label for="max_quantity" { (t().units()) }
+                }
+                small #max_quantity-help-text { (t().chore_max_quantity_help_text()) }
+
+                label for="checklist" {
+                    (t().checklist())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #checklist name="checklist" aria-describedby="checklist-help-text" { }
+                small #checklist-help-text { (t().chore_checklist_help_text()) }
+
+                label {
+                    input name="award_partial_checklist_points" type="checkbox" role="switch" value="true";
+                    (t().award_partial_checklist_points())
+                }
+
+                (excluded_users_fieldset(&users, |_| false))
+
+                button type="submit" { (t().create_action()) }
+            }
+        },
+    )
+}
+
+pub fn postpone(
+    chore: chore::Chore,
+    chore_list: chore_list::ChoreList,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("⏭️")
+            .title(&t().postpone_chore())
+            .teaser(&t().of_x(format!("🧹 {}", chore.name)))
+            .back_url(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
+            .build(),
+        html! {
+            p { (t().postpone_chore_text()) }
+
+            form method="post" {
+                (csrf_token_input())
+                label for="kind" { (t().postponement_kind()) }
+                select #kind name="kind" required {
+                    @if (chore.interval_days.is_some() || chore.interval_hours.is_some()) && !chore.is_one_off {
+                        option value=(ChorePostponementKind::Skip) { (postponement_kind_label(ChorePostponementKind::Skip)) }
+                    }
+                    option value=(ChorePostponementKind::Snooze) { (postponement_kind_label(ChorePostponementKind::Snooze)) }
+                }
+
+                label for="snooze_days" {
+                    (t().snooze_for())
+                    " "
+                    i.text-muted { "(" (t().only_for_snoozing()) ")" }
+                }
+                div role="group" {
+                    input #snooze_days name="snooze_days" type="number" min="1" max=(chore_postponement::MAX_SNOOZE_DAYS) step="1" value="1";
+                    label for="snooze_days" { (t().days()) }
+                }
+
+                label for="reason" {
+                    (t().reason())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #reason name="reason" { }
+
+                button type="submit" { (t().postpone_action()) }
+            }
+        },
+    )
+}
+
+pub fn create_task(
+    chore_list: chore_list::ChoreList,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("📌")
+            .title(&t().create_task())
+            .back_url(ChoreIndexPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="name" { (t().name()) }
+                input #name name="name" type="text" required;
+
+                label for="description" {
+                    (t().description())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #description name="description" { }
+
+                label for="points" { (t().points()) }
+                input #points name="points" type="number" min="1" step="1" required;
+
+                label for="due_date" {
+                    (t().due_date())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                input #due_date name="due_date" type="date" aria-describedby="due_date-help-text";
+                small #due_date-help-text { (t().task_due_date_help_text()) }
+
+                label for="bounty_points_per_day" {
+                    (t().bounty_per_overdue_day())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #bounty_points_per_day name="bounty_points_per_day" type="number" min="1" step="1" aria-describedby="bounty_points_per_day-help-text";
+                    label for="bounty_points_per_day" { (t().points()) }
+                }
+                small #bounty_points_per_day-help-text { (t().chore_bounty_help_text()) }
+
+                label for="bounty_max_points" {
+                    (t().bounty_max())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #bounty_max_points name="bounty_max_points" type="number" min="1" step="1" aria-describedby="bounty_max_points-help-text";
+                    label for="bounty_max_points" { (t().points()) }
+                }
+                small #bounty_max_points-help-text { (t().chore_bounty_max_help_text()) }
+
                 button type="submit" { (t().create_action()) }
             }
         },
@@ -200,6 +715,10 @@ pub fn create(
 pub fn update(
     chore: chore::Chore,
     chore_list: chore_list::ChoreList,
+    checklist_items: Vec<chore_checklist_item::ChoreChecklistItem>,
+    chores: Vec<chore::Chore>,
+    users: Vec<user::User>,
+    exclusions: Vec<chore_exclusion::ChoreExclusion>,
 ) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
@@ -210,6 +729,7 @@ pub fn update(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required value=(chore.name);
 
@@ -219,7 +739,7 @@ pub fn update(
                     i.text-muted { "(" (t().optional()) ")" }
                 }
                 textarea #description name="description" {
-                    @if let Some(description) = chore.description {
+                    @if let Some(description) = &chore.description {
                         (description)
                     }
                 }
@@ -227,16 +747,160 @@ pub fn update(
                 label for="points" { (t().points()) }
                 input #points name="points" type="number" min="1" step="1" required value=(chore.points);
 
-                label for="interval_days" {
-                    (t().interval())
+                label for="points_voting_method" {
+                    (t().points_voting_method())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                select #points_voting_method name="points_voting_method" aria-describedby="points_voting_method-help-text" {
+                    option selected[chore.points_voting_method.is_none()] value="" { (t().points_voting_method_none()) }
+                    @for points_voting_method in PointsVotingMethod::iter() {
+                        option value=(points_voting_method) selected[chore.points_voting_method == Some(points_voting_method)] {
+                            (points_voting_method_label(points_voting_method))
+                        }
+                    }
+                }
+                small #points_voting_method-help-text { (t().points_voting_method_help_text()) }
+
+                @if chore.is_one_off {
+                    label for="due_date" {
+                        (t().due_date())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    input #due_date name="due_date" type="date" value=[chore.next_due_date.map(|due_date| due_date.format("%Y-%m-%d"))];
+                } @else {
+                    label for="interval_days" {
+                        (t().interval())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    div role="group" {
+                        input #interval_days name="interval_days" type="number" min="1" step="1" aria-describedby="interval_days-help-text" value=[chore.interval_days];
+                        label for="interval_days" { (t().days()) }
+                    }
+                    small #interval_days-help-text { (t().chore_interval_help_text()) }
+
+                    label for="interval_hours" {
+                        (t().interval_in_hours())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    div role="group" {
+                        input #interval_hours name="interval_hours" type="number" min="1" step="1" aria-describedby="interval_hours-help-text" value=[chore.interval_hours];
+                        label for="interval_hours" { (t().hours()) }
+                    }
+                    small #interval_hours-help-text { (t().chore_interval_hours_help_text()) }
+
+                    label for="cooldown_days" {
+                        (t().cooldown())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    div role="group" {
+                        input #cooldown_days name="cooldown_days" type="number" min="1" step="1" aria-describedby="cooldown_days-help-text" value=[chore.cooldown_days];
+                        label for="cooldown_days" { (t().days()) }
+                    }
+                    small #cooldown_days-help-text { (t().chore_cooldown_help_text()) }
+
+                    label for="follow_up_of_chore_id" {
+                        (t().follow_up_of())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    select #follow_up_of_chore_id name="follow_up_of_chore_id" aria-describedby="follow_up_of_chore_id-help-text" {
+                        option selected[chore.follow_up_of_chore_id.is_none()] value="" { (t().follow_up_of_none()) }
+                        @for other_chore in &chores {
+                            @if !other_chore.is_deleted() && !other_chore.is_one_off && other_chore.id != chore.id {
+                                option selected[chore.follow_up_of_chore_id == Some(other_chore.id)] value=(other_chore.id) { (other_chore.name) }
+                            }
+                        }
+                    }
+                    small #follow_up_of_chore_id-help-text { (t().chore_follow_up_help_text()) }
+
+                    label for="follow_up_delay_days" {
+                        (t().follow_up_delay())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    div role="group" {
+                        input #follow_up_delay_days name="follow_up_delay_days" type="number" min="0" step="1" value=[chore.follow_up_delay_days];
+                        label for="follow_up_delay_days" { (t().days()) }
+                    }
+
+                    label for="active_from_month" {
+                        (t().season())
+                        " "
+                        i.text-muted { "(" (t().optional()) ")" }
+                    }
+                    div role="group" aria-describedby="active_months-help-text" {
+                        select #active_from_month name="active_from_month" aria-label=(t().season_from()) {
+                            option selected[chore.active_from_month.is_none()] value="" { (t().season_from()) }
+                            @for month in 1..=12 {
+                                option selected[chore.active_from_month == Some(month)] value=(month) { (t().month_name(month)) }
+                            }
+                        }
+                        select #active_until_month name="active_until_month" aria-label=(t().season_until()) {
+                            option selected[chore.active_until_month.is_none()] value="" { (t().season_until()) }
+                            @for month in 1..=12 {
+                                option selected[chore.active_until_month == Some(month)] value=(month) { (t().month_name(month)) }
+                            }
+                        }
+                    }
+                    small #active_months-help-text { (t().chore_season_help_text()) }
+                }
+
+                label for="bounty_points_per_day" {
+                    (t().bounty_per_overdue_day())
                     " "
                     i.text-muted { "(" (t().optional()) ")" }
                 }
                 div role="group" {
-                    input #interval_days name="interval_days" type="number" min="1" step="1" aria-describedby="interval_days-help-text" value=[chore.interval_days];
-                    label for="interval_days" { (t().days()) }
+                    input #bounty_points_per_day name="bounty_points_per_day" type="number" min="1" step="1" aria-describedby="bounty_points_per_day-help-text" value=[chore.bounty_points_per_day];
+                    label for="bounty_points_per_day" { (t().points()) }
                 }
-                small #interval_days-help-text { (t().chore_interval_help_text()) }
+                small #bounty_points_per_day-help-text { (t().chore_bounty_help_text()) }
+
+                label for="bounty_max_points" {
+                    (t().bounty_max())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #bounty_max_points name="bounty_max_points" type="number" min="1" step="1" aria-describedby="bounty_max_points-help-text" value=[chore.bounty_max_points];
+                    label for="bounty_max_points" { (t().points()) }
+                }
+                small #bounty_max_points-help-text { (t().chore_bounty_max_help_text()) }
+
+                label for="max_quantity" {
+                    (t().max_quantity())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #max_quantity name="max_quantity" type="number" min="1" step="1" aria-describedby="max_quantity-help-text" value=[chore.max_quantity];
+                    label for="max_quantity" { (t().units()) }
+                }
+                small #max_quantity-help-text { (t().chore_max_quantity_help_text()) }
+
+                label for="checklist" {
+                    (t().checklist())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #checklist name="checklist" aria-describedby="checklist-help-text" {
+                    @for checklist_item in &checklist_items {
+                        (checklist_item.name) "\n"
+                    }
+                }
+                small #checklist-help-text { (t().chore_checklist_help_text()) }
+
+                label {
+                    input name="award_partial_checklist_points" type="checkbox" role="switch" value="true" checked[chore.award_partial_checklist_points];
+                    (t().award_partial_checklist_points())
+                }
+
+                (excluded_users_fieldset(&users, |user| exclusions.iter().any(|exclusion| exclusion.user_id == user.id)))
 
                 button type="submit" { (t().save_action()) }
             }
@@ -249,6 +913,7 @@ pub fn list_activities(
     chore_list: chore_list::ChoreList,
     activities_by_date: Vec<(Date, Vec<&chore_activity::ChoreActivity>)>,
     deleted_activities: Vec<chore_activity::ChoreActivity>,
+    postponements: Vec<chore_postponement::ChorePostponement>,
     users: Vec<user::User>,
 ) -> Markup {
     layout::default(
@@ -278,6 +943,14 @@ pub fn list_activities(
                                 a.card href=(ChoreActivityDetailPath {chore_list_id: chore_list.id, chore_activity_id: activity.id }) {
                                     div.title { (user.name) }
 
+                                    @if let Some(time) = activity.time {
+                                        small.text-muted { time datetime=(time.format("%H:%M")) { (time) } }
+                                    }
+
+                                    @if activity.is_cooldown_violation {
+                                        small.text-muted { (t().cooldown_violation_hint()) }
+                                    }
+
                                     @if activity.comment.is_some() {
                                         small.text-muted { (t().has_comment()) }
                                     }
@@ -288,6 +961,37 @@ pub fn list_activities(
                 }
             }
 
+            @if ! postponements.is_empty() {
+                br;
+
+                details {
+                    summary.arrow-left.text-muted { (t().postponements()) }
+                    ul.card-container.collapse {
+                        @for postponement in postponements {
+                            @let user = users.iter().find(|user| user.id == postponement.user_id).unwrap();
+
+                            li {
+                                div.card {
+                                    div.title { (postponement_kind_label(postponement.kind)) " – " (user.name) }
+
+                                    small.text-muted {
+                                        time datetime=(postponement.date_created.format("%Y-%m-%d")) title=(postponement.date_created.format("%Y-%m-%d")) {
+                                            (format_date_long(Date::from(postponement.date_created.as_ref().naive_utc())))
+                                        }
+
+                                        " – " (t().postponed_from_to(format_date_long(postponement.previous_due_date), format_date_long(postponement.new_due_date)))
+                                    }
+
+                                    @if let Some(reason) = &postponement.reason {
+                                        small.text-muted { (reason) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
             @if ! deleted_activities.is_empty() {
                 br;
 
@@ -306,6 +1010,10 @@ pub fn list_activities(
                                             (format_date_long(activity.date))
                                         }
 
+                                        @if activity.is_cooldown_violation {
+                                            " – " (t().cooldown_violation_hint())
+                                        }
+
                                         @if activity.comment.is_some() {
                                             " – " (t().has_comment())
                                         }
@@ -319,3 +1027,41 @@ pub fn list_activities(
         },
     )
 }
+
+fn excluded_users_fieldset(
+    users: &[user::User],
+    is_excluded: impl Fn(&user::User) -> bool,
+) -> Markup {
+    html! {
+        fieldset aria-describedby="excluded_user_ids-help-text" {
+            legend {
+                (t().excluded_users())
+                " "
+                i.text-muted { "(" (t().optional()) ")" }
+            }
+
+            @for user in users {
+                label {
+                    input name="excluded_user_ids" type="checkbox" value=(user.id) checked[is_excluded(user)];
+                    (user.name)
+                }
+            }
+
+            small #excluded_user_ids-help-text { (t().chore_excluded_users_help_text()) }
+        }
+    }
+}
+
+fn points_voting_method_label(points_voting_method: PointsVotingMethod) -> String {
+    match points_voting_method {
+        PointsVotingMethod::Median => t().points_voting_method_median().to_string(),
+        PointsVotingMethod::Mean => t().points_voting_method_mean().to_string(),
+    }
+}
+
+fn postponement_kind_label(kind: ChorePostponementKind) -> String {
+    match kind {
+        ChorePostponementKind::Skip => format!("⏭️ {}", t().postponement_kind_skip()),
+        ChorePostponementKind::Snooze => format!("💤 {}", t().postponement_kind_snooze()),
+    }
+}
@@ -1,8 +1,11 @@
 pub mod activity;
 pub mod chore;
+pub mod reward;
+pub mod swap_request;
 pub mod user;
 
 use maud::{html, Markup};
+use crate::handler::chore_activity::ChoreActivityCooldownViolationIndexPath;
 use crate::handler::chore_activity::ChoreActivityIndexPath;
 use crate::handler::chore_list::ChoreListCreatePath;
 use crate::handler::chore_list::ChoreListDeletePath;
@@ -12,6 +15,7 @@ use crate::handler::chore_list::ChoreListSettingsPath;
 use crate::handler::chore_list::ChoreListUpdatePath;
 use wg_core::model::chore_list;
 use wg_core::model::chore_list::ScoreResetInterval;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
@@ -77,6 +81,7 @@ pub fn create() -> Markup {
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required;
 
@@ -120,6 +125,7 @@ pub fn update(chore_list: chore_list::ChoreList) -> Markup {
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required value=(chore_list.name);
 
@@ -157,7 +163,7 @@ pub fn update(chore_list: chore_list::ChoreList) -> Markup {
     )
 }
 
-pub fn settings(chore_list: chore_list::ChoreList) -> Markup {
+pub fn settings(chore_list: chore_list::ChoreList, can_delete_chore_list: bool, can_view_cooldown_violations: bool) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
             .emoji("⚙️")
@@ -170,11 +176,13 @@ pub fn settings(chore_list: chore_list::ChoreList) -> Markup {
             nav style="flex-direction: column;" {
                 ul.card-container.collapse {
                     @if chore_list.is_deleted() {
-                        li {
-                            button.card.text-align-left.mb-0 type="submit" form="chore_list_restore" {
-                                div.title { "♻️ " (t().restore_chore_list()) }
+                        @if can_delete_chore_list {
+                            li {
+                                button.card.text-align-left.mb-0 type="submit" form="chore_list_restore" {
+                                    div.title { "♻️ " (t().restore_chore_list()) }
+                                }
+                                form #chore_list_restore method="post" action=(ChoreListRestorePath { chore_list_id: chore_list.id }) { (csrf_token_input()) }
                             }
-                            form #chore_list_restore method="post" action=(ChoreListRestorePath { chore_list_id: chore_list.id }) { }
                         }
                     } @else {
                         li {
@@ -182,11 +190,20 @@ pub fn settings(chore_list: chore_list::ChoreList) -> Markup {
                                 div.title { "✏️ " (t().edit_chore_list()) }
                             }
                         }
-                        li {
-                            button.card.text-align-left.mb-0 type="submit" form="chore_list_delete" {
-                                div.title.text-danger { "🗑️ " (t().delete_chore_list()) }
+                        @if can_view_cooldown_violations {
+                            li {
+                                a.card href=(ChoreActivityCooldownViolationIndexPath { chore_list_id: chore_list.id }) {
+                                    div.title { "⏱️ " (t().cooldown_violations()) }
+                                }
+                            }
+                        }
+                        @if can_delete_chore_list {
+                            li {
+                                button.card.text-align-left.mb-0 type="submit" form="chore_list_delete" {
+                                    div.title.text-danger { "🗑️ " (t().delete_chore_list()) }
+                                }
+                                form #chore_list_delete method="post" action=(ChoreListDeletePath { chore_list_id: chore_list.id }) { (csrf_token_input()) }
                             }
-                            form #chore_list_delete method="post" action=(ChoreListDeletePath { chore_list_id: chore_list.id }) { }
                         }
                     }
                 }
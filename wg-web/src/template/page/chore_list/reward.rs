@@ -0,0 +1,295 @@
+use maud::{html, Markup};
+use crate::handler::chore_list::ChoreListIndexPath;
+use crate::handler::reward::RewardCreatePath;
+use crate::handler::reward::RewardDeletePath;
+use crate::handler::reward::RewardDetailPath;
+use crate::handler::reward::RewardIndexPath;
+use crate::handler::reward::RewardRedeemPath;
+use crate::handler::reward::RewardRedemptionDeletePath;
+use crate::handler::reward::RewardRedemptionIndexPath;
+use crate::handler::reward::RewardRestorePath;
+use crate::handler::reward::RewardUpdatePath;
+use wg_core::model::authentication_session::AuthenticationSession;
+use wg_core::model::chore_list;
+use wg_core::model::reward;
+use wg_core::model::reward_redemption;
+use wg_core::model::user;
+use wg_core::value::Date;
+use crate::template::helper::format_date_long;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::ChoreListNavigationItem;
+
+pub fn list(
+    chore_list: chore_list::ChoreList,
+    rewards: Vec<reward::Reward>,
+    deleted_rewards: Vec<reward::Reward>,
+    balance: i32,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🎁")
+            .title(&t().rewards())
+            .teaser(&t().of_x(format!("📋 {}", chore_list.name)))
+            .back_url(ChoreListIndexPath.to_string().as_str())
+            .meta_actions(html! {
+                @if !chore_list.is_deleted() {
+                    a.secondary.subtle href=(RewardCreatePath { chore_list_id: chore_list.id }) { "+ " (t().add_action()) }
+                }
+            })
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Rewards)))
+            .build(),
+        html! {
+            p { (t().your_balance(balance)) }
+
+            @if rewards.is_empty() {
+                p.text-muted { (t().no_rewards()) }
+            }
+
+            ul.card-container.collapse {
+                @for reward in rewards {
+                    li {
+                        a.card href=(RewardDetailPath { chore_list_id: chore_list.id, reward_id: reward.id }) {
+                            div.title { (reward.name) }
+                            small.text-muted.text-danger[balance < reward.points as i32] { (t().points_value_short(reward.points)) }
+                        }
+                    }
+                }
+            }
+
+            @if ! deleted_rewards.is_empty() {
+                br;
+
+                details {
+                    summary.arrow-left.text-muted { (t().deleted_rewards()) }
+                    ul.card-container.collapse {
+                        @for reward in deleted_rewards {
+                            li {
+                                a.card href=(RewardDetailPath { chore_list_id: chore_list.id, reward_id: reward.id }) {
+                                    div.title { (reward.name) }
+                                    small.text-muted { (t().points_value_short(reward.points)) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            br;
+            br;
+
+            nav style="flex-direction: column;" {
+                ul.card-container.collapse {
+                    li {
+                        a.card href=(RewardRedemptionIndexPath { chore_list_id: chore_list.id }) {
+                            div.title { "🧾 " (t().redemptions()) }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn detail(
+    reward: reward::Reward,
+    chore_list: chore_list::ChoreList,
+    balance: i32,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🎁")
+            .title(&reward.name)
+            .teaser(&t().of_x(format!("📋 {}", chore_list.name)))
+            .back_url(RewardIndexPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .meta_actions(html! {
+                @if reward.is_deleted() {
+                    button.link.secondary.subtle.mb-0 type="submit" form="reward_restore" { "↻ " (t().restore_action()) }
+                    form #reward_restore method="post" action=(RewardRestorePath { chore_list_id: chore_list.id, reward_id: reward.id }) { (csrf_token_input()) }
+                } @else if !chore_list.is_deleted() {
+                    button.link.secondary.subtle.mb-0 type="submit" form="reward_delete" { "✗ " (t().delete_action()) }
+
+                    a.secondary.subtle href=(RewardUpdatePath { chore_list_id: chore_list.id, reward_id: reward.id }) style="margin-left: 1.25rem;" { "✎ " (t().edit_action()) }
+
+                    form #reward_delete method="post" action=(RewardDeletePath { chore_list_id: chore_list.id, reward_id: reward.id }) { (csrf_token_input()) }
+                }
+            })
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Rewards)))
+            .build(),
+        html! {
+            @if reward.is_deleted() || chore_list.is_deleted() {
+                div {
+                    em { (t().reward_has_been_deleted()) }
+                }
+
+                br;
+            }
+
+            dl {
+                dt { (t().price()) }
+                dd { (t().points_value_short(reward.points)) }
+
+                @if let Some(description) = &reward.description {
+                    dt { (t().description()) }
+                    dd { (description) }
+                }
+            }
+
+            @if !reward.is_deleted() && !chore_list.is_deleted() {
+                p { (t().your_balance(balance)) }
+
+                form method="post" action=(RewardRedeemPath { chore_list_id: chore_list.id, reward_id: reward.id }) {
+                    (csrf_token_input())
+                    button type="submit" disabled[balance < reward.points as i32] { (t().redeem_action()) }
+                }
+            }
+        },
+    )
+}
+
+pub fn create(
+    chore_list: chore_list::ChoreList,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🎁")
+            .title(&t().create_reward())
+            .back_url(RewardIndexPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Rewards)))
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="name" { (t().name()) }
+                input #name name="name" type="text" required;
+
+                label for="description" {
+                    (t().description())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #description name="description" { }
+
+                label for="points" { (t().price()) }
+                div role="group" {
+                    input #points name="points" type="number" min="1" step="1" required;
+                    label for="points" { (t().points()) }
+                }
+
+                button type="submit" { (t().create_action()) }
+            }
+        },
+    )
+}
+
+pub fn update(
+    reward: reward::Reward,
+    chore_list: chore_list::ChoreList,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🎁")
+            .title(&t().edit_reward())
+            .back_url(RewardDetailPath { chore_list_id: chore_list.id, reward_id: reward.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Rewards)))
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="name" { (t().name()) }
+                input #name name="name" type="text" required value=(reward.name);
+
+                label for="description" {
+                    (t().description())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #description name="description" { (reward.description.unwrap_or_default()) }
+
+                label for="points" { (t().price()) }
+                div role="group" {
+                    input #points name="points" type="number" min="1" step="1" required value=(reward.points);
+                    label for="points" { (t().points()) }
+                }
+
+                button type="submit" { (t().save_action()) }
+            }
+        },
+    )
+}
+
+pub fn list_redemptions(
+    chore_list: chore_list::ChoreList,
+    redemptions: Vec<reward_redemption::RewardRedemption>,
+    deleted_redemptions: Vec<reward_redemption::RewardRedemption>,
+    rewards: Vec<reward::Reward>,
+    users: Vec<user::User>,
+    auth_session: AuthenticationSession,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🧾")
+            .title(&t().redemptions())
+            .teaser(&t().of_x(format!("📋 {}", chore_list.name)))
+            .back_url(RewardIndexPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Rewards)))
+            .build(),
+        html! {
+            @if redemptions.is_empty() {
+                p.text-muted { (t().no_redemptions()) }
+            }
+
+            ul.card-container.collapse {
+                @for redemption in redemptions.iter() {
+                    @let reward = rewards.iter().find(|reward| reward.id == redemption.reward_id).unwrap();
+                    @let user = users.iter().find(|user| user.id == redemption.user_id).unwrap();
+                    @let date = Date::from(redemption.date_created.as_ref().naive_utc());
+
+                    li {
+                        div.card {
+                            div.title { (reward.name) }
+
+                            small.text-muted {
+                                (user.name)
+                                " – " (t().points_value_short(redemption.points))
+                                " – " time datetime=(date.format("%Y-%m-%d")) title=(date.format("%Y-%m-%d")) {
+                                    (format_date_long(date))
+                                }
+                            }
+
+                            @if redemption.user_id == auth_session.user_id && redemption.can_be_undone() && !chore_list.is_deleted() {
+                                form method="post" action=(RewardRedemptionDeletePath { chore_list_id: chore_list.id, reward_redemption_id: redemption.id }) {
+                                    (csrf_token_input())
+                                    button.link.secondary.subtle.mb-0 type="submit" { "↺ " (t().undo_action()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if ! deleted_redemptions.is_empty() {
+                br;
+
+                details {
+                    summary.arrow-left.text-muted { (t().undone_redemptions()) }
+                    ul.card-container.collapse {
+                        @for redemption in deleted_redemptions.iter() {
+                            @let reward = rewards.iter().find(|reward| reward.id == redemption.reward_id).unwrap();
+                            @let user = users.iter().find(|user| user.id == redemption.user_id).unwrap();
+
+                            li {
+                                div.card {
+                                    div.title { (reward.name) }
+                                    small.text-muted { (user.name) " – " (t().points_value_short(redemption.points)) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
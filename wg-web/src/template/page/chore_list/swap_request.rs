@@ -0,0 +1,189 @@
+use maud::{html, Markup};
+use crate::handler::chore::ChoreDetailPath;
+use crate::handler::chore::ChoreIndexPath;
+use crate::handler::chore_swap_request::ChoreSwapRequestAcceptPath;
+use crate::handler::chore_swap_request::ChoreSwapRequestCancelPath;
+use crate::handler::chore_swap_request::ChoreSwapRequestDeclinePath;
+use wg_core::model::authentication_session::AuthenticationSession;
+use wg_core::model::chore;
+use wg_core::model::chore_list;
+use wg_core::model::chore_swap_request;
+use wg_core::model::chore_swap_request::ChoreSwapRequestStatus;
+use wg_core::model::user;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::ChoreListNavigationItem;
+
+pub fn list(
+    chore_list: chore_list::ChoreList,
+    open_swap_requests: Vec<chore_swap_request::ChoreSwapRequest>,
+    closed_swap_requests: Vec<chore_swap_request::ChoreSwapRequest>,
+    chores: Vec<chore::Chore>,
+    users: Vec<user::User>,
+    auth_session: AuthenticationSession,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔁")
+            .title(&t().swap_requests())
+            .teaser(&t().of_x(format!("📋 {}", chore_list.name)))
+            .back_url(ChoreIndexPath { chore_list_id: chore_list.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
+            .build(),
+        html! {
+            @if open_swap_requests.is_empty() {
+                p.text-muted { (t().no_open_swap_requests()) }
+            }
+
+            ul.card-container.collapse {
+                @for swap_request in open_swap_requests.iter() {
+                    @let chore = chores.iter().find(|chore| chore.id == swap_request.chore_id).unwrap();
+                    @let requesting_user = users.iter().find(|user| user.id == swap_request.requesting_user_id).unwrap();
+                    @let target_user_name = target_user_name(swap_request, &users);
+
+                    li {
+                        div.card {
+                            div.title {
+                                a href=(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }) { (chore.name) }
+                            }
+
+                            small.text-muted {
+                                (t().swap_request_from_to(requesting_user.name.clone(), target_user_name))
+
+                                @if swap_request.offered_points > 0 {
+                                    " – " (t().points_value_short(swap_request.offered_points))
+                                }
+
+                                " – " (status_label(swap_request.status))
+                            }
+
+                            @if let Some(comment) = &swap_request.comment {
+                                p.mb-0 { (comment) }
+                            }
+
+                            @if !chore_list.is_deleted() {
+                                @if swap_request.is_handover_offer() && swap_request.requesting_user_id != auth_session.user_id {
+                                    div {
+                                        button.link.secondary.subtle.mb-0 type="submit" form={ "swap_request_accept_" (swap_request.id) } { "✓ " (t().take_over_action()) }
+                                        form id={ "swap_request_accept_" (swap_request.id) } method="post" action=(ChoreSwapRequestAcceptPath { chore_list_id: chore_list.id, chore_swap_request_id: swap_request.id }) { (csrf_token_input()) }
+                                    }
+                                } @else if swap_request.target_user_id == Some(auth_session.user_id) {
+                                    div {
+                                        @if swap_request.status == ChoreSwapRequestStatus::Pending {
+                                            button.link.secondary.subtle.mb-0 type="submit" form={ "swap_request_accept_" (swap_request.id) } { "✓ " (t().accept_action()) }
+                                            form id={ "swap_request_accept_" (swap_request.id) } method="post" action=(ChoreSwapRequestAcceptPath { chore_list_id: chore_list.id, chore_swap_request_id: swap_request.id }) { (csrf_token_input()) }
+                                        }
+
+                                        button.link.secondary.subtle.mb-0 type="submit" form={ "swap_request_decline_" (swap_request.id) } style="margin-left: 1.25rem;" { "✗ " (t().decline_action()) }
+                                        form id={ "swap_request_decline_" (swap_request.id) } method="post" action=(ChoreSwapRequestDeclinePath { chore_list_id: chore_list.id, chore_swap_request_id: swap_request.id }) { (csrf_token_input()) }
+                                    }
+                                } @else if swap_request.requesting_user_id == auth_session.user_id {
+                                    div {
+                                        button.link.secondary.subtle.mb-0 type="submit" form={ "swap_request_cancel_" (swap_request.id) } { "✗ " (t().cancel_action()) }
+                                        form id={ "swap_request_cancel_" (swap_request.id) } method="post" action=(ChoreSwapRequestCancelPath { chore_list_id: chore_list.id, chore_swap_request_id: swap_request.id }) { (csrf_token_input()) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if ! closed_swap_requests.is_empty() {
+                br;
+
+                details {
+                    summary.arrow-left.text-muted { (t().closed_swap_requests()) }
+                    ul.card-container.collapse {
+                        @for swap_request in closed_swap_requests.iter() {
+                            @let chore = chores.iter().find(|chore| chore.id == swap_request.chore_id).unwrap();
+                            @let requesting_user = users.iter().find(|user| user.id == swap_request.requesting_user_id).unwrap();
+                            @let target_user_name = target_user_name(swap_request, &users);
+
+                            li {
+                                div.card {
+                                    div.title { (chore.name) }
+                                    small.text-muted {
+                                        (t().swap_request_from_to(requesting_user.name.clone(), target_user_name))
+
+                                        @if swap_request.offered_points > 0 {
+                                            " – " (t().points_value_short(swap_request.offered_points))
+                                        }
+
+                                        " – " (status_label(swap_request.status))
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+pub fn create(
+    chore: chore::Chore,
+    chore_list: chore_list::ChoreList,
+    users: Vec<user::User>,
+) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔁")
+            .title(&t().create_swap_request())
+            .teaser(&t().of_x(format!("🧹 {}", chore.name)))
+            .back_url(ChoreDetailPath { chore_list_id: chore_list.id, chore_id: chore.id }.to_string().as_str())
+            .navigation(partial::navigation::chore_list(&chore_list, Some(ChoreListNavigationItem::Chores)))
+            .build(),
+        html! {
+            form method="post" {
+                (csrf_token_input())
+                label for="target_user_id" { (t().swap_request_target_user()) }
+                select #target_user_id name="target_user_id" required {
+                    @for user in users.iter() {
+                        option value=(user.id) { (user.name) }
+                    }
+                }
+
+                label for="offered_points" {
+                    (t().offered_points())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                div role="group" {
+                    input #offered_points name="offered_points" type="number" min="0" step="1" aria-describedby="offered_points-help-text";
+                    label for="offered_points" { (t().points()) }
+                }
+                small #offered_points-help-text { (t().offered_points_help_text()) }
+
+                label for="comment" {
+                    (t().comment())
+                    " "
+                    i.text-muted { "(" (t().optional()) ")" }
+                }
+                textarea #comment name="comment" { }
+
+                button type="submit" { (t().send_swap_request_action()) }
+            }
+        },
+    )
+}
+
+fn target_user_name(swap_request: &chore_swap_request::ChoreSwapRequest, users: &[user::User]) -> String {
+    match swap_request.target_user_id {
+        Some(target_user_id) => users.iter().find(|user| user.id == target_user_id).unwrap().name.clone(),
+        None => t().anyone().to_string(),
+    }
+}
+
+fn status_label(status: ChoreSwapRequestStatus) -> String {
+    match status {
+        ChoreSwapRequestStatus::Pending => t().swap_request_status_pending().to_string(),
+        ChoreSwapRequestStatus::Accepted => t().swap_request_status_accepted().to_string(),
+        ChoreSwapRequestStatus::Declined => t().swap_request_status_declined().to_string(),
+        ChoreSwapRequestStatus::Cancelled => t().swap_request_status_cancelled().to_string(),
+        ChoreSwapRequestStatus::Completed => t().swap_request_status_completed().to_string(),
+    }
+}
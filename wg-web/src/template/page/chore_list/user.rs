@@ -45,6 +45,10 @@ pub fn list(
                                 } @else {
                                     (t().score_value(scores.adjusted_score))
                                 }
+
+                                @if scores.available_points < scores.total_points {
+                                    " – " (t().available_points_value(scores.available_points, scores.total_points))
+                                }
                             }
                         }
                     }
@@ -140,7 +144,19 @@ pub fn list_activities(
                                     div.title { (chore.name) }
 
                                     small.text-muted {
-                                        (t().points_value_short(chore.points))
+                                        (t().points_value_short(activity.total_points()))
+
+                                        @if activity.quantity > 1 {
+                                            " (" (t().quantity_value_short(activity.quantity)) ")"
+                                        }
+
+                                        @if let Some(time) = activity.time {
+                                            " – " time datetime=(time.format("%H:%M")) { (time) }
+                                        }
+
+                                        @if activity.is_cooldown_violation {
+                                            " – " (t().cooldown_violation_hint())
+                                        }
 
                                         @if activity.comment.is_some() {
                                             " – " (t().has_comment())
@@ -167,12 +183,24 @@ pub fn list_activities(
                                     div.title { (chore.name) }
 
                                     small.text-muted {
-                                        (t().points_value_short(chore.points))
+                                        (t().points_value_short(activity.total_points()))
+
+                                        @if activity.quantity > 1 {
+                                            " (" (t().quantity_value_short(activity.quantity)) ")"
+                                        }
 
                                         " – " time datetime=(activity.date.format("%Y-%m-%d")) title=(activity.date.format("%Y-%m-%d")) {
                                             (format_date_long(activity.date))
                                         }
 
+                                        @if let Some(time) = activity.time {
+                                            ", " time datetime=(time.format("%H:%M")) { (time) }
+                                        }
+
+                                        @if activity.is_cooldown_violation {
+                                            " – " (t().cooldown_violation_hint())
+                                        }
+
                                         @if activity.comment.is_some() {
                                             " – " (t().has_comment())
                                         }
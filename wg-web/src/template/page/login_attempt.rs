@@ -0,0 +1,42 @@
+use maud::{html, Markup};
+use wg_core::model::login_attempt::LoginAttempt;
+use crate::handler::settings::SettingsIndexPath;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::GlobalNavigationItem;
+
+pub fn list(login_attempts: Vec<LoginAttempt>) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🕓")
+            .display_emoji(false)
+            .title(&t().login_history())
+            .back_url(SettingsIndexPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().login_history_help_text()) }
+
+            ul.card-container.collapse {
+                @for login_attempt in login_attempts.iter() {
+                    li {
+                        div.card {
+                            div.title { (login_attempt.user_agent.as_deref().unwrap_or(&t().unknown_device())) }
+
+                            small.text-muted {
+                                @if login_attempt.is_successful {
+                                    "✓ " (t().login_successful())
+                                } @else {
+                                    span.text-danger { "✗ " (t().login_failed()) }
+                                }
+                                " – " (login_attempt.date_created.format("%Y-%m-%d %H:%M"))
+                                " – " (t().session_ip_address(&login_attempt.ip_address))
+                            }
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
@@ -1,7 +1,12 @@
 pub mod authentication;
+pub mod authentication_session;
 pub mod error;
 pub mod chore_list;
 pub mod settings;
+pub mod two_factor_authentication;
 pub mod user;
+pub mod user_invitation;
 pub mod legal;
+pub mod login_attempt;
+pub mod passkey;
 pub mod absence;
@@ -0,0 +1,85 @@
+use maud::{html, Markup};
+use wg_core::model::passkey::Passkey;
+use wg_core::model::passkey_challenge::PasskeyChallenge;
+use wg_core::model::user::User;
+use wg_core::service::passkey::RelyingParty;
+use crate::handler::passkey::PasskeyCreatePath;
+use crate::handler::passkey::PasskeyDeletePath;
+use crate::handler::settings::SettingsIndexPath;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::GlobalNavigationItem;
+
+pub fn list(
+    user: User,
+    passkeys: Vec<Passkey>,
+    passkey_challenge: PasskeyChallenge,
+    relying_party: RelyingParty,
+) -> Markup {
+    let exclude_credential_ids = passkeys.iter()
+        .map(|passkey| passkey.credential_id.as_str())
+        .collect::<Vec<&str>>()
+        .join(",");
+
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔑")
+            .display_emoji(false)
+            .title(&t().passkeys())
+            .back_url(SettingsIndexPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().passkeys_help_text()) }
+
+            @if !passkeys.is_empty() {
+                ul.card-container.collapse {
+                    @for passkey in passkeys.iter() {
+                        li {
+                            div.card {
+                                div.title { (passkey.name) }
+
+                                small.text-muted {
+                                    (t().passkey_added_on(passkey.date_created.format("%Y-%m-%d")))
+
+                                    @if let Some(date_last_used) = passkey.date_last_used {
+                                        " – " (t().passkey_last_used_on(date_last_used.format("%Y-%m-%d")))
+                                    }
+                                }
+
+                                form method="post" action=(PasskeyDeletePath { passkey_id: passkey.id }) {
+                                    (csrf_token_input())
+                                    button.link.secondary.subtle.mb-0 type="submit" { "✗ " (t().delete_action()) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            form method="post" action=(PasskeyCreatePath)
+                data-passkey-register
+                data-challenge=(passkey_challenge.challenge)
+                data-rp-id=(relying_party.id)
+                data-user-id=(user.id)
+                data-user-name=(user.email.as_deref().unwrap_or(&user.name))
+                data-user-display-name=(user.name)
+                data-exclude-credential-ids=(exclude_credential_ids)
+            {
+                (csrf_token_input())
+                label for="name" { (t().name()) }
+                input #name name="name" type="text" required placeholder=(t().passkey_name_placeholder());
+
+                input type="hidden" name="credential_id";
+                input type="hidden" name="client_data_json";
+                input type="hidden" name="authenticator_data";
+                input type="hidden" name="public_key";
+                input type="hidden" name="public_key_algorithm";
+
+                button type="submit" { "+ " (t().add_passkey_action()) }
+            }
+        },
+    )
+}
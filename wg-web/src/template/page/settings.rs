@@ -2,14 +2,19 @@ use maud::{html, Markup};
 use strum::IntoEnumIterator;
 use wg_core::value::Language;
 use crate::handler::authentication::LogoutPath;
+use crate::handler::authentication_session::AuthenticationSessionIndexPath;
 use crate::extractor::language::LanguageSelection;
 use crate::handler::legal::PrivacyPolicyPath;
+use crate::handler::login_attempt::LoginAttemptIndexPath;
+use crate::handler::passkey::PasskeyIndexPath;
 use crate::handler::settings::SettingsAppearancePath;
 use crate::handler::settings::SettingsIndexPath;
 use crate::extractor::theme::Theme;
+use crate::handler::two_factor_authentication::TwoFactorAuthenticationPath;
 use crate::handler::user::UserIndexPath;
 use crate::handler::user::UserUpdatePath;
 use wg_core::model::authentication_session::AuthenticationSession;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
@@ -35,11 +40,31 @@ pub fn settings(auth_session: AuthenticationSession) -> Markup {
                             div.title { "🪪 " (t().edit_profile()) }
                         }
                     }
+                    li {
+                        a.card href=(PasskeyIndexPath) {
+                            div.title { "🔑 " (t().passkeys()) }
+                        }
+                    }
+                    li {
+                        a.card href=(TwoFactorAuthenticationPath) {
+                            div.title { "🔐 " (t().two_factor_authentication()) }
+                        }
+                    }
+                    li {
+                        a.card href=(AuthenticationSessionIndexPath) {
+                            div.title { "💻 " (t().sessions()) }
+                        }
+                    }
+                    li {
+                        a.card href=(LoginAttemptIndexPath) {
+                            div.title { "🕓 " (t().login_history()) }
+                        }
+                    }
                     li {
                         button.card.text-align-left.mb-0 type="submit" form="logout" {
                             div.title { "🚪 " (t().logout_action()) }
                         }
-                        form #logout method="post" action=(LogoutPath) { }
+                        form #logout method="post" action=(LogoutPath) { (csrf_token_input()) }
                     }
                 }
 
@@ -79,6 +104,7 @@ pub fn appearence(
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="language-selection" { (t().language()) }
                 select.auto-submit #language-selection name="language" required {
                     option value=(LanguageSelection::Auto.to_string()) selected[language_selection == LanguageSelection::Auto] {
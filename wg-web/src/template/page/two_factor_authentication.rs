@@ -0,0 +1,107 @@
+use maud::{html, Markup, PreEscaped};
+use secrecy::{ExposeSecret, SecretString};
+use wg_core::value::TotpSecret;
+use crate::handler::settings::SettingsIndexPath;
+use crate::handler::two_factor_authentication::TwoFactorAuthenticationDisablePath;
+use crate::handler::two_factor_authentication::TwoFactorAuthenticationEnablePath;
+use crate::handler::two_factor_authentication::TwoFactorAuthenticationPath;
+use crate::handler::two_factor_authentication::TwoFactorAuthenticationRecoveryCodesPath;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+use crate::template::partial;
+use crate::template::partial::navigation::GlobalNavigationItem;
+
+pub fn enroll(totp_secret: TotpSecret, url: &str) -> Markup {
+    let qr_code = qrcode::QrCode::new(url)
+        .unwrap()
+        .render::<qrcode::render::svg::Color>()
+        .min_dimensions(200, 200)
+        .build();
+
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔐")
+            .display_emoji(false)
+            .title(&t().two_factor_authentication())
+            .back_url(SettingsIndexPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().two_factor_enroll_help_text()) }
+
+            div { (PreEscaped(qr_code)) }
+
+            p {
+                small.text-muted {
+                    (t().two_factor_secret()) ": "
+                    code { (totp_secret.expose_encoded()) }
+                }
+            }
+
+            form method="post" action=(TwoFactorAuthenticationEnablePath) {
+                (csrf_token_input())
+                input type="hidden" name="secret" value=(totp_secret.expose_encoded());
+
+                label for="code" { (t().two_factor_code()) }
+                input #code name="code" type="text" required inputmode="numeric" autocomplete="one-time-code";
+
+                button type="submit" { (t().enable_action()) }
+            }
+        },
+    )
+}
+
+pub fn enabled() -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔐")
+            .display_emoji(false)
+            .title(&t().two_factor_authentication())
+            .back_url(SettingsIndexPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().two_factor_enabled_text()) }
+
+            form method="post" action=(TwoFactorAuthenticationRecoveryCodesPath) {
+                (csrf_token_input())
+                label for="recovery_codes_code" { (t().two_factor_code()) }
+                input #recovery_codes_code name="code" type="text" required autocomplete="one-time-code";
+
+                button.secondary type="submit" { (t().regenerate_recovery_codes_action()) }
+            }
+
+            form method="post" action=(TwoFactorAuthenticationDisablePath) {
+                (csrf_token_input())
+                label for="disable_code" { (t().two_factor_code()) }
+                input #disable_code name="code" type="text" required autocomplete="one-time-code";
+
+                button.secondary type="submit" { (t().disable_action()) }
+            }
+        },
+    )
+}
+
+pub fn recovery_codes(recovery_codes: Vec<SecretString>) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .emoji("🔐")
+            .display_emoji(false)
+            .title(&t().recovery_codes())
+            .back_url(TwoFactorAuthenticationPath.to_string().as_str())
+            .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
+            .build(),
+        html! {
+            p { (t().recovery_codes_help_text()) }
+
+            ul {
+                @for recovery_code in recovery_codes.iter() {
+                    li { code { (recovery_code.expose_secret()) } }
+                }
+            }
+
+            a href=(TwoFactorAuthenticationPath) { (t().done_action()) }
+        },
+    )
+}
@@ -5,7 +5,15 @@ use crate::handler::user::UserDeletePath;
 use crate::handler::user::UserDetailPath;
 use crate::handler::user::UserIndexPath;
 use crate::handler::user::UserRestorePath;
+use crate::handler::user::UserRoleUpdatePath;
+use crate::handler::user_invitation::UserInvitationRevokePath;
 use wg_core::model::user;
+use strum::IntoEnumIterator;
+use wg_core::model::user::Permission;
+use wg_core::model::user::UserKind;
+use wg_core::model::user::UserRole;
+use wg_core::model::user_invitation::UserInvitation;
+use crate::template::helper::csrf_token_input;
 use crate::template::helper::t;
 use crate::template::layout;
 use crate::template::partial;
@@ -14,6 +22,8 @@ use crate::template::partial::navigation::GlobalNavigationItem;
 pub fn list(
     users: Vec<user::User>,
     deleted_users: Vec<user::User>,
+    invitations: Vec<UserInvitation>,
+    can_manage_users: bool,
 ) -> Markup {
     layout::default(
         layout::DefaultLayoutOptions::builder()
@@ -21,7 +31,9 @@ pub fn list(
             .title(&t().users())
             .back_url(SettingsIndexPath.to_string().as_str())
             .meta_actions(html! {
-                a.secondary.subtle href=(UserCreatePath) { "+ " (t().add_action()) }
+                @if can_manage_users {
+                    a.secondary.subtle href=(UserCreatePath) { "+ " (t().add_action()) }
+                }
             })
             .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
             .build(),
@@ -31,6 +43,35 @@ pub fn list(
                     li {
                         a.card href=(UserDetailPath { user_id: user.id }) {
                             div.title { (user.name) }
+
+                            @if !user.is_scoring() {
+                                small.text-muted { (user_kind_label(user.kind)) }
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if !invitations.is_empty() {
+                h4 { (t().pending_invitations()) }
+
+                ul.card-container.collapse {
+                    @for invitation in invitations.iter() {
+                        li {
+                            div.card {
+                                div.title { (invitation.name) }
+
+                                small.text-muted {
+                                    (invitation.email)
+                                    " – "
+                                    (t().invitation_valid_until(invitation.date_expires.format("%Y-%m-%d")))
+                                }
+
+                                form method="post" action=(UserInvitationRevokePath { user_invitation_id: invitation.id }) {
+                                    (csrf_token_input())
+                                    button.link.secondary.subtle.mb-0 type="submit" { "✗ " (t().revoke_action()) }
+                                }
+                            }
                         }
                     }
                 }
@@ -56,19 +97,23 @@ pub fn list(
     )
 }
 
-pub fn detail(user: user::User) -> Markup {
+pub fn detail(user: user::User, current_user: user::User) -> Markup {
+    let can_manage_user = current_user.has_permission(Permission::ManageUsers) && user.id != current_user.id;
+
     layout::default(
         layout::DefaultLayoutOptions::builder()
             .emoji("👤")
             .title(&user.name)
             .back_url(UserIndexPath.to_string().as_str())
             .meta_actions(html! {
-                @if user.is_deleted() {
-                    button.link.secondary.subtle.mb-0 type="submit" form="user_restore" { "↻ " (t().restore_action()) }
-                    form #user_restore method="post" action=(UserRestorePath { user_id: user.id }) { }
-                } @else {
-                    button.link.secondary.subtle.mb-0 type="submit" form="user_delete" { "✗ " (t().delete_action()) }
-                    form #user_delete method="post" action=(UserDeletePath { user_id: user.id }) { }
+                @if can_manage_user {
+                    @if user.is_deleted() {
+                        button.link.secondary.subtle.mb-0 type="submit" form="user_restore" { "↻ " (t().restore_action()) }
+                        form #user_restore method="post" action=(UserRestorePath { user_id: user.id }) { (csrf_token_input()) }
+                    } @else {
+                        button.link.secondary.subtle.mb-0 type="submit" form="user_delete" { "✗ " (t().delete_action()) }
+                        form #user_delete method="post" action=(UserDeletePath { user_id: user.id }) { (csrf_token_input()) }
+                    }
                 }
             })
             .navigation(partial::navigation::global(Some(GlobalNavigationItem::Settings)))
@@ -78,6 +123,38 @@ pub fn detail(user: user::User) -> Markup {
                 div {
                     em { (t().user_has_been_deleted()) }
                 }
+
+                br;
+            }
+
+            dl {
+                dt { (t().user_kind()) }
+                dd { (user_kind_label(user.kind)) }
+
+                @if user.is_scoring() {
+                    dt { (t().user_role()) }
+                    dd { (user_role_label(user.role)) }
+                }
+
+                @if let Some(email) = &user.email {
+                    dt { (t().email_address()) }
+                    dd { (email) }
+                }
+            }
+
+            @if can_manage_user && user.is_scoring() && !user.is_deleted() {
+                form method="post" action=(UserRoleUpdatePath { user_id: user.id }) {
+                    (csrf_token_input())
+                    label for="role" { (t().user_role()) }
+                    select #role name="role" required aria-describedby="role-help-text" {
+                        @for role in UserRole::iter() {
+                            option value=(role) selected[role == user.role] { (user_role_label(role)) }
+                        }
+                    }
+                    small #role-help-text { (t().user_role_help_text()) }
+
+                    button type="submit" { (t().save_action()) }
+                }
             }
         },
     )
@@ -93,14 +170,24 @@ pub fn create() -> Markup {
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required autocomplete="given-name";
 
-                label for="email" { (t().email_address()) }
-                input #email name="email" type="email" required autocomplete="email";
+                label for="kind" { (t().user_kind()) }
+                select #kind name="kind" required aria-describedby="kind-help-text" {
+                    option selected value=(UserKind::Member) { (user_kind_label(UserKind::Member)) }
+                    option value=(UserKind::NonScoring) { (user_kind_label(UserKind::NonScoring)) }
+                }
+                small #kind-help-text { (t().user_kind_help_text()) }
 
-                label for="password" { (t().password()) }
-                input #password name="password" type="password" required minlength="5" autocomplete="current-password";
+                label for="email" {
+                    (t().email_address())
+                    " "
+                    i.text-muted { "(" (t().only_for_members()) ")" }
+                }
+                input #email name="email" type="email" autocomplete="email" aria-describedby="email-help-text";
+                small #email-help-text { (t().user_invitation_help_text()) }
 
                 button type="submit" { (t().create_action()) }
             }
@@ -119,11 +206,12 @@ pub fn update(user: user::User) -> Markup {
             .build(),
         html! {
             form method="post" {
+                (csrf_token_input())
                 label for="name" { (t().name()) }
                 input #name name="name" type="text" required autocomplete="given-name" value=(user.name);
 
                 label for="email" { (t().email_address()) }
-                input #email name="email" type="email" required autocomplete="email" value=(user.email);
+                input #email name="email" type="email" required autocomplete="email" value=[user.email];
 
                 label for="password" {
                     (t().new_password())
@@ -137,3 +225,17 @@ pub fn update(user: user::User) -> Markup {
         },
     )
 }
+
+fn user_role_label(role: UserRole) -> String {
+    match role {
+        UserRole::Admin => t().user_role_admin().to_string(),
+        UserRole::Member => t().user_role_member().to_string(),
+    }
+}
+
+fn user_kind_label(kind: UserKind) -> String {
+    match kind {
+        UserKind::Member => t().user_kind_member().to_string(),
+        UserKind::NonScoring => t().user_kind_non_scoring().to_string(),
+    }
+}
@@ -0,0 +1,31 @@
+use maud::{html, Markup};
+use wg_core::model::user_invitation::UserInvitation;
+use wg_core::value::PasswordHash;
+use crate::template::helper::csrf_token_input;
+use crate::template::helper::t;
+use crate::template::layout;
+
+pub fn accept(invitation: UserInvitation) -> Markup {
+    layout::default(
+        layout::DefaultLayoutOptions::builder()
+            .title(&t().accept_invitation())
+            .build(),
+        html! {
+            p { (t().accept_invitation_help_text()) }
+
+            form method="post" {
+                (csrf_token_input())
+                label for="name" { (t().name()) }
+                input #name type="text" value=(invitation.name) disabled;
+
+                label for="email" { (t().email_address()) }
+                input #email type="email" autocomplete="email" value=(invitation.email) disabled;
+
+                label for="password" { (t().password()) }
+                input #password name="password" type="password" required minlength=(PasswordHash::MIN_LENGTH) autocomplete="new-password" autofocus;
+
+                button type="submit" { (t().accept_invitation_action()) }
+            }
+        },
+    )
+}
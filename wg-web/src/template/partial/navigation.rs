@@ -4,6 +4,7 @@ use crate::handler::chore::ChoreIndexPath;
 use crate::handler::chore_activity::ChoreActivityIndexPath;
 use crate::handler::chore_list::{ChoreListIndexPath, ChoreListSettingsPath};
 use crate::handler::chore_list_user::ChoreListUserIndexPath;
+use crate::handler::reward::RewardIndexPath;
 use crate::handler::settings::SettingsIndexPath;
 use crate::template::helper::t;
 use wg_core::model::chore_list;
@@ -45,6 +46,7 @@ pub enum ChoreListNavigationItem {
     Activities,
     Chores,
     Users,
+    Rewards,
     Settings,
 }
 
@@ -69,6 +71,12 @@ pub fn chore_list(chore_list: &chore_list::ChoreList, active_item: Option<ChoreL
                     div.label { (t().users()) }
                 }
             }
+            li {
+                a href=(RewardIndexPath { chore_list_id: chore_list.id }) aria-current=[if active_item == Some(ChoreListNavigationItem::Rewards) { Some("page") } else { None }] {
+                    div.icon { "🎁" }
+                    div.label { (t().rewards()) }
+                }
+            }
             li {
                 a href=(ChoreListSettingsPath { chore_list_id: chore_list.id }) aria-current=[if active_item == Some(ChoreListNavigationItem::Settings) { Some("page") } else { None }] {
                     div.icon { "⚙️" }
@@ -6,3 +6,108 @@ document.querySelectorAll('.auto-submit')
       }
     })
   })
+
+document.querySelectorAll('select[data-checklist-select]')
+  .forEach((select) => {
+    const showChecklistOfSelectedChore = () => {
+      select.form.querySelectorAll('fieldset[data-checklist-chore-id]')
+        .forEach((fieldset) => {
+          const isSelected = fieldset.dataset.checklistChoreId === select.value
+          fieldset.hidden = !isSelected
+          fieldset.disabled = !isSelected
+        })
+    }
+
+    select.addEventListener('change', showChecklistOfSelectedChore)
+    showChecklistOfSelectedChore()
+  })
+
+const encodeBase64Url = (buffer) => btoa(String.fromCharCode(...new Uint8Array(buffer)))
+  .replace(/\+/g, '-')
+  .replace(/\//g, '_')
+  .replace(/=+$/, '')
+
+const decodeBase64Url = (string) => Uint8Array.from(
+  atob(string.replace(/-/g, '+').replace(/_/g, '/')),
+  (char) => char.charCodeAt(0),
+)
+
+const isPasskeySupported = 'PublicKeyCredential' in window
+
+document.querySelectorAll('form[data-passkey-register]')
+  .forEach((form) => {
+    if (!isPasskeySupported) {
+      form.querySelector('button[type="submit"]').disabled = true
+      return
+    }
+
+    form.addEventListener('submit', async (event) => {
+      if (form.elements.credential_id.value) {
+        return
+      }
+
+      event.preventDefault()
+
+      const credential = await navigator.credentials.create({
+        publicKey: {
+          challenge: decodeBase64Url(form.dataset.challenge),
+          rp: { id: form.dataset.rpId, name: 'WG' },
+          user: {
+            id: new TextEncoder().encode(form.dataset.userId),
+            name: form.dataset.userName,
+            displayName: form.dataset.userDisplayName,
+          },
+          pubKeyCredParams: [{ type: 'public-key', alg: -7 }],
+          excludeCredentials: form.dataset.excludeCredentialIds
+            .split(',')
+            .filter((id) => id)
+            .map((id) => ({ type: 'public-key', id: decodeBase64Url(id) })),
+          authenticatorSelection: { residentKey: 'required', userVerification: 'required' },
+          attestation: 'none',
+        },
+      })
+
+      form.elements.credential_id.value = encodeBase64Url(credential.rawId)
+      form.elements.client_data_json.value = encodeBase64Url(credential.response.clientDataJSON)
+      form.elements.authenticator_data.value = encodeBase64Url(credential.response.getAuthenticatorData())
+      form.elements.public_key.value = encodeBase64Url(credential.response.getPublicKey())
+      form.elements.public_key_algorithm.value = credential.response.getPublicKeyAlgorithm()
+      form.submit()
+    })
+  })
+
+document.querySelectorAll('form[data-passkey-login]')
+  .forEach((form) => {
+    if (!isPasskeySupported) {
+      return
+    }
+
+    form.hidden = false
+
+    form.addEventListener('submit', async (event) => {
+      event.preventDefault()
+
+      const response = await fetch(form.dataset.challengeUrl, {
+        method: 'POST',
+        headers: { 'X-CSRF-Token': form.elements.csrf_token.value },
+      })
+      if (!response.ok) {
+        return
+      }
+      const { challenge, rp_id: rpId } = await response.json()
+
+      const credential = await navigator.credentials.get({
+        publicKey: {
+          challenge: decodeBase64Url(challenge),
+          rpId,
+          userVerification: 'required',
+        },
+      })
+
+      form.elements.credential_id.value = encodeBase64Url(credential.rawId)
+      form.elements.client_data_json.value = encodeBase64Url(credential.response.clientDataJSON)
+      form.elements.authenticator_data.value = encodeBase64Url(credential.response.authenticatorData)
+      form.elements.signature.value = encodeBase64Url(credential.response.signature)
+      form.submit()
+    })
+  })
@@ -13,6 +13,8 @@ edit_action = Bearbeiten
 save_action = Speichern
 delete_action = Löschen
 restore_action = Wiederherstellen
+cancel_action = Abbrechen
+deleted_hint = Gelöscht
 of_x = Von {$x}
 of_x_in_y = Von {$x} in {$y}
 today = Heute
@@ -47,12 +49,146 @@ points = Punkte
 points_value_short = {$points}P
 interval = Intervall
 chore_interval_help_text = Wie oft die Aufgabe erledigt werden sollte
+cooldown = Sperrfrist
+chore_cooldown_help_text = Mindestabstand zwischen zwei Aktivitäten, die Punkte bringen
+bounty = Prämie
+bounty_value_short = {$points}P Prämie
+bounty_per_overdue_day = Prämie pro überfälligem Tag
+bounty_max = Maximale Prämie
+chore_bounty_help_text = Zusätzliche Punkte, die für jeden Tag hinzukommen, an dem die Aufgabe überfällig ist
+chore_bounty_max_help_text = Obergrenze für die Prämie. Leer lassen für keine Obergrenze
+bounty_per_day = {$points}P pro überfälligem Tag
+bounty_per_day_with_max = {$points}P pro überfälligem Tag, bis zu {$max_points}P
+task = Einmalaufgabe
+create_task = Einmalaufgabe erstellen
+done_tasks = Erledigte Einmalaufgaben
+due_date = Fälligkeitsdatum
+task_due_date_help_text = Eine Einmalaufgabe ist erledigt, sobald die erste Aktivität dafür eingetragen wurde
+task_open = Einmalig, noch offen
+task_completed_on = Einmalig, erledigt am {$date}
+quantity = Menge
+max_quantity = Maximale Menge
+units = Einheiten
+chore_max_quantity_help_text = Erlaubt es, bei Aktivitäten die erledigte Menge anzugeben (z. B. Müllsäcke). Die Punkte werden pro Einheit vergeben. Leer lassen zum Deaktivieren
+per_unit_up_to_n = Punkte pro Einheit, bis zu {$max_quantity} Einheiten pro Aktivität
+quantity_value_short = {$quantity}×
+quantity_times_points = {$quantity} × {$points}P
+activity_quantity_help_text = Nur für Aufgaben, die in Einheiten gezählt werden
+checklist = Checkliste
+chore_checklist_help_text = Ein Schritt pro Zeile, in der Reihenfolge, in der sie erledigt werden sollen
+award_partial_checklist_points = Anteilige Punkte vergeben, wenn nur einige Schritte erledigt wurden
+follow_up_of = Folgeaufgabe von
+follow_up_of_none = Keine
+follow_ups = Folgeaufgaben
+follow_up_delay = Verzögerung nach der anderen Aufgabe
+chore_follow_up_help_text = Die Aufgabe wird fällig, sobald die andere Aufgabe erledigt wurde
+season = Saison
+season_from = Von
+season_until = Bis
+chore_season_help_text = Außerhalb dieser Monate ruht die Aufgabe und wird nicht fällig
+dormant_hint = 💤 Ruht
+excluded_users = Ausgenommene Benutzer
+chore_excluded_users_help_text = Benutzer, die diese Aufgabe nicht erledigen können. Sie werden nicht daran erinnert und ihre Punktzahl wird entsprechend angepasst.
+available_points_value = {$available_points} von {$total_points} Punkten verfügbar
+user_kind = Art
+user_kind_member = Mitglied
+user_kind_non_scoring = Teilnehmer ohne Punkte
+user_kind_help_text = Teilnehmer ohne Punkte, z.B. eine Reinigungskraft, können sich nicht anmelden und sammeln keine Punkte. Andere erfassen Aktivitäten für sie.
+user_role = Rolle
+user_role_admin = Administrator
+user_role_member = Mitglied
+user_role_help_text = Administratoren verwalten die Benutzer und können Aufgabenlisten löschen.
+only_for_members = nur für Mitglieder
+done_by = Erledigt von
+postpone_chore = Überspringen oder aufschieben
+postpone_chore_text = Verschieben Sie das Fälligkeitsdatum, wenn die Aufgabe gerade nicht erledigt werden muss. Dafür gibt es keine Punkte.
+postponement_kind = Aktion
+postponement_kind_skip = Dieses Mal überspringen
+postponement_kind_snooze = Aufschieben
+snooze_for = Aufschieben um
+only_for_snoozing = nur beim Aufschieben
+reason = Grund
+postpone_action = Fälligkeitsdatum verschieben
+postponements = Übersprungen & Aufgeschoben
+postponed_from_to = Fälligkeitsdatum von {$previous_due_date} auf {$new_due_date} verschoben
+month_name = {$month ->
+    [1] Januar
+    [2] Februar
+    [3] März
+    [4] April
+    [5] Mai
+    [6] Juni
+    [7] Juli
+    [8] August
+    [9] September
+    [10] Oktober
+    [11] November
+   *[other] Dezember
+}
+follow_up_delay_value = {$days ->
+    [0] am selben Tag
+    [one] 1 Tag später
+   *[other] {$days} Tage später
+}
+points_voting_method = Punkte-Abstimmung
+points_voting_method_none = Aus (feste Punkte)
+points_voting_method_median = Median aller Stimmen
+points_voting_method_mean = Durchschnitt aller Stimmen
+points_voting_method_help_text = Alle Benutzer stimmen darüber ab, wie viele Punkte die Aufgabe wert ist. Bis zur ersten Stimme werden die obigen Punkte verwendet
+votes = Stimmen
+your_vote = Ihre Stimme
+vote_pending = Noch keine Stimme
+vote_action = Abstimmen
+retract_vote_action = Stimme zurückziehen
+
+rewards = Belohnungen
+deleted_rewards = Gelöschte Belohnungen
+reward_has_been_deleted = Diese Belohnung wurde gelöscht
+create_reward = Belohnung erstellen
+edit_reward = Belohnung bearbeiten
+no_rewards = Es wurden noch keine Belohnungen hinzugefügt
+price = Preis
+your_balance = Ihr Guthaben: {$balance}P
+redeem_action = Einlösen
+redemptions = Einlösungen
+undone_redemptions = Rückgängig gemachte Einlösungen
+no_redemptions = Es wurde noch keine Belohnung eingelöst
+undo_action = Rückgängig machen
+
+swap_requests = Tauschanfragen
+closed_swap_requests = Abgeschlossene Tauschanfragen
+no_open_swap_requests = Es gibt keine offenen Tauschanfragen
+create_swap_request = Jemanden bitten, zu übernehmen
+send_swap_request_action = Anfrage senden
+swap_request_target_user = Wer soll übernehmen?
+swap_request_from_to = {$from} → {$to}
+offered_points = Angebotene Punkte
+offered_points_help_text = Diese Punkte werden von Ihnen an die andere Person übertragen, sobald diese die Aufgabe erfasst hat
+accept_action = Annehmen
+decline_action = Ablehnen
+swap_request_status_pending = Wartet auf Antwort
+swap_request_status_accepted = Angenommen
+swap_request_status_declined = Abgelehnt
+swap_request_status_cancelled = Abgebrochen
+swap_request_status_completed = Erledigt
+anyone = Alle
+take_over_action = Übernehmen
+open_handover_offers = Offene Übergaben
+handover_offered_by = Angeboten von {$name}
 next_due_date = Nächstes Fälligkeitsdatum
 due_hint = Fällig!
 every_n_days = {$n ->
     [one] jeden Tag
    *[other] alle {$n} Tage
 }
+interval_in_hours = Intervall in Stunden
+chore_interval_hours_help_text = Für Aufgaben, die mehrmals am Tag fällig sind, anstelle eines Intervalls in Tagen
+hours = Stunden
+every_n_hours = {$n ->
+    [one] jede Stunde
+   *[other] alle {$n} Stunden
+}
+time = Uhrzeit
 days = Tage
 duration = Dauer
 unknown = unbekannt
@@ -67,6 +203,16 @@ deleted_activities = Gelöschte Aktivitäten
 activity_has_been_deleted = Diese Aktivität wurde gelöscht
 create_activity = Aktivität erstellen
 edit_activity = Aktivität bearbeiten
+cooldown_violation = Zu früh
+cooldown_violations = Sperrfrist-Verstöße
+no_cooldown_violations = Bisher wurde keine Aktivität innerhalb einer Sperrfrist erfasst
+cooldown_violation_hint = Keine Punkte (Sperrfrist)
+cooldown_violation_no_points = Keine, innerhalb der Sperrfrist erfasst
+cooldown_violation_warning = "{$chore_name}" hat eine Sperrfrist von {$cooldown_days ->
+        [one] 1 Tag
+       *[other] {$cooldown_days} Tagen
+    } und wurde bereits am {$date} erledigt. Sie können diese Aktivität trotzdem erfassen, sie bringt aber keine Punkte.
+create_without_points_action = Ohne Punkte erfassen
 
 ## Settings
 
@@ -99,12 +245,58 @@ email_address = E-Mail-Adresse
 password = Passwort
 new_password = Neues Passwort
 create_user = Benutzer erstellen
+pending_invitations = Offene Einladungen
+invitation_valid_until = gültig bis {$date}
+user_invitation_help_text = Mitglieder erhalten eine Einladung per E-Mail und legen ihr Passwort selbst fest.
+revoke_action = Zurückziehen
+accept_invitation = Einladung annehmen
+accept_invitation_help_text = Legen Sie ein Passwort fest, um Ihr Konto zu aktivieren. Danach können Sie sich mit Ihrer E-Mail-Adresse anmelden.
+accept_invitation_action = Konto aktivieren
 
 ## Authentication
 
 login = Login
 login_action = Anmelden
 logout_action = Abmelden
+forgot_password = Passwort vergessen?
+forgot_password_help_text = Geben Sie die E-Mail-Adresse Ihres Kontos ein und wir senden Ihnen einen Link, um ein neues Passwort festzulegen.
+send_reset_link_action = Link senden
+password_reset_requested_text = Falls ein Konto mit dieser E-Mail-Adresse existiert, wurde ein Link zum Festlegen eines neuen Passworts dorthin gesendet. Der Link ist eine Stunde lang gültig.
+reset_password = Passwort zurücksetzen
+reset_password_action = Passwort festlegen
+login_link_help_text = Alternativ senden wir Ihnen einen Link per E-Mail, mit dem Sie sich ohne Passwort anmelden.
+send_login_link_action = Login-Link senden
+login_link_requested_text = Falls ein Konto mit dieser E-Mail-Adresse existiert, wurde ein Login-Link dorthin gesendet. Der Link ist 15 Minuten lang gültig.
+two_factor_authentication = Zwei-Faktor-Authentifizierung
+two_factor_code = Code
+two_factor_code_help_text = Geben Sie den Code aus Ihrer Authenticator-App oder einen Ihrer Wiederherstellungscodes ein.
+two_factor_enroll_help_text = Scannen Sie den QR-Code mit einer Authenticator-App und geben Sie den angezeigten Code ein, um die Zwei-Faktor-Authentifizierung zu aktivieren.
+two_factor_secret = Schlüssel zur manuellen Eingabe
+two_factor_enabled_text = Die Zwei-Faktor-Authentifizierung ist aktiviert. Geben Sie einen Code aus Ihrer Authenticator-App ein, um neue Wiederherstellungscodes zu erhalten oder sie zu deaktivieren.
+recovery_codes = Wiederherstellungscodes
+recovery_codes_help_text = Bewahren Sie diese Codes an einem sicheren Ort auf. Jeder davon kann einmal zum Anmelden verwendet werden, falls Sie Ihre Authenticator-App nicht zur Hand haben. Sie werden nicht erneut angezeigt.
+enable_action = Aktivieren
+disable_action = Deaktivieren
+regenerate_recovery_codes_action = Neue Wiederherstellungscodes
+passkeys = Passkeys
+passkeys_help_text = Mit Passkeys melden Sie sich per Fingerabdruck, Gesichtserkennung oder Geräte-PIN statt mit Ihrem Passwort an.
+passkey_name_placeholder = z.B. Mein Handy
+passkey_added_on = Hinzugefügt am {$date}
+passkey_last_used_on = zuletzt verwendet am {$date}
+add_passkey_action = Passkey hinzufügen
+login_with_passkey_action = Mit Passkey anmelden
+sessions = Sitzungen
+sessions_help_text = Auf diesen Geräten sind Sie angemeldet. Melden Sie Geräte ab, die Sie nicht kennen oder nicht mehr haben.
+current_session = Dieses Gerät
+unknown_device = Unbekanntes Gerät
+session_created_on = Angemeldet am {$date}
+session_last_used_on = zuletzt verwendet am {$date}
+session_ip_address = IP-Adresse {$ip_address}
+revoke_other_sessions_action = Überall sonst abmelden
+login_history = Anmeldeverlauf
+login_history_help_text = Die letzten Anmeldeversuche bei Ihrem Konto. Sie erhalten eine E-Mail, wenn sich jemand von einem bisher nicht verwendeten Gerät oder Netzwerk anmeldet.
+login_successful = Erfolgreich
+login_failed = Fehlgeschlagen
 
 ## Absence
 
@@ -117,6 +309,13 @@ create_absence = Abwesenheit erstellen
 edit_absence = Abwesenheit bearbeiten
 absence_start_date = Abreisedatum
 absence_end_date = Rückreisedatum
+absence_handover = Fällige Aufgaben übergeben
+absence_handover_help_text = Diese Aufgaben werden während Ihrer Abwesenheit fällig. Bieten Sie sie den anderen an, damit jemand übernehmen kann.
+absence_no_due_chores = Während Ihrer Abwesenheit werden keine Aufgaben fällig
+absence_handover_offer_action = Anderen anbieten
+absence_handover_offered = Den anderen angeboten
+absence_handover_taken_over = Übernommen
+done_action = Fertig
 n_days = {$n ->
     [one] 1 Tag
    *[other] {$n} Tage
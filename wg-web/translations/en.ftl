@@ -13,6 +13,8 @@ edit_action = Edit
 save_action = Save
 delete_action = Delete
 restore_action = Restore
+cancel_action = Cancel
+deleted_hint = Deleted
 of_x = Of {$x}
 of_x_in_y = Of {$x} in {$y}
 today = Today
@@ -47,12 +49,146 @@ points = Points
 points_value_short = {$points}P
 interval = Interval
 chore_interval_help_text = How often the chore should be done
+cooldown = Cooldown
+chore_cooldown_help_text = Minimum time between two activities that earn points
+bounty = Bounty
+bounty_value_short = {$points}P bounty
+bounty_per_overdue_day = Bounty per overdue day
+bounty_max = Maximum bounty
+chore_bounty_help_text = Extra points that are added for every day the chore is overdue
+chore_bounty_max_help_text = Upper limit for the bounty. Leave empty for no limit
+bounty_per_day = {$points}P per overdue day
+bounty_per_day_with_max = {$points}P per overdue day, up to {$max_points}P
+task = Task
+create_task = Create Task
+done_tasks = Done Tasks
+due_date = Due Date
+task_due_date_help_text = A task is done once the first activity has been logged for it
+task_open = One-off, still open
+task_completed_on = One-off, done on {$date}
+quantity = Quantity
+max_quantity = Maximum quantity
+units = Units
+chore_max_quantity_help_text = Lets activities record how many units were done (e.g. bags of trash). The points are awarded per unit. Leave empty to disable
+per_unit_up_to_n = Points per unit, up to {$max_quantity} units per activity
+quantity_value_short = {$quantity}×
+quantity_times_points = {$quantity} × {$points}P
+activity_quantity_help_text = Only used for chores that are counted in units
+checklist = Checklist
+chore_checklist_help_text = One step per line, in the order they should be done
+award_partial_checklist_points = Award a share of the points if only some steps were done
+follow_up_of = Follow-up of
+follow_up_of_none = None
+follow_ups = Follow-ups
+follow_up_delay = Delay after the other chore
+chore_follow_up_help_text = The chore becomes due once the other chore has been done
+season = Season
+season_from = From
+season_until = Until
+chore_season_help_text = Outside of these months the chore is dormant and doesn't come due
+dormant_hint = 💤 Dormant
+excluded_users = Excluded users
+chore_excluded_users_help_text = Users who can't do this chore. They won't be reminded of it and their score is adjusted accordingly.
+available_points_value = {$available_points} of {$total_points} points available
+user_kind = Kind
+user_kind_member = Member
+user_kind_non_scoring = Non-scoring participant
+user_kind_help_text = Non-scoring participants, e.g. a paid cleaner, can't log in and don't collect points. Others record activities for them.
+user_role = Role
+user_role_admin = Admin
+user_role_member = Member
+user_role_help_text = Admins manage the users and can delete chore lists.
+only_for_members = only for members
+done_by = Done by
+postpone_chore = Skip or snooze
+postpone_chore_text = Move the due date if the chore doesn't need to be done right now. Nobody gets points for this.
+postponement_kind = Action
+postponement_kind_skip = Skip this occurrence
+postponement_kind_snooze = Snooze
+snooze_for = Snooze for
+only_for_snoozing = only for snoozing
+reason = Reason
+postpone_action = Move due date
+postponements = Skipped & Snoozed
+postponed_from_to = Due date moved from {$previous_due_date} to {$new_due_date}
+month_name = {$month ->
+    [1] January
+    [2] February
+    [3] March
+    [4] April
+    [5] May
+    [6] June
+    [7] July
+    [8] August
+    [9] September
+    [10] October
+    [11] November
+   *[other] December
+}
+follow_up_delay_value = {$days ->
+    [0] on the same day
+    [one] 1 day later
+   *[other] {$days} days later
+}
+points_voting_method = Points Voting
+points_voting_method_none = Off (fixed points)
+points_voting_method_median = Median of all votes
+points_voting_method_mean = Average of all votes
+points_voting_method_help_text = Let every user vote on how many points the chore is worth. The points above are used until the first vote is cast
+votes = Votes
+your_vote = Your vote
+vote_pending = No vote yet
+vote_action = Vote
+retract_vote_action = Retract vote
+
+rewards = Rewards
+deleted_rewards = Deleted Rewards
+reward_has_been_deleted = This reward has been deleted
+create_reward = Create Reward
+edit_reward = Edit Reward
+no_rewards = No rewards have been added yet
+price = Price
+your_balance = Your balance: {$balance}P
+redeem_action = Redeem
+redemptions = Redemptions
+undone_redemptions = Undone Redemptions
+no_redemptions = No reward has been redeemed yet
+undo_action = Undo
+
+swap_requests = Swap Requests
+closed_swap_requests = Closed Swap Requests
+no_open_swap_requests = There are no open swap requests
+create_swap_request = Ask someone to take over
+send_swap_request_action = Send request
+swap_request_target_user = Who should take over?
+swap_request_from_to = {$from} → {$to}
+offered_points = Offered points
+offered_points_help_text = These points are transferred from you to the other person once they have logged the chore
+accept_action = Accept
+decline_action = Decline
+swap_request_status_pending = Waiting for answer
+swap_request_status_accepted = Accepted
+swap_request_status_declined = Declined
+swap_request_status_cancelled = Cancelled
+swap_request_status_completed = Done
+anyone = Anyone
+take_over_action = Take over
+open_handover_offers = Open Handover Offers
+handover_offered_by = Offered by {$name}
 next_due_date = Next Due Date
 due_hint = Due!
 every_n_days = {$n ->
     [one] every day
    *[other] every {$n} days
 }
+interval_in_hours = Interval in hours
+chore_interval_hours_help_text = For chores which are due several times a day, instead of an interval in days
+hours = hours
+every_n_hours = {$n ->
+    [one] every hour
+   *[other] every {$n} hours
+}
+time = Time
 days = days
 duration = Duration
 unknown = unknown
@@ -67,6 +203,16 @@ deleted_activities = Deleted Activities
 activity_has_been_deleted = This activity has been deleted
 create_activity = Create Activity
 edit_activity = Edit Activity
+cooldown_violation = Too soon
+cooldown_violations = Cooldown Violations
+no_cooldown_violations = No activity has been logged within a cooldown yet
+cooldown_violation_hint = No points (cooldown)
+cooldown_violation_no_points = None, logged within the cooldown
+cooldown_violation_warning = "{$chore_name}" has a cooldown of {$cooldown_days ->
+        [one] 1 day
+       *[other] {$cooldown_days} days
+    } and was already done on {$date}. You can still log this activity, but it will not earn any points.
+create_without_points_action = Log without points
 
 ## Settings
 
@@ -99,12 +245,58 @@ email_address = E-Mail-Address
 password = Password
 new_password = New Password
 create_user = Create User
+pending_invitations = Pending invitations
+invitation_valid_until = valid until {$date}
+user_invitation_help_text = Members receive an invitation by e-mail and set their password themselves.
+revoke_action = Revoke
+accept_invitation = Accept Invitation
+accept_invitation_help_text = Set a password to activate your account. You can log in with your e-mail address afterwards.
+accept_invitation_action = Activate account
 
 ## Authentication
 
 login = Login
 login_action = Login
 logout_action = Logout
+forgot_password = Forgot password?
+forgot_password_help_text = Enter the e-mail address of your account and we will send you a link to set a new password.
+send_reset_link_action = Send link
+password_reset_requested_text = If an account with this e-mail address exists, a link to set a new password has been sent to it. The link is valid for one hour.
+reset_password = Reset Password
+reset_password_action = Set password
+login_link_help_text = Alternatively we can send you a link by e-mail, which logs you in without a password.
+send_login_link_action = Send login link
+login_link_requested_text = If an account with this e-mail address exists, a login link has been sent to it. The link is valid for 15 minutes.
+two_factor_authentication = Two-factor authentication
+two_factor_code = Code
+two_factor_code_help_text = Enter the code from your authenticator app or one of your recovery codes.
+two_factor_enroll_help_text = Scan the QR code with an authenticator app and enter the code it shows to enable two-factor authentication.
+two_factor_secret = Secret for manual entry
+two_factor_enabled_text = Two-factor authentication is enabled. Enter a code from your authenticator app to get new recovery codes or to disable it.
+recovery_codes = Recovery codes
+recovery_codes_help_text = Keep these codes in a safe place. Each of them can be used once to log in if you don't have your authenticator app at hand. They won't be shown again.
+enable_action = Enable
+disable_action = Disable
+regenerate_recovery_codes_action = New recovery codes
+passkeys = Passkeys
+passkeys_help_text = Passkeys let you log in with your fingerprint, face or device PIN instead of your password.
+passkey_name_placeholder = e.g. My phone
+passkey_added_on = Added on {$date}
+passkey_last_used_on = last used on {$date}
+add_passkey_action = Add passkey
+login_with_passkey_action = Login with passkey
+sessions = Sessions
+sessions_help_text = You are logged in on these devices. Log out a device you don't recognize or no longer have.
+current_session = This device
+unknown_device = Unknown device
+session_created_on = Logged in on {$date}
+session_last_used_on = last used on {$date}
+session_ip_address = IP address {$ip_address}
+revoke_other_sessions_action = Log out everywhere else
+login_history = Login history
+login_history_help_text = The latest login attempts to your account. You get an e-mail when somebody logs in from a device or network that hasn't been used before.
+login_successful = Successful
+login_failed = Failed
 
 ## Absence
 
@@ -117,6 +309,13 @@ create_absence = Create absence
 edit_absence = Edit absence
 absence_start_date = Date of departure
 absence_end_date = Date of return
+absence_handover = Hand over due chores
+absence_handover_help_text = These chores are due during your absence. Offer them to the others so that somebody can take over.
+absence_no_due_chores = No chores are due during your absence
+absence_handover_offer_action = Offer to others
+absence_handover_offered = Offered to others
+absence_handover_taken_over = Taken over
+done_action = Done
 n_days = {$n ->
     [one] 1 day
    *[other] {$n} days
@@ -28,16 +28,16 @@ async fn main() {
 
     let db_file = std::env::var("DB_FILE")
         .unwrap_or(String::from("./data/sqlite.db"));
-    let pool = wg_core::db::create_pool(db_file).await;
+    let pool = wg_core::db::create_pool(db_file.clone()).await;
 
-    wg_core::db::MIGRATOR.run(&pool).await.unwrap();
+    wg_core::db::run_migrations(&db_file).await;
 
     if !service::user::exists_any_user(&pool).await {
         let (admin_user, admin_password) = service::user::create_default_admin_user(&pool).await;
 
         println!(
             "Created user with email '{}' and password '{}'",
-            admin_user.email, admin_password.expose_secret()
+            admin_user.email.unwrap_or_default(), admin_password.expose_secret()
         );
     }
 
@@ -65,13 +65,30 @@ async fn main() {
 async fn start_web_server(pool: Pool, cancel_token: CancellationToken) -> () {
     tracing::debug!("Starting web server");
 
-    let web_router = wg_web::make_router(wg_web::AppState {
-        pool: pool,
-    });
-
     let port = std::env::var("PORT")
         .map(|raw_port| raw_port.parse::<i32>().unwrap())
         .unwrap_or(80);
+
+    let base_url = std::env::var("BASE_URL")
+        .map(|base_url| base_url.trim_end_matches('/').to_string())
+        .unwrap_or(format!("http://localhost:{}", port));
+
+    let is_magic_link_login_enabled = std::env::var("MAGIC_LINK_LOGIN")
+        .map(|raw_value| raw_value.parse::<bool>().unwrap())
+        .unwrap_or(false);
+
+    let trusted_proxy_header = std::env::var("TRUSTED_PROXY_HEADER")
+        .map(|raw_header_name| raw_header_name.parse().unwrap())
+        .ok();
+
+    let web_router = wg_web::make_router(wg_web::AppState {
+        pool: pool,
+        mail_transport: make_mail_transport(),
+        base_url,
+        is_magic_link_login_enabled,
+        trusted_proxy_header,
+        login_throttle: Default::default(),
+    });
     let address = format!("0.0.0.0:{}", port);
     let listener = tokio::net::TcpListener::bind(address).await.unwrap();
     println!("Listening on http://{} ...", listener.local_addr().unwrap());
@@ -82,22 +99,24 @@ async fn start_web_server(pool: Pool, cancel_token: CancellationToken) -> () {
 async fn start_scheduler(pool: Pool, cancel_token: CancellationToken) -> () {
     tracing::debug!("Starting scheduler");
 
-    let mail_transport = if let Ok(url) = std::env::var("SMTP_URL") {
-        wg_mail::MailTransport::Smtp(AsyncSmtpTransport::<Tokio1Executor>::from_url(&url).unwrap().build())
-    } else if let Ok(command) = std::env::var("SENDMAIL_COMMAND") {
-        wg_mail::MailTransport::Sendmail(AsyncSendmailTransport::<Tokio1Executor>::new_with_command(command))
-    } else {
-        wg_mail::MailTransport::Sendmail(AsyncSendmailTransport::<Tokio1Executor>::new())
-    };
-
     let state = wg_scheduler::AppState {
         pool: pool,
-        mail_transport,
+        mail_transport: make_mail_transport(),
     };
 
     wg_scheduler::start(state, cancel_token).await
 }
 
+fn make_mail_transport() -> wg_mail::MailTransport {
+    if let Ok(url) = std::env::var("SMTP_URL") {
+        wg_mail::MailTransport::Smtp(AsyncSmtpTransport::<Tokio1Executor>::from_url(&url).unwrap().build())
+    } else if let Ok(command) = std::env::var("SENDMAIL_COMMAND") {
+        wg_mail::MailTransport::Sendmail(AsyncSendmailTransport::<Tokio1Executor>::new_with_command(command))
+    } else {
+        wg_mail::MailTransport::Sendmail(AsyncSendmailTransport::<Tokio1Executor>::new())
+    }
+}
+
 async fn shutdown_signal() {
     let ctrl_c = async {
         tokio::signal::ctrl_c()